@@ -26,7 +26,7 @@ use clap::Parser;
 // `use futures::TryStreamExt`, this is better practice though.
 use futures_util::TryStreamExt;
 use hedera::{
-    AccountId, Client, PrivateKey, TopicId, TopicMessageQuery, TopicMessageSubmitTransaction
+    AccountId, Client, PrivateKey, TopicId, TopicMessageItem, TopicMessageQuery, TopicMessageSubmitTransaction
 };
 use parking_lot::RwLock;
 use tokio::time::sleep;
@@ -84,7 +84,11 @@ async fn main() -> anyhow::Result<()> {
 
     let mut latencies = Vec::new();
 
-    while let Some(tm) = stream.try_next().await? {
+    while let Some(item) = stream.try_next().await? {
+        let TopicMessageItem::Message(tm) = item else {
+            continue;
+        };
+
         let message = String::from_utf8(tm.contents)?;
 
         let times = message_send_times.read();
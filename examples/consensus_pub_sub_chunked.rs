@@ -25,7 +25,7 @@ mod resources;
 use clap::Parser;
 use futures_util::StreamExt;
 use hedera::{
-    AccountId, Client, PrivateKey, TopicCreateTransaction, TopicMessageQuery, TopicMessageSubmitTransaction, Transaction
+    AccountId, Client, PrivateKey, TopicCreateTransaction, TopicMessageItem, TopicMessageQuery, TopicMessageSubmitTransaction, Transaction
 };
 use tokio::task::JoinHandle;
 
@@ -121,7 +121,11 @@ async fn main() -> anyhow::Result<()> {
 
     while let Some(elem) = stream.next().await {
         let elem = match elem {
-            Ok(it) => it,
+            Ok(TopicMessageItem::Message(it)) => it,
+            Ok(TopicMessageItem::Incomplete(it)) => {
+                eprintln!("Message `{}` is incomplete: {}", it.transaction, it.reason);
+                continue;
+            }
             Err(e) => {
                 eprintln!("Error while handling message stream: {e:?}");
                 break;
@@ -3,7 +3,7 @@ use std::time::Duration;
 use clap::Parser;
 use futures_util::StreamExt;
 use hedera::{
-    AccountId, Client, PrivateKey, TopicCreateTransaction, TopicMessageQuery, TopicMessageSubmitTransaction
+    AccountId, Client, PrivateKey, TopicCreateTransaction, TopicMessageItem, TopicMessageQuery, TopicMessageSubmitTransaction
 };
 
 #[derive(Parser, Debug)]
@@ -85,7 +85,11 @@ async fn main() -> anyhow::Result<()> {
 
     while let Some(elem) = stream.next().await {
         let elem = match elem {
-            Ok(it) => it,
+            Ok(TopicMessageItem::Message(it)) => it,
+            Ok(TopicMessageItem::Incomplete(it)) => {
+                eprintln!("Message `{}` is incomplete: {}", it.transaction, it.reason);
+                continue;
+            }
             Err(e) => {
                 eprintln!("Error while handling message stream: {e:?}");
                 break;
@@ -26,6 +26,8 @@ use std::fs::{
 };
 use std::path::Path;
 
+use regex::RegexBuilder;
+
 const DERIVE_EQ_HASH: &str = "#[derive(Eq, Hash)]";
 const DERIVE_EQ_HASH_COPY: &str = "#[derive(Copy, Eq, Hash)]";
 const SERVICES_FOLDER: &str = "./protobufs/services";
@@ -36,18 +38,65 @@ fn main() -> anyhow::Result<()> {
 
     let services_path = Path::new(SERVICES_FOLDER);
 
+    // The contents of this folder will be copied and modified before it is
+    // used for code generation. Later we will suppress generation of cargo
+    // directives on the copy, so set a directive on the source.
+    println!("cargo:rerun-if-changed={SERVICES_FOLDER}");
+
     if !services_path.is_dir() {
         anyhow::bail!("Folder {SERVICES_FOLDER} does not exist; do you need to `git submodule update --init`?");
     }
 
-    let services: Vec<_> = read_dir(services_path)?
+    let out_dir = env::var("OUT_DIR")?;
+    let out_path = Path::new(&out_dir);
+    let services_tmp_path = out_path.join("services");
+
+    // ensure we start fresh
+    let _ = fs::remove_dir_all(&services_tmp_path);
+
+    create_dir_all(&services_tmp_path)?;
+
+    // copy over services into our tmp path so we can edit
+    fs_extra::copy_items(
+        &[services_path],
+        out_path,
+        &fs_extra::dir::CopyOptions::new().overwrite(true).copy_inside(false),
+    )?;
+
+    let services: Vec<_> = read_dir(&services_tmp_path)?
+        .chain(read_dir(services_tmp_path.join("auxiliary").join("tss"))?)
         .filter_map(|entry| {
             let entry = entry.ok()?;
             entry.file_type().ok()?.is_file().then(|| entry.path())
         })
         .collect();
 
-    let mut cfg = tonic_build::configure().build_server(cfg!(feature = "server"));
+    // iterate through each file
+    let re_package = RegexBuilder::new(r"^package (.*);$").multi_line(true).build()?;
+    for service in &services {
+        let contents = fs::read_to_string(service)?;
+
+        // ensure that every `package _` entry is `package proto;`
+        let contents = re_package.replace(&contents, "package proto;");
+
+        // remove the `com.hedera.hapi.*` prefixes, everything lives in `proto`.
+        let contents = contents.replace("com.hedera.hapi.node.addressbook.", "");
+        let contents = contents.replace("com.hedera.hapi.services.auxiliary.history.", "");
+        let contents = contents.replace("com.hedera.hapi.services.auxiliary.tss.", "");
+        let contents = contents.replace("com.hedera.hapi.platform.event.", "");
+
+        let contents = remove_unused_types(&contents);
+
+        fs::write(service, &*contents)?;
+    }
+
+    let mut cfg = tonic_build::configure()
+        .build_server(cfg!(feature = "server"))
+        // We have already emitted a cargo directive to trigger a rerun on the source folder
+        // that the copy this builds is based on. If the directives are not suppressed, the
+        // crate will rebuild on every compile due to the modified time stamps post-dating
+        // the start time of the compile action.
+        .emit_rerun_if_changed(false);
 
     // most of the protobufs in "basic types" should be Eq + Hash + Copy
     // any protobufs that would typically be used as parameter, that meet the requirements of those
@@ -98,7 +147,7 @@ fn main() -> anyhow::Result<()> {
      "]"#,
     );
 
-    cfg.compile(&services, &["./protobufs/services/"])?;
+    cfg.compile(&services, &[out_path])?;
 
     // NOTE: prost generates rust doc comments and fails to remove the leading * line
     remove_useless_comments(&Path::new(&env::var("OUT_DIR")?).join("proto.rs"))?;
@@ -125,7 +174,7 @@ fn main() -> anyhow::Result<()> {
                 "./protobufs/mirror/consensus_service.proto",
                 "./protobufs/mirror/mirror_network_service.proto",
             ],
-            &["./protobufs/mirror/", "./protobufs/services/"],
+            &[Path::new("./protobufs/mirror/"), out_path],
         )?;
 
     remove_useless_comments(&mirror_out_dir.join("proto.rs"))?;
@@ -142,7 +191,7 @@ fn main() -> anyhow::Result<()> {
 
     cfg.out_dir(&streams_out_dir).compile(
         &["./protobufs/streams/account_balance_file.proto"],
-        &["./protobufs/streams/", "./protobufs/services/"],
+        &[Path::new("./protobufs/streams/"), out_path],
     )?;
 
     // see note wrt services.
@@ -217,11 +266,15 @@ fn main() -> anyhow::Result<()> {
         .services_same("TransactionBody")
         .services_same("UncheckedSubmitBody")
         .services_same("UtilPrngTransactionBody")
+        .services_same("TssMessageTransactionBody")
+        .services_same("TssVoteTransactionBody")
+        .services_same("TssShareSignatureTransactionBody")
+        .services_same("TssEncryptionKeyTransactionBody")
         .services_same("VirtualAddress");
 
     cfg.out_dir(&sdk_out_dir).compile(
         &["./protobufs/sdk/transaction_list.proto"],
-        &["./protobufs/sdk/", "./protobufs/services/"],
+        &[Path::new("./protobufs/sdk/"), out_path],
     )?;
 
     // see note wrt services.
@@ -237,11 +290,89 @@ fn remove_useless_comments(path: &Path) -> anyhow::Result<()> {
     contents = contents.replace("/// *\n", "");
     contents = contents.replace("/// UNDOCUMENTED", "");
 
+    // Remove code examples in comments
+    let re = regex::Regex::new(r"/// ```[\s\S]*?/// ```\n").unwrap();
+    contents = re.replace_all(&contents, "").to_string();
+
     fs::write(path, contents)?;
 
     Ok(())
 }
 
+// Temporary function to remove unused types in transaction.proto
+fn remove_unused_types(contents: &str) -> String {
+    let contents = contents.replace(
+        "import \"platform/event/state_signature_transaction.proto\";",
+        "// import \"platform/event/state_signature_transaction.proto\";",
+    );
+
+    let contents = contents.replace(
+        "import \"services/auxiliary/history/history_proof_vote.proto\";",
+        "// import \"services/auxiliary/history/history_proof_vote.proto\";",
+    );
+    let contents = contents.replace(
+        "import \"services/auxiliary/history/history_proof_signature.proto\";",
+        "// import \"services/auxiliary/history/history_proof_signature.proto\";",
+    );
+    let contents = contents.replace(
+        "import \"services/auxiliary/history/history_proof_key_publication.proto\";",
+        "// import \"services/auxiliary/history/history_proof_key_publication.proto\";",
+    );
+
+    let contents = contents.replace(
+        "import \"services/auxiliary/hints/hints_key_publication.proto\";",
+        "// import \"services/auxiliary/hints/hints_key_publication.proto\";",
+    );
+
+    let contents = contents.replace(
+        "import \"services/auxiliary/hints/hints_preprocessing_vote.proto\";",
+        "// import \"services/auxiliary/hints/hints_preprocessing_vote.proto\";",
+    );
+
+    let contents = contents.replace(
+        "import \"services/auxiliary/hints/hints_partial_signature.proto\";",
+        "// import \"services/auxiliary/hints/hints_partial_signature.proto\";",
+    );
+
+    let contents = contents.replace(
+        "import \"services/auxiliary/hints/crs_publication.proto\";",
+        "// import \"services/auxiliary/hints/crs_publication.proto\";",
+    );
+
+    let contents = contents.replace("StateSignatureTransaction", "// StateSignatureTransaction");
+
+    let contents =
+        contents.replace("HistoryProofSignatureTransaction", "// HistoryProofSignatureTransaction");
+
+    let contents = contents.replace(
+        "HistoryProofKeyPublicationTransaction",
+        "// HistoryProofKeyPublicationTransaction",
+    );
+
+    let contents =
+        contents.replace("HistoryProofVoteTransaction", "// HistoryProofVoteTransaction");
+
+    let contents = contents.replace(
+        "com.hedera.hapi.services.auxiliary.hints.HintsPreprocessingVoteTransactionBody",
+        "// com.hedera.hapi.services.auxiliary.hints.HintsPreprocessingVoteTransactionBody",
+    );
+
+    let contents = contents.replace(
+        "com.hedera.hapi.services.auxiliary.hints.HintsKeyPublicationTransactionBody",
+        "// com.hedera.hapi.services.auxiliary.hints.HintsKeyPublicationTransactionBody",
+    );
+
+    let contents = contents.replace(
+        "com.hedera.hapi.services.auxiliary.hints.HintsPartialSignatureTransactionBody",
+        "// com.hedera.hapi.services.auxiliary.hints.HintsPartialSignatureTransactionBody",
+    );
+
+    contents.replace(
+        "com.hedera.hapi.services.auxiliary.hints.CrsPublicationTransactionBody",
+        "// com.hedera.hapi.services.auxiliary.hints.CrsPublicationTransactionBody",
+    )
+}
+
 trait BuilderExtensions {
     fn services_path<T: AsRef<str>, U: AsRef<str>>(self, proto_name: T, rust_name: U) -> Self
     where
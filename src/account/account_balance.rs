@@ -33,7 +33,7 @@ use crate::{
 };
 
 /// Response from [`AccountBalanceQuery`][crate::AccountBalanceQuery].
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AccountBalance {
     /// The account that is being referenced.
     pub account_id: AccountId,
@@ -38,7 +38,7 @@ use crate::{
 };
 
 /// Response from [`AccountInfoQuery`][crate::AccountInfoQuery].
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct AccountInfo {
     /// The account that is being referenced.
     pub account_id: AccountId,
@@ -99,7 +99,9 @@ pub struct AccountInfo {
     pub owned_nfts: u64,
 
     /// The maximum number of tokens that an Account can be implicitly associated with.
-    pub max_automatic_token_associations: u32,
+    ///
+    /// A value of `-1` means the number of automatic associations is unlimited.
+    pub max_automatic_token_associations: i32,
 
     /// The alias of this account.
     pub alias_key: Option<PublicKey>,
@@ -140,7 +142,7 @@ impl AccountInfo {
             auto_renew_period: self.auto_renew_period.to_protobuf(),
             memo: self.account_memo.clone(),
             owned_nfts: self.owned_nfts as i64,
-            max_automatic_token_associations: self.max_automatic_token_associations as i32,
+            max_automatic_token_associations: self.max_automatic_token_associations,
             alias: self.alias_key.as_ref().map(ToProtobuf::to_bytes).unwrap_or_default(),
             ledger_id: self.ledger_id.to_bytes(),
             ethereum_nonce: self.ethereum_nonce as i64,
@@ -195,7 +197,7 @@ impl FromProtobuf<services::crypto_get_info_response::AccountInfo> for AccountIn
             auto_renew_period: pb.auto_renew_period.map(Into::into),
             account_memo: pb.memo,
             owned_nfts: pb.owned_nfts as u64,
-            max_automatic_token_associations: pb.max_automatic_token_associations as u32,
+            max_automatic_token_associations: pb.max_automatic_token_associations,
             alias_key,
             ethereum_nonce: pb.ethereum_nonce as u64,
             is_receiver_signature_required: pb.receiver_sig_required,
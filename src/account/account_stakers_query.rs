@@ -19,7 +19,6 @@
  */
 
 use hedera_proto::services;
-use hedera_proto::services::crypto_service_client::CryptoServiceClient;
 use tonic::transport::Channel;
 
 use crate::ledger_id::RefLedgerId;
@@ -40,6 +39,10 @@ use crate::{
 
 /// Get all the accounts that are proxy staking to this account.
 /// For each of them, give the amount currently staked.
+///
+/// The network never answered this query, and `getStakersByAccountID` has since been removed from
+/// `CryptoService`, so executing it always fails with an [`Error::GrpcStatus`] of `UNIMPLEMENTED`
+/// without contacting a node.
 pub type AccountStakersQuery = Query<AccountStakersQueryData>;
 
 #[derive(Debug, Clone, Default)]
@@ -85,11 +88,13 @@ impl QueryExecute for AccountStakersQueryData {
 
     fn execute(
         &self,
-        channel: Channel,
-        request: services::Query,
+        _channel: Channel,
+        _request: services::Query,
     ) -> BoxGrpcFuture<'_, services::Response> {
+        // `getStakersByAccountID` was never supported by the network and has been removed from the service,
+        // so there is no client method to call anymore, fail like the network would have.
         Box::pin(async {
-            CryptoServiceClient::new(channel).get_stakers_by_account_id(request).await
+            Err(tonic::Status::unimplemented("`getStakersByAccountID` is not supported"))
         })
     }
 }
@@ -102,12 +107,20 @@ impl ValidateChecksums for AccountStakersQueryData {
 
 #[cfg(test)]
 mod tests {
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+
     use expect_test::expect;
+    use triomphe::Arc;
 
+    use crate::client::mock_node;
     use crate::query::ToQueryProtobuf;
     use crate::{
         AccountId,
         AccountStakersQuery,
+        Error,
         Hbar,
     };
 
@@ -163,4 +176,33 @@ mod tests {
 
         assert_eq!(query.get_account_id(), Some(AccountId::new(0, 0, 5005)));
     }
+
+    #[tokio::test]
+    async fn execute_is_unimplemented() {
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let address = mock_node::spawn({
+            let requests = requests.clone();
+            move |_, _| {
+                requests.fetch_add(1, Ordering::Relaxed);
+                Err(tonic::Status::internal("unexpected request"))
+            }
+        })
+        .await;
+
+        let client = mock_node::client(&[address]);
+
+        let error = AccountStakersQuery::new()
+            .account_id(AccountId::new(0, 0, 5005))
+            .node_account_ids([AccountId::new(0, 0, 3)])
+            .max_payment_amount(Hbar::from_tinybars(100_000))
+            .execute(&client)
+            .await;
+
+        assert!(
+            matches!(&error, Err(Error::GrpcStatus(status)) if status.code() == tonic::Code::Unimplemented),
+            "{error:?}"
+        );
+        assert_eq!(requests.load(Ordering::Relaxed), 0);
+    }
 }
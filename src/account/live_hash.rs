@@ -0,0 +1,158 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use time::Duration;
+
+use crate::protobuf::ToProtobuf;
+use crate::{
+    AccountId,
+    FromProtobuf,
+    KeyList,
+};
+
+/// A hash (presumably of some kind of credential or certificate), along with a list of keys,
+/// attached to an account.
+///
+/// Response from [`LiveHashQuery`](crate::LiveHashQuery).
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiveHash {
+    /// The account to which the livehash is attached.
+    pub account_id: AccountId,
+
+    /// The SHA-384 hash of a credential or certificate.
+    pub hash: Vec<u8>,
+
+    /// A list of keys, all of which may sign to delete the livehash.
+    pub keys: KeyList,
+
+    /// The duration for which the livehash will remain valid.
+    pub duration: Duration,
+}
+
+impl LiveHash {
+    /// Create a new `LiveHash` from protobuf-encoded `bytes`.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the bytes fails to produce a valid protobuf.
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the protobuf fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        FromProtobuf::<services::LiveHash>::from_bytes(bytes)
+    }
+
+    /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        ToProtobuf::to_bytes(self)
+    }
+}
+
+impl FromProtobuf<services::response::Response> for LiveHash {
+    fn from_protobuf(pb: services::response::Response) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        let response = pb_getv!(pb, CryptoGetLiveHash, services::response::Response);
+        let live_hash = pb_getf!(response, live_hash)?;
+
+        Self::from_protobuf(live_hash)
+    }
+}
+
+impl FromProtobuf<services::LiveHash> for LiveHash {
+    fn from_protobuf(pb: services::LiveHash) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        let account_id = pb_getf!(pb, account_id)?;
+
+        Ok(Self {
+            account_id: AccountId::from_protobuf(account_id)?,
+            hash: pb.hash,
+            keys: Option::from_protobuf(pb.keys)?.unwrap_or_default(),
+            duration: pb.duration.map(Into::into).unwrap_or_default(),
+        })
+    }
+}
+
+impl ToProtobuf for LiveHash {
+    type Protobuf = services::LiveHash;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::LiveHash {
+            account_id: Some(self.account_id.to_protobuf()),
+            hash: self.hash.clone(),
+            keys: Some(self.keys.to_protobuf()),
+            duration: Some(self.duration.to_protobuf()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use time::Duration;
+
+    use crate::transaction::test_helpers::unused_private_key;
+    use crate::{
+        AccountId,
+        KeyList,
+        LiveHash,
+    };
+
+    fn make_live_hash() -> LiveHash {
+        LiveHash {
+            account_id: AccountId::new(0, 0, 5006),
+            hash: b"hash".to_vec(),
+            keys: KeyList::from([unused_private_key().public_key()]),
+            duration: Duration::days(30),
+        }
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let live_hash = LiveHash::from_bytes(&make_live_hash().to_bytes()).unwrap();
+
+        expect![[r#"
+            LiveHash {
+                account_id: "0.0.5006",
+                hash: [
+                    104,
+                    97,
+                    115,
+                    104,
+                ],
+                keys: KeyList {
+                    keys: [
+                        Single(
+                            "302a300506032b6570032100e0c8ec2758a5879ffac226a13c0c516b799e72e35141a0dd828f94d37988a4b7",
+                        ),
+                    ],
+                    threshold: None,
+                },
+                duration: Duration {
+                    seconds: 2592000,
+                    nanoseconds: 0,
+                },
+            }
+        "#]]
+        .assert_debug_eq(&live_hash);
+    }
+}
@@ -0,0 +1,382 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use hedera_proto::services::crypto_service_client::CryptoServiceClient;
+use time::Duration;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Error,
+    KeyList,
+    Transaction,
+    ValidateChecksums,
+};
+
+/// Attach a hash (presumably of some kind of credential or certificate) to an account,
+/// along with a list of keys that may be used to delete it.
+///
+/// --- Signing Requirements ---
+/// 1. The key of the account the livehash is attached to must sign.
+/// 2. Every key in the livehash's `keys` must sign.
+pub type LiveHashAddTransaction = Transaction<LiveHashAddTransactionData>;
+
+#[derive(Debug, Clone, Default)]
+pub struct LiveHashAddTransactionData {
+    /// The account to which the livehash is attached.
+    account_id: Option<AccountId>,
+
+    /// The SHA-384 hash of a credential or certificate.
+    hash: Vec<u8>,
+
+    /// A list of keys, all of which may sign to delete the livehash.
+    keys: Option<KeyList>,
+
+    /// The duration for which the livehash will remain valid.
+    duration: Option<Duration>,
+}
+
+impl LiveHashAddTransaction {
+    /// Returns the account to which the livehash is attached.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.data().account_id
+    }
+
+    /// Sets the account to which the livehash is attached.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.data_mut().account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the SHA-384 hash of a credential or certificate.
+    #[must_use]
+    pub fn get_hash(&self) -> &[u8] {
+        &self.data().hash
+    }
+
+    /// Sets the SHA-384 hash of a credential or certificate.
+    pub fn hash(&mut self, hash: impl Into<Vec<u8>>) -> &mut Self {
+        self.data_mut().hash = hash.into();
+        self
+    }
+
+    /// Returns the list of keys, all of which may sign to delete the livehash.
+    #[must_use]
+    pub fn get_keys(&self) -> Option<&KeyList> {
+        self.data().keys.as_ref()
+    }
+
+    /// Sets the list of keys, all of which may sign to delete the livehash.
+    pub fn keys(&mut self, keys: impl Into<KeyList>) -> &mut Self {
+        self.data_mut().keys = Some(keys.into());
+        self
+    }
+
+    /// Returns the duration for which the livehash will remain valid.
+    #[must_use]
+    pub fn get_duration(&self) -> Option<Duration> {
+        self.data().duration
+    }
+
+    /// Sets the duration for which the livehash will remain valid.
+    pub fn duration(&mut self, duration: Duration) -> &mut Self {
+        self.data_mut().duration = Some(duration);
+        self
+    }
+}
+
+impl TransactionData for LiveHashAddTransactionData {}
+
+impl TransactionExecute for LiveHashAddTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { CryptoServiceClient::new(channel).add_live_hash(request).await })
+    }
+}
+
+impl ValidateChecksums for LiveHashAddTransactionData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.account_id.validate_checksums(ledger_id)
+    }
+}
+
+impl ToTransactionDataProtobuf for LiveHashAddTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::CryptoAddLiveHash(self.to_protobuf())
+    }
+}
+
+impl From<LiveHashAddTransactionData> for AnyTransactionData {
+    fn from(transaction: LiveHashAddTransactionData) -> Self {
+        Self::LiveHashAdd(transaction)
+    }
+}
+
+impl FromProtobuf<services::CryptoAddLiveHashTransactionBody> for LiveHashAddTransactionData {
+    fn from_protobuf(pb: services::CryptoAddLiveHashTransactionBody) -> crate::Result<Self> {
+        let Some(live_hash) = pb.live_hash else {
+            return Ok(Self::default());
+        };
+
+        Ok(Self {
+            account_id: Option::from_protobuf(live_hash.account_id)?,
+            hash: live_hash.hash,
+            keys: Option::from_protobuf(live_hash.keys)?,
+            duration: live_hash.duration.map(Into::into),
+        })
+    }
+}
+
+impl ToProtobuf for LiveHashAddTransactionData {
+    type Protobuf = services::CryptoAddLiveHashTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::CryptoAddLiveHashTransactionBody {
+            live_hash: Some(services::LiveHash {
+                account_id: self.account_id.to_protobuf(),
+                hash: self.hash.clone(),
+                keys: self.keys.to_protobuf(),
+                duration: self.duration.to_protobuf(),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use hedera_proto::services;
+    use time::Duration;
+
+    use super::LiveHashAddTransactionData;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::transaction::test_helpers::{
+        check_body,
+        transaction_body,
+        unused_private_key,
+    };
+    use crate::{
+        AccountId,
+        AnyTransaction,
+        KeyList,
+        LiveHashAddTransaction,
+    };
+
+    const TEST_ACCOUNT_ID: AccountId = AccountId::new(0, 0, 5006);
+    const TEST_HASH: &[u8] = b"hash";
+    const TEST_DURATION: Duration = Duration::days(30);
+
+    fn test_keys() -> KeyList {
+        KeyList::from([unused_private_key().public_key()])
+    }
+
+    fn make_transaction() -> LiveHashAddTransaction {
+        let mut tx = LiveHashAddTransaction::new_for_tests();
+
+        tx.account_id(TEST_ACCOUNT_ID)
+            .hash(TEST_HASH)
+            .keys(test_keys())
+            .duration(TEST_DURATION)
+            .freeze()
+            .unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn serialize() {
+        let tx = make_transaction();
+
+        let tx = transaction_body(tx);
+
+        let tx = check_body(tx);
+
+        expect![[r#"
+            CryptoAddLiveHash(
+                CryptoAddLiveHashTransactionBody {
+                    live_hash: Some(
+                        LiveHash {
+                            account_id: Some(
+                                AccountId {
+                                    shard_num: 0,
+                                    realm_num: 0,
+                                    account: Some(
+                                        AccountNum(
+                                            5006,
+                                        ),
+                                    ),
+                                },
+                            ),
+                            hash: [
+                                104,
+                                97,
+                                115,
+                                104,
+                            ],
+                            keys: Some(
+                                KeyList {
+                                    keys: [
+                                        Key {
+                                            key: Some(
+                                                Ed25519(
+                                                    [
+                                                        224,
+                                                        200,
+                                                        236,
+                                                        39,
+                                                        88,
+                                                        165,
+                                                        135,
+                                                        159,
+                                                        250,
+                                                        194,
+                                                        38,
+                                                        161,
+                                                        60,
+                                                        12,
+                                                        81,
+                                                        107,
+                                                        121,
+                                                        158,
+                                                        114,
+                                                        227,
+                                                        81,
+                                                        65,
+                                                        160,
+                                                        221,
+                                                        130,
+                                                        143,
+                                                        148,
+                                                        211,
+                                                        121,
+                                                        136,
+                                                        164,
+                                                        183,
+                                                    ],
+                                                ),
+                                            ),
+                                        },
+                                    ],
+                                },
+                            ),
+                            duration: Some(
+                                Duration {
+                                    seconds: 2592000,
+                                },
+                            ),
+                        },
+                    ),
+                },
+            )
+        "#]].assert_debug_eq(&tx);
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_body(tx);
+        let tx2 = transaction_body(tx2);
+
+        assert_eq!(tx, tx2);
+    }
+
+    #[test]
+    fn from_proto_body() {
+        let tx = services::CryptoAddLiveHashTransactionBody {
+            live_hash: Some(services::LiveHash {
+                account_id: Some(TEST_ACCOUNT_ID.to_protobuf()),
+                hash: TEST_HASH.to_vec(),
+                keys: Some(test_keys().to_protobuf()),
+                duration: Some(TEST_DURATION.to_protobuf()),
+            }),
+        };
+
+        let tx = LiveHashAddTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(tx.account_id, Some(TEST_ACCOUNT_ID));
+        assert_eq!(tx.hash, TEST_HASH);
+        assert_eq!(tx.keys, Some(test_keys()));
+        assert_eq!(tx.duration, Some(TEST_DURATION));
+    }
+
+    #[test]
+    fn get_set_account_id() {
+        let mut tx = LiveHashAddTransaction::new();
+        tx.account_id(TEST_ACCOUNT_ID);
+        assert_eq!(tx.get_account_id(), Some(TEST_ACCOUNT_ID));
+    }
+
+    #[test]
+    fn get_set_hash() {
+        let mut tx = LiveHashAddTransaction::new();
+        tx.hash(TEST_HASH);
+        assert_eq!(tx.get_hash(), TEST_HASH);
+    }
+
+    #[test]
+    fn get_set_keys() {
+        let mut tx = LiveHashAddTransaction::new();
+        tx.keys(test_keys());
+        assert_eq!(tx.get_keys(), Some(&test_keys()));
+    }
+
+    #[test]
+    fn get_set_duration() {
+        let mut tx = LiveHashAddTransaction::new();
+        tx.duration(TEST_DURATION);
+        assert_eq!(tx.get_duration(), Some(TEST_DURATION));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_hash_frozen_panic() {
+        let mut tx = make_transaction();
+        tx.hash(TEST_HASH);
+    }
+}
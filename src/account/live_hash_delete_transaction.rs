@@ -0,0 +1,251 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use hedera_proto::services::crypto_service_client::CryptoServiceClient;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Error,
+    Transaction,
+    ValidateChecksums,
+};
+
+/// Delete a livehash from an account.
+///
+/// --- Signing Requirements ---
+/// 1. Either the key of the account the livehash is attached to, or any one key
+///    from the livehash's `keys`, must sign.
+pub type LiveHashDeleteTransaction = Transaction<LiveHashDeleteTransactionData>;
+
+#[derive(Debug, Clone, Default)]
+pub struct LiveHashDeleteTransactionData {
+    /// The account to which the livehash is attached.
+    account_id: Option<AccountId>,
+
+    /// The SHA-384 hash of the livehash to delete.
+    hash: Vec<u8>,
+}
+
+impl LiveHashDeleteTransaction {
+    /// Returns the account to which the livehash is attached.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.data().account_id
+    }
+
+    /// Sets the account to which the livehash is attached.
+    pub fn account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.data_mut().account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the SHA-384 hash of the livehash to delete.
+    #[must_use]
+    pub fn get_hash(&self) -> &[u8] {
+        &self.data().hash
+    }
+
+    /// Sets the SHA-384 hash of the livehash to delete.
+    pub fn hash(&mut self, hash: impl Into<Vec<u8>>) -> &mut Self {
+        self.data_mut().hash = hash.into();
+        self
+    }
+}
+
+impl TransactionData for LiveHashDeleteTransactionData {}
+
+impl TransactionExecute for LiveHashDeleteTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { CryptoServiceClient::new(channel).delete_live_hash(request).await })
+    }
+}
+
+impl ValidateChecksums for LiveHashDeleteTransactionData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.account_id.validate_checksums(ledger_id)
+    }
+}
+
+impl ToTransactionDataProtobuf for LiveHashDeleteTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::CryptoDeleteLiveHash(self.to_protobuf())
+    }
+}
+
+impl From<LiveHashDeleteTransactionData> for AnyTransactionData {
+    fn from(transaction: LiveHashDeleteTransactionData) -> Self {
+        Self::LiveHashDelete(transaction)
+    }
+}
+
+impl FromProtobuf<services::CryptoDeleteLiveHashTransactionBody> for LiveHashDeleteTransactionData {
+    fn from_protobuf(pb: services::CryptoDeleteLiveHashTransactionBody) -> crate::Result<Self> {
+        Ok(Self {
+            account_id: Option::from_protobuf(pb.account_of_live_hash)?,
+            hash: pb.live_hash_to_delete,
+        })
+    }
+}
+
+impl ToProtobuf for LiveHashDeleteTransactionData {
+    type Protobuf = services::CryptoDeleteLiveHashTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::CryptoDeleteLiveHashTransactionBody {
+            account_of_live_hash: self.account_id.to_protobuf(),
+            live_hash_to_delete: self.hash.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use hedera_proto::services;
+
+    use super::LiveHashDeleteTransactionData;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::transaction::test_helpers::{
+        check_body,
+        transaction_body,
+    };
+    use crate::{
+        AccountId,
+        AnyTransaction,
+        LiveHashDeleteTransaction,
+    };
+
+    const TEST_ACCOUNT_ID: AccountId = AccountId::new(0, 0, 5006);
+    const TEST_HASH: &[u8] = b"hash";
+
+    fn make_transaction() -> LiveHashDeleteTransaction {
+        let mut tx = LiveHashDeleteTransaction::new_for_tests();
+
+        tx.account_id(TEST_ACCOUNT_ID).hash(TEST_HASH).freeze().unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn serialize() {
+        let tx = make_transaction();
+
+        let tx = transaction_body(tx);
+
+        let tx = check_body(tx);
+
+        expect![[r#"
+            CryptoDeleteLiveHash(
+                CryptoDeleteLiveHashTransactionBody {
+                    account_of_live_hash: Some(
+                        AccountId {
+                            shard_num: 0,
+                            realm_num: 0,
+                            account: Some(
+                                AccountNum(
+                                    5006,
+                                ),
+                            ),
+                        },
+                    ),
+                    live_hash_to_delete: [
+                        104,
+                        97,
+                        115,
+                        104,
+                    ],
+                },
+            )
+        "#]].assert_debug_eq(&tx);
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_body(tx);
+        let tx2 = transaction_body(tx2);
+
+        assert_eq!(tx, tx2);
+    }
+
+    #[test]
+    fn from_proto_body() {
+        let tx = services::CryptoDeleteLiveHashTransactionBody {
+            account_of_live_hash: Some(TEST_ACCOUNT_ID.to_protobuf()),
+            live_hash_to_delete: TEST_HASH.to_vec(),
+        };
+
+        let tx = LiveHashDeleteTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(tx.account_id, Some(TEST_ACCOUNT_ID));
+        assert_eq!(tx.hash, TEST_HASH);
+    }
+
+    #[test]
+    fn get_set_account_id() {
+        let mut tx = LiveHashDeleteTransaction::new();
+        tx.account_id(TEST_ACCOUNT_ID);
+        assert_eq!(tx.get_account_id(), Some(TEST_ACCOUNT_ID));
+    }
+
+    #[test]
+    fn get_set_hash() {
+        let mut tx = LiveHashDeleteTransaction::new();
+        tx.hash(TEST_HASH);
+        assert_eq!(tx.get_hash(), TEST_HASH);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_hash_frozen_panic() {
+        let mut tx = make_transaction();
+        tx.hash(TEST_HASH);
+    }
+}
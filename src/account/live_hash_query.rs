@@ -0,0 +1,185 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use hedera_proto::services::crypto_service_client::CryptoServiceClient;
+use tonic::transport::Channel;
+
+use crate::account::LiveHash;
+use crate::ledger_id::RefLedgerId;
+use crate::query::{
+    AnyQueryData,
+    QueryExecute,
+    ToQueryProtobuf,
+};
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Error,
+    Query,
+    ToProtobuf,
+    ValidateChecksums,
+};
+
+/// Get a single livehash attached to an account, by its hash.
+pub type LiveHashQuery = Query<LiveHashQueryData>;
+
+#[derive(Default, Clone, Debug)]
+pub struct LiveHashQueryData {
+    account_id: Option<AccountId>,
+    hash: Vec<u8>,
+}
+
+impl From<LiveHashQueryData> for AnyQueryData {
+    #[inline]
+    fn from(data: LiveHashQueryData) -> Self {
+        Self::LiveHash(data)
+    }
+}
+
+impl LiveHashQuery {
+    /// Gets the account ID to which the livehash is attached.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.data.account_id
+    }
+
+    /// Sets the account ID to which the livehash is attached.
+    pub fn account_id(&mut self, id: AccountId) -> &mut Self {
+        self.data.account_id = Some(id);
+        self
+    }
+
+    /// Gets the SHA-384 hash of the livehash to query.
+    #[must_use]
+    pub fn get_hash(&self) -> &[u8] {
+        &self.data.hash
+    }
+
+    /// Sets the SHA-384 hash of the livehash to query.
+    pub fn hash(&mut self, hash: impl Into<Vec<u8>>) -> &mut Self {
+        self.data.hash = hash.into();
+        self
+    }
+}
+
+impl ToQueryProtobuf for LiveHashQueryData {
+    fn to_query_protobuf(&self, header: services::QueryHeader) -> services::Query {
+        let account_id = self.account_id.to_protobuf();
+
+        services::Query {
+            query: Some(services::query::Query::CryptoGetLiveHash(
+                services::CryptoGetLiveHashQuery {
+                    header: Some(header),
+                    account_id,
+                    hash: self.hash.clone(),
+                },
+            )),
+        }
+    }
+}
+
+impl QueryExecute for LiveHashQueryData {
+    type Response = LiveHash;
+
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Query,
+    ) -> BoxGrpcFuture<'_, services::Response> {
+        Box::pin(async { CryptoServiceClient::new(channel).get_live_hash(request).await })
+    }
+}
+
+impl ValidateChecksums for LiveHashQueryData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.account_id.validate_checksums(ledger_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::query::ToQueryProtobuf;
+    use crate::{
+        AccountId,
+        LiveHashQuery,
+    };
+
+    #[test]
+    fn serialize() {
+        expect![[r#"
+            Query {
+                query: Some(
+                    CryptoGetLiveHash(
+                        CryptoGetLiveHashQuery {
+                            header: Some(
+                                QueryHeader {
+                                    payment: None,
+                                    response_type: AnswerOnly,
+                                },
+                            ),
+                            account_id: Some(
+                                AccountId {
+                                    shard_num: 0,
+                                    realm_num: 0,
+                                    account: Some(
+                                        AccountNum(
+                                            5005,
+                                        ),
+                                    ),
+                                },
+                            ),
+                            hash: [
+                                104,
+                                97,
+                                115,
+                                104,
+                            ],
+                        },
+                    ),
+                ),
+            }
+        "#]].assert_debug_eq(
+            &LiveHashQuery::new()
+                .account_id(AccountId::new(0, 0, 5005))
+                .hash(b"hash".to_vec())
+                .data
+                .to_query_protobuf(Default::default()),
+        );
+    }
+
+    #[test]
+    fn get_set_account_id() {
+        let mut query = LiveHashQuery::new();
+        query.account_id(AccountId::new(0, 0, 5005));
+
+        assert_eq!(query.get_account_id(), Some(AccountId::new(0, 0, 5005)));
+    }
+
+    #[test]
+    fn get_set_hash() {
+        let mut query = LiveHashQuery::new();
+        query.hash(b"hash".to_vec());
+
+        assert_eq!(query.get_hash(), b"hash");
+    }
+}
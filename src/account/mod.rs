@@ -33,6 +33,10 @@ mod account_info_query;
 mod account_records_query;
 mod account_stakers_query;
 mod account_update_transaction;
+mod live_hash;
+mod live_hash_add_transaction;
+mod live_hash_delete_transaction;
+mod live_hash_query;
 mod proxy_staker;
 
 pub use account_allowance_approve_transaction::AccountAllowanceApproveTransaction;
@@ -56,6 +60,13 @@ pub use account_stakers_query::AccountStakersQuery;
 pub(crate) use account_stakers_query::AccountStakersQueryData;
 pub use account_update_transaction::AccountUpdateTransaction;
 pub(crate) use account_update_transaction::AccountUpdateTransactionData;
+pub use live_hash::LiveHash;
+pub use live_hash_add_transaction::LiveHashAddTransaction;
+pub(crate) use live_hash_add_transaction::LiveHashAddTransactionData;
+pub use live_hash_delete_transaction::LiveHashDeleteTransaction;
+pub(crate) use live_hash_delete_transaction::LiveHashDeleteTransactionData;
+pub use live_hash_query::LiveHashQuery;
+pub(crate) use live_hash_query::LiveHashQueryData;
 pub use proxy_staker::{
     AllProxyStakers,
     ProxyStaker,
@@ -30,7 +30,7 @@ use crate::{
 pub type AllProxyStakers = Vec<ProxyStaker>;
 
 /// Information about a single account that is proxy staking.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ProxyStaker {
     /// The Account ID that is proxy staking.
     pub account_id: AccountId,
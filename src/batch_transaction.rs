@@ -0,0 +1,614 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use hedera_proto::services::util_service_client::UtilServiceClient;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::{
+    AnyTransaction,
+    BoxGrpcFuture,
+    Error,
+    Transaction,
+    TransactionId,
+    ValidateChecksums,
+};
+
+/// Execute multiple transactions atomically, either all of them succeed or none of them do.
+///
+/// Each inner transaction must be frozen and signed, and have its `batch_key` set
+/// (see [`Transaction::batchify`]). This transaction must be signed by every inner transaction's batch key.
+///
+/// The receipts of the inner transactions can be fetched with
+/// [`TransactionReceiptQuery::include_children`](crate::TransactionReceiptQuery::include_children)
+/// or individually by their [inner transaction IDs](Self::get_inner_transaction_ids).
+pub type BatchTransaction = Transaction<BatchTransactionData>;
+
+#[derive(Debug, Clone, Default)]
+pub struct BatchTransactionData {
+    inner_transactions: Vec<AnyTransaction>,
+
+    /// The signed bytes of each inner transaction, exactly as they'll be submitted.
+    signed_transactions: Vec<Vec<u8>>,
+}
+
+impl BatchTransaction {
+    /// Returns the transactions to be executed as part of this batch.
+    #[must_use]
+    pub fn get_inner_transactions(&self) -> &[AnyTransaction] {
+        &self.data().inner_transactions
+    }
+
+    /// Sets the transactions to be executed as part of this batch.
+    ///
+    /// # Errors
+    /// - If any of the transactions would error in [`add_inner_transaction`](Self::add_inner_transaction),
+    ///   the transactions before it are kept.
+    pub fn inner_transactions(
+        &mut self,
+        transactions: impl IntoIterator<Item = AnyTransaction>,
+    ) -> crate::Result<&mut Self> {
+        let data = self.data_mut();
+        data.inner_transactions.clear();
+        data.signed_transactions.clear();
+
+        for transaction in transactions {
+            self.add_inner_transaction(transaction)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Adds a transaction to be executed as part of this batch.
+    ///
+    /// # Errors
+    /// - [`Error::BatchInnerTransactionInvalid`] if the transaction:
+    ///   - isn't frozen.
+    ///   - has no `batch_key`.
+    ///   - has no explicit transaction ID.
+    ///   - is itself a `BatchTransaction`.
+    ///   - isn't a single chunk for a single node.
+    /// - If the transaction's signed bytes can't be made (see [`Transaction::to_bytes`]).
+    pub fn add_inner_transaction<D>(
+        &mut self,
+        transaction: Transaction<D>,
+    ) -> crate::Result<&mut Self>
+    where
+        D: TransactionExecute,
+    {
+        let invalid = |reason| Error::BatchInnerTransactionInvalid { reason };
+
+        let mut transaction = transaction.into_any();
+
+        if !transaction.is_frozen() {
+            return Err(invalid("it must be frozen"));
+        }
+
+        if transaction.get_batch_key().is_none() {
+            return Err(invalid("it must have a batch key"));
+        }
+
+        if transaction.get_transaction_id().is_none() {
+            return Err(invalid("it must have an explicit transaction ID"));
+        }
+
+        if matches!(transaction.data(), AnyTransactionData::Batch(_)) {
+            return Err(invalid("it's a `BatchTransaction`"));
+        }
+
+        // fix the transaction's bytes so that what's submitted is exactly what was signed.
+        let mut signed_transactions = transaction.signed_transaction_bytes()?;
+
+        let signed_transaction = match signed_transactions.pop() {
+            Some(it) if signed_transactions.is_empty() => it,
+            _ => return Err(invalid("it must be a single chunk for a single node")),
+        };
+
+        let data = self.data_mut();
+        data.inner_transactions.push(transaction);
+        data.signed_transactions.push(signed_transaction);
+
+        Ok(self)
+    }
+
+    /// Returns the transaction IDs of the transactions in this batch.
+    #[must_use]
+    pub fn get_inner_transaction_ids(&self) -> Vec<TransactionId> {
+        self.data().inner_transactions.iter().filter_map(Transaction::get_transaction_id).collect()
+    }
+}
+
+impl TransactionData for BatchTransactionData {}
+
+impl TransactionExecute for BatchTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { UtilServiceClient::new(channel).atomic_batch(request).await })
+    }
+}
+
+impl ValidateChecksums for BatchTransactionData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        for transaction in &self.inner_transactions {
+            transaction.validate_checksums(ledger_id)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl ToTransactionDataProtobuf for BatchTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::AtomicBatch(self.to_protobuf())
+    }
+}
+
+impl From<BatchTransactionData> for AnyTransactionData {
+    fn from(transaction: BatchTransactionData) -> Self {
+        Self::Batch(transaction)
+    }
+}
+
+impl FromProtobuf<services::AtomicBatchTransactionBody> for BatchTransactionData {
+    fn from_protobuf(pb: services::AtomicBatchTransactionBody) -> crate::Result<Self> {
+        let inner_transactions = pb
+            .transactions
+            .iter()
+            .map(|signed_transaction_bytes| {
+                AnyTransaction::from_transaction_list(Vec::from([services::Transaction {
+                    signed_transaction_bytes: signed_transaction_bytes.clone(),
+                    ..Default::default()
+                }]))
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self { inner_transactions, signed_transactions: pb.transactions })
+    }
+}
+
+impl ToProtobuf for BatchTransactionData {
+    type Protobuf = services::AtomicBatchTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::AtomicBatchTransactionBody { transactions: self.signed_transactions.clone() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use expect_test::expect;
+
+    use crate::protobuf::ToProtobuf;
+    use crate::transaction::test_helpers::{
+        check_body,
+        transaction_body,
+        unused_private_key,
+        TEST_TX_ID,
+    };
+    use crate::transaction::TransactionExecute;
+    use crate::{
+        AccountId,
+        AnyTransaction,
+        BatchTransaction,
+        Client,
+        Error,
+        Hbar,
+        Transaction,
+        TransferTransaction,
+    };
+
+    fn make_inner_transaction() -> TransferTransaction {
+        let mut tx = TransferTransaction::new();
+
+        tx.hbar_transfer(AccountId::new(0, 0, 5005), Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 5006), Hbar::new(1))
+            .node_account_ids([AccountId::new(0, 0, 0)])
+            .transaction_id(TEST_TX_ID)
+            .batch_key(unused_private_key().public_key())
+            .freeze()
+            .unwrap()
+            .sign(unused_private_key());
+
+        tx
+    }
+
+    fn make_transaction() -> BatchTransaction {
+        let mut tx = BatchTransaction::new_for_tests();
+
+        tx.add_inner_transaction(make_inner_transaction()).unwrap().freeze().unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn serialize() {
+        let tx = make_transaction();
+
+        let tx = transaction_body(tx);
+
+        let tx = check_body(tx);
+
+        expect![[r#"
+            AtomicBatch(
+                AtomicBatchTransactionBody {
+                    transactions: [
+                        [
+                            10,
+                            93,
+                            10,
+                            13,
+                            10,
+                            6,
+                            8,
+                            206,
+                            167,
+                            138,
+                            229,
+                            5,
+                            18,
+                            3,
+                            24,
+                            142,
+                            39,
+                            18,
+                            2,
+                            24,
+                            0,
+                            24,
+                            128,
+                            132,
+                            175,
+                            95,
+                            34,
+                            2,
+                            8,
+                            120,
+                            114,
+                            26,
+                            10,
+                            24,
+                            10,
+                            10,
+                            10,
+                            3,
+                            24,
+                            141,
+                            39,
+                            16,
+                            255,
+                            131,
+                            175,
+                            95,
+                            10,
+                            10,
+                            10,
+                            3,
+                            24,
+                            142,
+                            39,
+                            16,
+                            128,
+                            132,
+                            175,
+                            95,
+                            202,
+                            4,
+                            34,
+                            18,
+                            32,
+                            224,
+                            200,
+                            236,
+                            39,
+                            88,
+                            165,
+                            135,
+                            159,
+                            250,
+                            194,
+                            38,
+                            161,
+                            60,
+                            12,
+                            81,
+                            107,
+                            121,
+                            158,
+                            114,
+                            227,
+                            81,
+                            65,
+                            160,
+                            221,
+                            130,
+                            143,
+                            148,
+                            211,
+                            121,
+                            136,
+                            164,
+                            183,
+                            18,
+                            102,
+                            10,
+                            100,
+                            10,
+                            32,
+                            224,
+                            200,
+                            236,
+                            39,
+                            88,
+                            165,
+                            135,
+                            159,
+                            250,
+                            194,
+                            38,
+                            161,
+                            60,
+                            12,
+                            81,
+                            107,
+                            121,
+                            158,
+                            114,
+                            227,
+                            81,
+                            65,
+                            160,
+                            221,
+                            130,
+                            143,
+                            148,
+                            211,
+                            121,
+                            136,
+                            164,
+                            183,
+                            26,
+                            64,
+                            8,
+                            78,
+                            217,
+                            147,
+                            211,
+                            49,
+                            151,
+                            78,
+                            230,
+                            11,
+                            154,
+                            129,
+                            220,
+                            82,
+                            79,
+                            79,
+                            60,
+                            137,
+                            25,
+                            49,
+                            151,
+                            223,
+                            98,
+                            158,
+                            188,
+                            246,
+                            192,
+                            157,
+                            245,
+                            39,
+                            122,
+                            159,
+                            226,
+                            115,
+                            119,
+                            252,
+                            76,
+                            154,
+                            183,
+                            129,
+                            171,
+                            152,
+                            127,
+                            57,
+                            106,
+                            74,
+                            253,
+                            238,
+                            213,
+                            228,
+                            188,
+                            171,
+                            158,
+                            150,
+                            129,
+                            146,
+                            134,
+                            73,
+                            127,
+                            107,
+                            137,
+                            177,
+                            228,
+                            11,
+                        ],
+                    ],
+                },
+            )
+        "#]]
+        .assert_debug_eq(&tx)
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_body(tx);
+
+        let tx2 = transaction_body(tx2);
+
+        assert_eq!(tx, tx2);
+    }
+
+    #[test]
+    fn get_inner_transaction_ids() {
+        let tx = make_transaction();
+
+        assert_eq!(tx.get_inner_transaction_ids(), [TEST_TX_ID]);
+    }
+
+    #[test]
+    fn inner_transaction_round_trip() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+        let tx2 = tx2.downcast::<BatchTransaction>().unwrap();
+
+        let inner = &tx2.get_inner_transactions()[0];
+
+        assert_eq!(inner.get_transaction_id(), Some(TEST_TX_ID));
+        assert_eq!(inner.get_batch_key(), Some(&unused_private_key().public_key().into()));
+        assert_eq!(inner.get_node_account_ids(), Some([AccountId::new(0, 0, 0)].as_slice()));
+    }
+
+    fn add_error<D: TransactionExecute>(inner: Transaction<D>) -> String {
+        BatchTransaction::new().add_inner_transaction(inner).map(|_| ()).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn add_unfrozen_inner_transaction_errors() {
+        let mut inner = TransferTransaction::new();
+        inner.batch_key(unused_private_key().public_key());
+
+        expect!["transaction can't be an inner transaction of a batch: it must be frozen"]
+            .assert_eq(&add_error(inner));
+    }
+
+    #[test]
+    fn add_inner_transaction_without_batch_key_errors() {
+        let mut inner = TransferTransaction::new();
+        inner
+            .node_account_ids([AccountId::new(0, 0, 0)])
+            .transaction_id(TEST_TX_ID)
+            .freeze()
+            .unwrap();
+
+        expect!["transaction can't be an inner transaction of a batch: it must have a batch key"]
+            .assert_eq(&add_error(inner));
+    }
+
+    #[test]
+    fn add_batch_inner_transaction_errors() {
+        let mut inner = BatchTransaction::new();
+        inner
+            .node_account_ids([AccountId::new(0, 0, 0)])
+            .transaction_id(TEST_TX_ID)
+            .batch_key(unused_private_key().public_key())
+            .freeze()
+            .unwrap();
+
+        expect!["transaction can't be an inner transaction of a batch: it's a `BatchTransaction`"]
+            .assert_eq(&add_error(inner));
+    }
+
+    #[test]
+    fn add_multi_node_inner_transaction_errors() {
+        let mut inner = TransferTransaction::new();
+        inner
+            .node_account_ids([AccountId::new(0, 0, 0), AccountId::new(0, 0, 1)])
+            .transaction_id(TEST_TX_ID)
+            .batch_key(unused_private_key().public_key())
+            .freeze()
+            .unwrap();
+
+        expect!["transaction can't be an inner transaction of a batch: it must be a single chunk for a single node"]
+            .assert_eq(&add_error(inner));
+    }
+
+    #[test]
+    fn decoded_batch_reserializes_inner_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+        let tx2 = tx2.downcast::<BatchTransaction>().unwrap();
+
+        assert_eq!(tx2.data().to_protobuf(), tx.data().to_protobuf());
+    }
+
+    #[test]
+    fn batchify() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+        client.set_operator(AccountId::new(0, 0, 5005), unused_private_key());
+
+        let mut inner = TransferTransaction::new();
+        inner
+            .hbar_transfer(AccountId::new(0, 0, 5005), Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 5006), Hbar::new(1))
+            .batchify(&client, unused_private_key().public_key())
+            .unwrap();
+
+        assert_eq!(inner.get_node_account_ids(), Some([AccountId::new(0, 0, 0)].as_slice()));
+        assert_eq!(
+            inner.get_transaction_id().map(|it| it.account_id),
+            Some(AccountId::new(0, 0, 5005))
+        );
+
+        let mut tx = BatchTransaction::new();
+        tx.add_inner_transaction(inner).unwrap();
+
+        assert_eq!(tx.get_inner_transactions().len(), 1);
+    }
+
+    #[test]
+    fn batchify_without_operator() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        let result = TransferTransaction::new()
+            .batchify(&client, unused_private_key().public_key())
+            .map(|_| ());
+
+        assert!(matches!(result, Err(Error::NoPayerAccountOrTransactionId)), "{result:?}");
+    }
+}
@@ -0,0 +1,251 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! A fake consensus node, for exercising request execution end to end in tests.
+//!
+//! Requests are handed to a handler as raw protobuf bytes along with the name of the method called,
+//! the helpers at the bottom build the responses most tests need.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+
+use bytes::{
+    Buf,
+    BufMut,
+};
+use hedera_proto::services;
+use prost::Message;
+use tonic::body::BoxBody;
+use tonic::codec::{
+    Codec,
+    DecodeBuf,
+    Decoder,
+    EncodeBuf,
+    Encoder,
+};
+use tonic::codegen::{
+    http,
+    BoxFuture,
+    Context,
+    Poll,
+    Service,
+};
+use tonic::server::NamedService;
+use tonic::transport::Body;
+use triomphe::Arc;
+
+use crate::{
+    AccountId,
+    Client,
+    PrivateKey,
+};
+
+/// Starts a node that answers every request with `handler(method, request)`, returning its address.
+///
+/// The node runs until the runtime shuts down.
+pub(crate) async fn spawn<H>(handler: H) -> String
+where
+    H: Fn(&str, &[u8]) -> Result<Vec<u8>, tonic::Status> + Send + Sync + 'static,
+{
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let address = listener.local_addr().unwrap().to_string();
+
+    let handler = Arc::new(handler);
+
+    let incoming = async_stream::stream! {
+        loop {
+            yield listener.accept().await.map(|(stream, _)| stream);
+        }
+    };
+
+    let server = tonic::transport::Server::builder()
+        .add_service(CryptoService(handler.clone()))
+        .add_service(ConsensusService(handler.clone()))
+        .add_service(FileService(handler.clone()))
+        .add_service(SmartContractService(handler.clone()))
+        .add_service(TokenService(handler.clone()))
+        .add_service(ScheduleService(handler.clone()))
+        .add_service(NetworkService(handler.clone()))
+        .add_service(UtilService(handler))
+        .serve_with_incoming(incoming);
+
+    tokio::spawn(server);
+
+    address
+}
+
+/// Creates a client for the nodes at `addresses`, with node account IDs starting at `0.0.3`.
+///
+/// The operator is `0.0.2` with a freshly generated key.
+pub(crate) fn client(addresses: &[String]) -> Client {
+    let network: HashMap<_, _> = addresses
+        .iter()
+        .zip(3..)
+        .map(|(address, num)| (address.clone(), AccountId::new(0, 0, num)))
+        .collect();
+
+    let client = Client::for_network(network).unwrap();
+    client.set_operator(AccountId::new(0, 0, 2), PrivateKey::generate_ed25519());
+
+    client
+}
+
+/// A `TransactionResponse` with the given precheck code.
+pub(crate) fn transaction_response(code: services::ResponseCodeEnum) -> Vec<u8> {
+    services::TransactionResponse { node_transaction_precheck_code: code as i32, cost: 0 }
+        .encode_to_vec()
+}
+
+/// A receipt query response with an `OK` precheck and a receipt with the given status.
+pub(crate) fn receipt_response(status: services::ResponseCodeEnum) -> Vec<u8> {
+    use services::response::Response;
+
+    services::Response {
+        response: Some(Response::TransactionGetReceipt(services::TransactionGetReceiptResponse {
+            header: Some(ok_header()),
+            receipt: Some(services::TransactionReceipt {
+                status: status as i32,
+                ..Default::default()
+            }),
+            duplicate_transaction_receipts: Vec::new(),
+            child_transaction_receipts: Vec::new(),
+        })),
+    }
+    .encode_to_vec()
+}
+
+/// A query response header with an `OK` precheck.
+pub(crate) fn ok_header() -> services::ResponseHeader {
+    services::ResponseHeader {
+        node_transaction_precheck_code: services::ResponseCodeEnum::Ok as i32,
+        response_type: services::ResponseType::AnswerOnly as i32,
+        cost: 0,
+        state_proof: Vec::new(),
+    }
+}
+
+macro_rules! service {
+    ($($ident:ident),* $(,)?) => {
+        $(
+            struct $ident<H>(Arc<H>);
+
+            impl<H> Clone for $ident<H> {
+                fn clone(&self) -> Self {
+                    Self(self.0.clone())
+                }
+            }
+
+            impl<H> NamedService for $ident<H> {
+                const NAME: &'static str = concat!("proto.", stringify!($ident));
+            }
+
+            impl<H> Service<http::Request<Body>> for $ident<H>
+            where
+                H: Fn(&str, &[u8]) -> Result<Vec<u8>, tonic::Status> + Send + Sync + 'static,
+            {
+                type Response = http::Response<BoxBody>;
+                type Error = Infallible;
+                type Future = BoxFuture<Self::Response, Self::Error>;
+
+                fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+                    Poll::Ready(Ok(()))
+                }
+
+                fn call(&mut self, request: http::Request<Body>) -> Self::Future {
+                    let handler = self.0.clone();
+                    let method = request.uri().path().rsplit('/').next().unwrap_or_default().to_owned();
+
+                    Box::pin(async move {
+                        let method = Method { handler, method };
+
+                        Ok(tonic::server::Grpc::new(RawCodec).unary(method, request).await)
+                    })
+                }
+            }
+        )*
+    };
+}
+
+service!(
+    CryptoService,
+    ConsensusService,
+    FileService,
+    SmartContractService,
+    TokenService,
+    ScheduleService,
+    NetworkService,
+    UtilService,
+);
+
+struct Method<H> {
+    handler: Arc<H>,
+    method: String,
+}
+
+impl<H> tonic::server::UnaryService<Vec<u8>> for Method<H>
+where
+    H: Fn(&str, &[u8]) -> Result<Vec<u8>, tonic::Status>,
+{
+    type Response = Vec<u8>;
+    type Future = std::future::Ready<Result<tonic::Response<Vec<u8>>, tonic::Status>>;
+
+    fn call(&mut self, request: tonic::Request<Vec<u8>>) -> Self::Future {
+        std::future::ready(
+            (self.handler)(&self.method, request.get_ref()).map(tonic::Response::new),
+        )
+    }
+}
+
+/// Passes messages through as bytes, leaving (de)serialization to the handler.
+struct RawCodec;
+
+impl Codec for RawCodec {
+    type Encode = Vec<u8>;
+    type Decode = Vec<u8>;
+    type Encoder = RawCodec;
+    type Decoder = RawCodec;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        RawCodec
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        RawCodec
+    }
+}
+
+impl Encoder for RawCodec {
+    type Item = Vec<u8>;
+    type Error = tonic::Status;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+        dst.put_slice(&item);
+        Ok(())
+    }
+}
+
+impl Decoder for RawCodec {
+    type Item = Vec<u8>;
+    type Error = tonic::Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+        Ok(Some(src.copy_to_bytes(src.remaining()).to_vec()))
+    }
+}
@@ -40,11 +40,16 @@ pub(crate) use operator::Operator;
 use parking_lot::RwLock;
 use tokio::sync::watch;
 use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
 
 use self::network::managed::ManagedNetwork;
 use self::network::mirror::MirrorNetwork;
 pub(crate) use self::network::mirror::MirrorNetworkData;
 use crate::ping_query::PingQuery;
+use crate::query::QueryCostCache;
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
@@ -52,14 +57,20 @@ use crate::{
     Error,
     Hbar,
     LedgerId,
+    MirrorQueryPolicy,
     NodeAddressBook,
     PrivateKey,
     PublicKey,
+    QueryPaymentPolicy,
+    TransactionId,
+    TransactionIdGenerator,
 };
 
 #[cfg(feature = "serde")]
 mod config;
 
+#[cfg(test)]
+pub(crate) mod mock_node;
 mod network;
 mod operator;
 
@@ -96,6 +107,8 @@ struct ClientBuilder {
     regenerate_transaction_ids: bool,
     update_network: bool,
     backoff: ClientBackoff,
+    #[cfg(feature = "mirror-rest")]
+    mirror_rest_url: Option<String>,
 }
 
 impl ClientBuilder {
@@ -111,6 +124,8 @@ impl ClientBuilder {
             regenerate_transaction_ids: true,
             update_network: true,
             backoff: ClientBackoff::default(),
+            #[cfg(feature = "mirror-rest")]
+            mirror_rest_url: None,
         }
     }
 
@@ -122,6 +137,11 @@ impl ClientBuilder {
         Self { ledger_id, ..self }
     }
 
+    #[cfg(feature = "mirror-rest")]
+    fn mirror_rest_url(self, mirror_rest_url: &str) -> Self {
+        Self { mirror_rest_url: Some(mirror_rest_url.to_owned()), ..self }
+    }
+
     fn build(self) -> Client {
         let Self {
             network,
@@ -133,11 +153,17 @@ impl ClientBuilder {
             regenerate_transaction_ids,
             update_network,
             backoff,
+            #[cfg(feature = "mirror-rest")]
+            mirror_rest_url,
         } = self;
 
+        // shared with the network update task, so that it follows changes to the policy.
+        let mirror_query_policy = Arc::new(RwLock::new(MirrorQueryPolicy::default()));
+
         let network_update_tx = match update_network {
             true => network::managed::spawn_network_update(
                 network.clone(),
+                mirror_query_policy.clone(),
                 Some(Duration::from_secs(24 * 60 * 60)),
             ),
             // yeah, we just drop the rx.
@@ -154,8 +180,14 @@ impl ClientBuilder {
             ledger_id: ArcSwapOption::new(ledger_id.map(Arc::new)),
             auto_validate_checksums: AtomicBool::new(auto_validate_checksums),
             regenerate_transaction_ids: AtomicBool::new(regenerate_transaction_ids),
+            transaction_id_generator: RwLock::new(None),
+            query_payment_policy: ArcSwapOption::new(None),
+            query_cost_cache: QueryCostCache::default(),
             network_update_tx,
             backoff: RwLock::new(backoff),
+            mirror_query_policy,
+            #[cfg(feature = "mirror-rest")]
+            mirror_rest_url: ArcSwapOption::new(mirror_rest_url.map(Arc::new)),
         }))
     }
 }
@@ -168,8 +200,15 @@ struct ClientInner {
     ledger_id: ArcSwapOption<LedgerId>,
     auto_validate_checksums: AtomicBool,
     regenerate_transaction_ids: AtomicBool,
+    // not an `ArcSwapOption`, which can't hold unsized values.
+    transaction_id_generator: RwLock<Option<Arc<dyn TransactionIdGenerator>>>,
+    query_payment_policy: ArcSwapOption<QueryPaymentPolicy>,
+    query_cost_cache: QueryCostCache,
     network_update_tx: watch::Sender<Option<Duration>>,
     backoff: RwLock<ClientBackoff>,
+    mirror_query_policy: Arc<RwLock<MirrorQueryPolicy>>,
+    #[cfg(feature = "mirror-rest")]
+    mirror_rest_url: ArcSwapOption<String>,
 }
 
 /// Managed client for use on the Hedera network.
@@ -267,13 +306,37 @@ impl Client {
     /// Sets the addresses to use for the mirror network.
     ///
     /// This is mostly useful if you used [`Self::for_network`] and need to set a mirror network.
+    ///
+    /// Addresses are used in order of preference, a mirror node that fails is skipped until it has backed off.
+    /// Each address' host is used as the domain name to verify its TLS certificate against.
     pub fn set_mirror_network<I: IntoIterator<Item = String>>(&self, addresses: I) {
-        self.mirrornet().store(
-            MirrorNetworkData::from_addresses(addresses.into_iter().map(Cow::Owned).collect())
-                .into(),
+        self.set_mirror_endpoints(addresses.into_iter().map(|address| (Cow::Owned(address), None)));
+    }
+
+    /// Sets the addresses to use for the mirror network, each with the domain name to verify its TLS certificate against.
+    ///
+    /// Like [`Self::set_mirror_network`], but for mirror nodes that are reached through an address
+    /// other than the domain their certificate is issued for.
+    pub fn set_mirror_network_with_tls_domains<I: IntoIterator<Item = (String, String)>>(
+        &self,
+        endpoints: I,
+    ) {
+        self.set_mirror_endpoints(
+            endpoints
+                .into_iter()
+                .map(|(address, tls_domain)| (Cow::Owned(address), Some(Cow::Owned(tls_domain)))),
         );
     }
 
+    fn set_mirror_endpoints(
+        &self,
+        endpoints: impl IntoIterator<Item = (Cow<'static, str>, Option<Cow<'static, str>>)>,
+    ) {
+        let mirror = self.mirrornet().load().with_endpoints(endpoints);
+
+        self.mirrornet().store(mirror.into());
+    }
+
     /// Construct a client with the given nodes configured.
     ///
     /// Note that this disables network auto-updating.
@@ -292,21 +355,37 @@ impl Client {
     /// Construct a Hedera client pre-configured for mainnet access.
     #[must_use]
     pub fn for_mainnet() -> Self {
-        ClientBuilder::new(ManagedNetwork::mainnet()).ledger_id(Some(LedgerId::mainnet())).build()
+        let builder =
+            ClientBuilder::new(ManagedNetwork::mainnet()).ledger_id(Some(LedgerId::mainnet()));
+
+        #[cfg(feature = "mirror-rest")]
+        let builder = builder.mirror_rest_url(crate::mirror_rest::MAINNET_URL);
+
+        builder.build()
     }
 
     /// Construct a Hedera client pre-configured for testnet access.
     #[must_use]
     pub fn for_testnet() -> Self {
-        ClientBuilder::new(ManagedNetwork::testnet()).ledger_id(Some(LedgerId::testnet())).build()
+        let builder =
+            ClientBuilder::new(ManagedNetwork::testnet()).ledger_id(Some(LedgerId::testnet()));
+
+        #[cfg(feature = "mirror-rest")]
+        let builder = builder.mirror_rest_url(crate::mirror_rest::TESTNET_URL);
+
+        builder.build()
     }
 
     /// Construct a Hedera client pre-configured for previewnet access.
     #[must_use]
     pub fn for_previewnet() -> Self {
-        ClientBuilder::new(ManagedNetwork::previewnet())
-            .ledger_id(Some(LedgerId::previewnet()))
-            .build()
+        let builder = ClientBuilder::new(ManagedNetwork::previewnet())
+            .ledger_id(Some(LedgerId::previewnet()));
+
+        #[cfg(feature = "mirror-rest")]
+        let builder = builder.mirror_rest_url(crate::mirror_rest::PREVIEWNET_URL);
+
+        builder.build()
     }
 
     /// Updates the network to use the given address book.
@@ -432,6 +511,33 @@ impl Client {
         self.0.regenerate_transaction_ids.store(value, Ordering::Relaxed);
     }
 
+    /// Sets the generator used for transaction IDs that aren't explicitly set on a request.
+    ///
+    /// When unset (ex. `None::<MonotonicTransactionIdGenerator>`), [`TransactionId::generate`] is used.
+    pub fn set_transaction_id_generator<G: TransactionIdGenerator + 'static>(
+        &self,
+        generator: Option<G>,
+    ) {
+        *self.0.transaction_id_generator.write() = generator
+            .map(|generator| Arc::new(generator).unsize(Coercion!(to dyn TransactionIdGenerator)));
+    }
+
+    // keep this internal (repr)
+    pub(crate) fn full_load_transaction_id_generator(
+        &self,
+    ) -> Option<Arc<dyn TransactionIdGenerator>> {
+        self.0.transaction_id_generator.read().clone()
+    }
+
+    /// Generate a transaction ID for `account_id` with the configured [`TransactionIdGenerator`].
+    #[must_use]
+    pub fn generate_transaction_id(&self, account_id: AccountId) -> TransactionId {
+        match &*self.0.transaction_id_generator.read() {
+            Some(generator) => generator.generate(account_id),
+            None => TransactionId::generate(account_id),
+        }
+    }
+
     /// Sets the account that will, by default, be paying for transactions and queries built with
     /// this client.
     ///
@@ -440,9 +546,11 @@ impl Client {
     ///
     /// The operator private key is used to sign all transactions executed by this client.
     pub fn set_operator(&self, id: AccountId, key: PrivateKey) {
-        self.0
-            .operator
-            .store(Some(Arc::new(Operator { account_id: id, signer: AnySigner::PrivateKey(key) })));
+        self.0.operator.store(Some(Arc::new(Operator {
+            account_id: id,
+            signer: AnySigner::PrivateKey(key),
+            transaction_id_generator: None,
+        })));
     }
 
     /// Sets the account that will, by default, be paying for transactions and queries built with
@@ -461,6 +569,7 @@ impl Client {
         self.0.operator.store(Some(Arc::new(Operator {
             account_id: id,
             signer: AnySigner::arbitrary(Box::new(public_key), f),
+            transaction_id_generator: None,
         })));
     }
 
@@ -510,6 +619,26 @@ impl Client {
         self.0.max_query_payment_tinybar.store(amount.to_tinybars() as u64, Ordering::Relaxed);
     }
 
+    /// Returns the policy used to pay for queries that have no explicit payment amount, if any.
+    #[must_use]
+    pub fn query_payment_policy(&self) -> Option<QueryPaymentPolicy> {
+        self.0.query_payment_policy.load().as_deref().cloned()
+    }
+
+    /// Sets the policy used to pay for queries that have no explicit payment amount.
+    ///
+    /// `None` (the default) asks a node for the cost of every such query before executing it.
+    ///
+    /// Any cached cost quotes are discarded.
+    pub fn set_query_payment_policy(&self, policy: Option<QueryPaymentPolicy>) {
+        self.0.query_payment_policy.store(policy.map(Arc::new));
+        self.0.query_cost_cache.clear();
+    }
+
+    pub(crate) fn query_cost_cache(&self) -> &QueryCostCache {
+        &self.0.query_cost_cache
+    }
+
     /// Returns the maximum amount of time that will be spent on a request.
     #[must_use]
     pub fn request_timeout(&self) -> Option<Duration> {
@@ -556,6 +685,39 @@ impl Client {
         self.0.backoff.write().max_backoff = max_backoff;
     }
 
+    /// Returns the default policy for mirror node queries.
+    #[must_use]
+    pub fn mirror_query_policy(&self) -> MirrorQueryPolicy {
+        self.0.mirror_query_policy.read().clone()
+    }
+
+    /// Sets the default policy for mirror node queries.
+    ///
+    /// Settings made on individual queries take precedence.
+    /// The policy also applies to the address book queries of the client's network updates.
+    pub fn set_mirror_query_policy(&self, policy: MirrorQueryPolicy) {
+        *self.0.mirror_query_policy.write() = policy;
+    }
+
+    /// Returns the base url of the mirror node REST API, if one is configured.
+    ///
+    /// Clients made with [`for_mainnet`](Self::for_mainnet), [`for_testnet`](Self::for_testnet)
+    /// and [`for_previewnet`](Self::for_previewnet) use the public mirror node of that network.
+    #[cfg(feature = "mirror-rest")]
+    #[must_use]
+    pub fn mirror_rest_url(&self) -> Option<String> {
+        self.0.mirror_rest_url.load().as_deref().cloned()
+    }
+
+    /// Sets the base url of the mirror node REST API (ex. `http://127.0.0.1:5551/api/v1`).
+    ///
+    /// This is separate from [`set_mirror_network`](Self::set_mirror_network),
+    /// which only configures the gRPC addresses.
+    #[cfg(feature = "mirror-rest")]
+    pub fn set_mirror_rest_url(&self, url: Option<String>) {
+        self.0.mirror_rest_url.store(url.map(Arc::new));
+    }
+
     #[must_use]
     pub(crate) fn backoff(&self) -> ClientBackoff {
         *self.0.backoff.read()
@@ -571,6 +733,21 @@ impl Client {
         self.0.operator.load_full()
     }
 
+    /// Returns a copy of the operator that generates transaction IDs with the current [`TransactionIdGenerator`].
+    ///
+    /// Frozen transactions keep this around so that IDs generated after the fact (ex. in `to_bytes`)
+    /// come from the same generator as the ones generated during execution.
+    pub(crate) fn full_load_operator_with_generator(&self) -> Option<Arc<Operator>> {
+        let operator = self.load_operator();
+        let operator = operator.as_deref()?;
+
+        Some(Arc::new(Operator {
+            account_id: operator.account_id,
+            signer: operator.signer.clone(),
+            transaction_id_generator: self.full_load_transaction_id_generator(),
+        }))
+    }
+
     /// Send a ping to the given node.
     pub async fn ping(&self, node_account_id: AccountId) -> crate::Result<()> {
         PingQuery::new(node_account_id).execute(self, None).await
@@ -1,12 +1,16 @@
 use std::time::Duration;
 
+use parking_lot::RwLock;
 use rand::Rng;
 use tokio::sync::watch;
 use triomphe::Arc;
 
 use super::mirror::MirrorNetwork;
 use super::Network;
-use crate::NodeAddressBookQuery;
+use crate::{
+    MirrorQueryPolicy,
+    NodeAddressBookQuery,
+};
 
 #[derive(Clone)]
 pub(crate) struct ManagedNetwork(Arc<ManagedNetworkInner>);
@@ -53,12 +57,13 @@ pub(crate) struct ManagedNetworkInner {
 
 pub(crate) fn spawn_network_update(
     network: ManagedNetwork,
+    mirror_query_policy: Arc<RwLock<MirrorQueryPolicy>>,
     initial_update_interval: Option<Duration>,
 ) -> watch::Sender<Option<Duration>> {
     let (tx, rx) = watch::channel(initial_update_interval);
 
     // note: this 100% dies if there's no runtime.
-    tokio::task::spawn(update_network(network, rx));
+    tokio::task::spawn(update_network(network, mirror_query_policy, rx));
 
     tx
 }
@@ -68,6 +73,7 @@ pub(crate) fn spawn_network_update(
 // which will eventually lead to this function returning and the strong count being decremented.
 async fn update_network(
     network: ManagedNetwork,
+    mirror_query_policy: Arc<RwLock<MirrorQueryPolicy>>,
     mut update_interval_rx: watch::Receiver<Option<Duration>>,
 ) {
     tokio::time::sleep(ManagedNetwork::NETWORK_FIRST_UPDATE_DELAY).await;
@@ -78,8 +84,10 @@ async fn update_network(
 
         // note: ideally we'd have a `select!` on the channel closing, but, we can't
         // since there's no `async fn closed()`, and honestly, I'm not 100% certain these futures are cancel safe.
+        let policy = mirror_query_policy.read().clone();
+
         match NodeAddressBookQuery::new()
-            .execute_mirrornet(network.mirror.load().channel(), None)
+            .execute_mirrornet(network.mirror.load_full(), None, policy)
             .await
         {
             Ok(it) => network.primary.update_from_address_book(&it),
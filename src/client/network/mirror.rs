@@ -19,10 +19,15 @@
  */
 
 use std::borrow::Cow;
+use std::net::IpAddr;
 use std::ops::Deref;
-use std::time::Duration;
+use std::time::{
+    Duration,
+    Instant,
+};
 
 use once_cell::sync::OnceCell;
+use parking_lot::RwLock;
 use tonic::transport::{
     Channel,
     ClientTlsConfig,
@@ -30,6 +35,10 @@ use tonic::transport::{
 };
 use triomphe::Arc;
 
+use super::{
+    NodeBackoff,
+    NodeHealth,
+};
 use crate::ArcSwap;
 
 pub(crate) const MAINNET: &str = "mainnet-public.mirrornode.hedera.com:443";
@@ -63,9 +72,7 @@ impl MirrorNetwork {
     }
 
     fn network(address: &'static str) -> Self {
-        let tls_config = ClientTlsConfig::new().domain_name(address.split_once(':').unwrap().0);
-
-        Self(ArcSwap::new(Arc::new(MirrorNetworkData::from_static(&[address], tls_config))))
+        Self(ArcSwap::new(Arc::new(MirrorNetworkData::from_static(&[address]))))
     }
 
     #[cfg(feature = "serde")]
@@ -74,49 +81,208 @@ impl MirrorNetwork {
     }
 }
 
+/// The mirror nodes a client subscribes through, in order of preference.
 #[derive(Clone, Default)]
 pub(crate) struct MirrorNetworkData {
-    addresses: Vec<Cow<'static, str>>,
+    endpoints: Box<[MirrorEndpoint]>,
+    backoff: NodeBackoff,
+}
+
+#[derive(Clone)]
+struct MirrorEndpoint {
+    address: Cow<'static, str>,
+    // `None` for IP address hosts, which can't be a TLS server name.
+    tls_domain: Option<Cow<'static, str>>,
     channel: OnceCell<Channel>,
-    tls_config: ClientTlsConfig,
+    // Health has to be in an Arc so that it sticks around when the mirror network is replaced.
+    health: Arc<RwLock<NodeHealth>>,
 }
 
-impl MirrorNetworkData {
-    pub(crate) fn from_addresses(addresses: Vec<Cow<'static, str>>) -> Self {
-        Self { addresses, channel: OnceCell::new(), tls_config: ClientTlsConfig::new() }
-    }
+impl MirrorEndpoint {
+    fn new(address: Cow<'static, str>, tls_domain: Option<Cow<'static, str>>) -> Self {
+        let tls_domain = tls_domain.or_else(|| {
+            let host = match &address {
+                Cow::Borrowed(it) => Cow::Borrowed(it.rsplit_once(':').map_or(*it, |it| it.0)),
+                Cow::Owned(it) => {
+                    Cow::Owned(it.rsplit_once(':').map_or(&**it, |it| it.0).to_owned())
+                }
+            };
 
-    pub(crate) fn from_static(network: &[&'static str], tls_config: ClientTlsConfig) -> Self {
-        let mut addresses = Vec::with_capacity(network.len());
+            let is_ip =
+                host.trim_start_matches('[').trim_end_matches(']').parse::<IpAddr>().is_ok();
 
-        for address in network {
-            addresses.push(Cow::Borrowed(*address));
-        }
+            (!is_ip).then_some(host)
+        });
 
-        Self { addresses, channel: OnceCell::new(), tls_config }
+        Self { address, tls_domain, channel: OnceCell::new(), health: Arc::default() }
     }
 
-    pub(crate) fn channel(&self) -> Channel {
+    fn channel(&self) -> Channel {
         self.channel
             .get_or_init(|| {
-                let endpoints = self.addresses.iter().map(|address| {
-                    let uri = format!("tcp://{address}");
-                    Endpoint::from_shared(uri)
-                        .unwrap()
-                        .keep_alive_timeout(Duration::from_secs(10))
-                        .tls_config(self.tls_config.clone())
-                        .unwrap()
-                        .keep_alive_while_idle(true)
-                        .tcp_keepalive(Some(Duration::from_secs(10)))
-                        .connect_timeout(Duration::from_secs(10))
-                });
-
-                Channel::balance_list(endpoints)
+                let uri = format!("tcp://{}", self.address);
+
+                let tls = match &self.tls_domain {
+                    Some(domain) => ClientTlsConfig::new().domain_name(&**domain),
+                    None => ClientTlsConfig::new(),
+                };
+
+                Endpoint::from_shared(uri)
+                    .unwrap()
+                    .keep_alive_timeout(Duration::from_secs(10))
+                    .tls_config(tls)
+                    .unwrap()
+                    .keep_alive_while_idle(true)
+                    .tcp_keepalive(Some(Duration::from_secs(10)))
+                    .connect_timeout(Duration::from_secs(10))
+                    .connect_lazy()
             })
             .clone()
     }
+}
+
+impl MirrorNetworkData {
+    /// Create a mirror network where each address uses its host as the TLS domain.
+    pub(crate) fn from_addresses(addresses: Vec<Cow<'static, str>>) -> Self {
+        Self::default().with_endpoints(addresses.into_iter().map(|it| (it, None)))
+    }
+
+    pub(crate) fn from_static(network: &[&'static str]) -> Self {
+        Self::from_addresses(network.iter().copied().map(Cow::Borrowed).collect())
+    }
+
+    /// Create a mirror network from `(address, tls_domain)` pairs,
+    /// keeping the health (and connection) of every endpoint that's also in `self`.
+    pub(crate) fn with_endpoints(
+        &self,
+        endpoints: impl IntoIterator<Item = (Cow<'static, str>, Option<Cow<'static, str>>)>,
+    ) -> Self {
+        let endpoints = endpoints
+            .into_iter()
+            .map(|(address, tls_domain)| {
+                let new = MirrorEndpoint::new(address, tls_domain);
+
+                self.endpoints
+                    .iter()
+                    .find(|old| old.address == new.address && old.tls_domain == new.tls_domain)
+                    .cloned()
+                    .unwrap_or(new)
+            })
+            .collect();
+
+        Self { endpoints, backoff: self.backoff }
+    }
 
     pub(crate) fn addresses(&self) -> impl Iterator<Item = String> + '_ {
-        self.addresses.iter().cloned().map(Cow::into_owned)
+        self.endpoints.iter().map(|it| it.address.clone().into_owned())
+    }
+
+    /// Returns the index of the endpoint to use next.
+    ///
+    /// This is the first healthy endpoint, or the one that'll be healthy soonest if none are.
+    /// Returns `None` if the mirror network is empty.
+    pub(crate) fn pick_endpoint(&self, now: Instant) -> Option<usize> {
+        let healthy_at = |index: usize| match *self.endpoints[index].health.read() {
+            NodeHealth::Unhealthy { healthy_at, .. } if healthy_at >= now => Some(healthy_at),
+            _ => None,
+        };
+
+        (0..self.endpoints.len()).min_by_key(|it| healthy_at(*it))
+    }
+
+    pub(crate) fn channel(&self, index: usize) -> Channel {
+        self.endpoints[index].channel()
+    }
+
+    pub(crate) fn address(&self, index: usize) -> &str {
+        &self.endpoints[index].address
+    }
+
+    pub(crate) fn mark_endpoint_unhealthy(&self, index: usize) {
+        self.endpoints[index].health.write().mark_unhealthy(self.backoff, Instant::now());
+    }
+
+    pub(crate) fn mark_endpoint_healthy(&self, index: usize) {
+        self.endpoints[index].health.write().mark_healthy(Instant::now());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::time::{
+        Duration,
+        Instant,
+    };
+
+    use super::MirrorNetworkData;
+    use crate::client::network::{
+        NodeBackoff,
+        NodeHealth,
+    };
+
+    fn network() -> MirrorNetworkData {
+        MirrorNetworkData::from_addresses(vec![
+            Cow::Borrowed("mirror-1.example.com:443"),
+            Cow::Borrowed("mirror-2.example.com:443"),
+        ])
+    }
+
+    #[test]
+    fn tls_domain_defaults_to_each_host() {
+        let network = MirrorNetworkData::default().with_endpoints([
+            (Cow::Borrowed("mirror-1.example.com:443"), None),
+            (Cow::Borrowed("10.0.0.1:5600"), Some(Cow::Borrowed("mirror-2.example.com"))),
+            (Cow::Borrowed("10.0.0.2:5600"), None),
+            (Cow::Borrowed("[::1]:5600"), None),
+        ]);
+
+        let domains: Vec<_> = network.endpoints.iter().map(|it| it.tls_domain.as_deref()).collect();
+
+        // IP addresses aren't valid server names, so they're left to the TLS config's default.
+        assert_eq!(
+            domains,
+            [Some("mirror-1.example.com"), Some("mirror-2.example.com"), None, None]
+        );
+    }
+
+    #[test]
+    fn unhealthy_endpoints_are_skipped() {
+        let network = network();
+
+        assert_eq!(network.pick_endpoint(Instant::now()), Some(0));
+
+        network.mark_endpoint_unhealthy(0);
+        assert_eq!(network.pick_endpoint(Instant::now()), Some(1));
+
+        // with every endpoint unhealthy, the first one to recover is used.
+        *network.endpoints[1].health.write() = NodeHealth::Unhealthy {
+            backoff: NodeBackoff::default(),
+            healthy_at: Instant::now() + Duration::from_secs(60 * 60),
+            attempts: 1,
+        };
+        assert_eq!(network.pick_endpoint(Instant::now()), Some(0));
+
+        network.mark_endpoint_healthy(1);
+        assert_eq!(network.pick_endpoint(Instant::now()), Some(1));
+    }
+
+    #[test]
+    fn health_survives_replacing_the_network() {
+        let network = network();
+        network.mark_endpoint_unhealthy(0);
+
+        let network = network.with_endpoints([
+            (Cow::Borrowed("mirror-1.example.com:443"), None),
+            (Cow::Borrowed("mirror-3.example.com:443"), None),
+        ]);
+
+        assert_eq!(network.pick_endpoint(Instant::now()), Some(1));
+        assert_eq!(network.address(1), "mirror-3.example.com:443");
+    }
+
+    #[test]
+    fn empty_network_has_no_endpoint() {
+        assert_eq!(MirrorNetworkData::default().pick_endpoint(Instant::now()), None);
     }
 }
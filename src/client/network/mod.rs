@@ -27,7 +27,6 @@ use std::collections::{
     HashMap,
 };
 use std::fmt;
-use std::net::Ipv4Addr;
 use std::num::NonZeroUsize;
 use std::str::FromStr;
 use std::time::{
@@ -50,6 +49,7 @@ use crate::{
     ArcSwap,
     Error,
     NodeAddressBook,
+    ServiceEndpoint,
 };
 
 pub(crate) const MAINNET: &[(u64, &[&str])] = &[
@@ -225,7 +225,7 @@ impl NetworkData {
                 .service_endpoints
                 .iter()
                 .filter(|it| it.port() == NodeConnection::PLAINTEXT_PORT)
-                .map(|it| (*it.ip()).into())
+                .map(HostAndPort::from)
                 .collect();
 
             // if the node is the exact same we want to reuse everything (namely the connections and `healthy`).
@@ -562,9 +562,14 @@ impl fmt::Display for HostAndPort {
     }
 }
 
-impl From<Ipv4Addr> for HostAndPort {
-    fn from(value: Ipv4Addr) -> Self {
-        Self { host: Cow::Owned(value.to_string()), port: NodeConnection::PLAINTEXT_PORT }
+impl From<&ServiceEndpoint> for HostAndPort {
+    fn from(value: &ServiceEndpoint) -> Self {
+        let host = match value {
+            ServiceEndpoint::Ip(addr) => addr.ip().to_string(),
+            ServiceEndpoint::DomainName { domain_name, .. } => domain_name.clone(),
+        };
+
+        Self { host: Cow::Owned(host), port: value.port() }
     }
 }
 
@@ -1,14 +1,31 @@
+use std::fmt;
+
+use triomphe::Arc;
+
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
     PublicKey,
     TransactionId,
+    TransactionIdGenerator,
 };
 
-#[derive(Debug)]
 pub(crate) struct Operator {
     pub(crate) account_id: AccountId,
     pub(crate) signer: AnySigner,
+    /// The generator used by [`generate_transaction_id`](Self::generate_transaction_id).
+    ///
+    /// Only set on the copies that frozen transactions keep, see `Client::full_load_operator_with_generator`.
+    pub(crate) transaction_id_generator: Option<Arc<dyn TransactionIdGenerator>>,
+}
+
+impl fmt::Debug for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Operator")
+            .field("account_id", &self.account_id)
+            .field("signer", &self.signer)
+            .finish_non_exhaustive()
+    }
 }
 
 impl Operator {
@@ -19,6 +36,9 @@ impl Operator {
 
     #[must_use]
     pub(crate) fn generate_transaction_id(&self) -> TransactionId {
-        TransactionId::generate(self.account_id)
+        match &self.transaction_id_generator {
+            Some(generator) => generator.generate(self.account_id),
+            None => TransactionId::generate(self.account_id),
+        }
     }
 }
@@ -37,7 +37,7 @@ use crate::{
 };
 
 /// The result returned by a call to a smart contract function.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ContractFunctionResult {
     /// The smart contract instance whose function was called.
     pub contract_id: ContractId,
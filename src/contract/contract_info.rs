@@ -35,7 +35,7 @@ use crate::{
 };
 
 /// Current information on a smart contract instance.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ContractInfo {
     /// ID of the contract instance, in the format used by transactions.
     pub contract_id: ContractId,
@@ -73,7 +73,9 @@ pub struct ContractInfo {
     pub auto_renew_account_id: Option<AccountId>,
 
     /// The maximum number of tokens that a contract can be implicitly associated with.
-    pub max_automatic_token_associations: u32,
+    ///
+    /// A value of `-1` means the number of automatic associations is unlimited.
+    pub max_automatic_token_associations: i32,
 
     /// The ledger ID the response was returned from
     pub ledger_id: LedgerId,
@@ -136,7 +138,7 @@ impl FromProtobuf<services::contract_get_info_response::ContractInfo> for Contra
             auto_renew_period,
             auto_renew_account_id,
             contract_memo: pb.memo,
-            max_automatic_token_associations: pb.max_automatic_token_associations as u32,
+            max_automatic_token_associations: pb.max_automatic_token_associations,
             admin_key,
             storage: pb.storage as u64,
             ledger_id,
@@ -163,7 +165,7 @@ impl ToProtobuf for ContractInfo {
             deleted: self.is_deleted,
             ledger_id: self.ledger_id.to_bytes(),
             auto_renew_account_id: self.auto_renew_account_id.to_protobuf(),
-            max_automatic_token_associations: self.max_automatic_token_associations as i32,
+            max_automatic_token_associations: self.max_automatic_token_associations,
             staking_info: self.staking_info.to_protobuf(),
 
             // unimplemented fields
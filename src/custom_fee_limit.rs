@@ -0,0 +1,141 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+
+use crate::{
+    AccountId,
+    FixedFeeData,
+    FromProtobuf,
+    ToProtobuf,
+};
+
+/// The maximum custom fees that a payer is willing to pay for a transaction.
+///
+/// Used with [`TopicMessageSubmitTransaction`](crate::TopicMessageSubmitTransaction) to cap the
+/// custom fees charged by a topic.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct CustomFeeLimit {
+    /// The account that is willing to pay the fees, or `None` for the transaction payer.
+    pub payer_id: Option<AccountId>,
+
+    /// The maximum fees (in hbar or tokens) that the payer is willing to pay.
+    pub custom_fees: Vec<FixedFeeData>,
+}
+
+impl CustomFeeLimit {
+    /// Create a new `CustomFeeLimit` for the given payer and maximum fees.
+    #[must_use]
+    pub fn new(
+        payer_id: Option<AccountId>,
+        custom_fees: impl IntoIterator<Item = FixedFeeData>,
+    ) -> Self {
+        Self { payer_id, custom_fees: custom_fees.into_iter().collect() }
+    }
+}
+
+impl FromProtobuf<services::CustomFeeLimit> for CustomFeeLimit {
+    fn from_protobuf(pb: services::CustomFeeLimit) -> crate::Result<Self> {
+        Ok(Self {
+            payer_id: Option::from_protobuf(pb.account_id)?,
+            custom_fees: Vec::from_protobuf(pb.fees)?,
+        })
+    }
+}
+
+impl ToProtobuf for CustomFeeLimit {
+    type Protobuf = services::CustomFeeLimit;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::CustomFeeLimit {
+            account_id: self.payer_id.to_protobuf(),
+            fees: self.custom_fees.to_protobuf(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::{
+        AccountId,
+        CustomFeeLimit,
+        FixedFeeData,
+        Hbar,
+        TokenId,
+    };
+
+    fn make_limit() -> CustomFeeLimit {
+        CustomFeeLimit::new(
+            Some(AccountId::new(0, 0, 5006)),
+            [
+                FixedFeeData::from_hbar(Hbar::new(1)),
+                FixedFeeData { amount: 10, denominating_token_id: Some(TokenId::new(0, 0, 7)) },
+            ],
+        )
+    }
+
+    #[test]
+    fn to_protobuf() {
+        expect![[r#"
+            CustomFeeLimit {
+                account_id: Some(
+                    AccountId {
+                        shard_num: 0,
+                        realm_num: 0,
+                        account: Some(
+                            AccountNum(
+                                5006,
+                            ),
+                        ),
+                    },
+                ),
+                fees: [
+                    FixedFee {
+                        amount: 100000000,
+                        denominating_token_id: None,
+                    },
+                    FixedFee {
+                        amount: 10,
+                        denominating_token_id: Some(
+                            TokenId {
+                                shard_num: 0,
+                                realm_num: 0,
+                                token_num: 7,
+                            },
+                        ),
+                    },
+                ],
+            }
+        "#]].assert_debug_eq(&make_limit().to_protobuf());
+    }
+
+    #[test]
+    fn from_protobuf() {
+        let limit = make_limit();
+
+        assert_eq!(CustomFeeLimit::from_protobuf(limit.to_protobuf()).unwrap(), limit);
+    }
+}
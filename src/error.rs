@@ -24,8 +24,11 @@ use std::result::Result as StdResult;
 use crate::entity_id::Checksum;
 use crate::{
     AccountId,
+    AnyQueryResponse,
+    FinalityPhase,
     Hbar,
     Status,
+    TopicId,
     TransactionId,
 };
 
@@ -202,6 +205,109 @@ pub enum Error {
     /// Failed to verify a signature.
     #[error("failed to verify a signature: {0}")]
     SignatureVerify(#[source] BoxStdError),
+
+    /// Waiting for `transaction_id` to reach finality took longer than the configured deadline.
+    ///
+    /// `phase` is the step that was still in progress when the deadline passed.
+    #[error(
+        "transaction{} did not reach finality before the deadline (phase: {phase})",
+        .transaction_id.as_ref().map(|it| format!(" `{it}`")).unwrap_or_default()
+    )]
+    FinalityTimedOut {
+        /// The phase that timed out.
+        phase: FinalityPhase,
+        /// The [`Transaction`](crate::Transaction)'s ID.
+        transaction_id: Option<Box<TransactionId>>,
+        /// The most recent error encountered in `phase`, if any.
+        #[source]
+        last_error: Option<Box<Error>>,
+    },
+
+    /// Fewer nodes were available than a [`Query`](crate::Query)'s consistency check requires.
+    #[error("consistency check requires {required} nodes but only {available} are available")]
+    QueryConsistencyNodesUnavailable {
+        /// The number of distinct nodes the query was configured to be sent to.
+        required: usize,
+        /// The number of distinct nodes that were available.
+        available: usize,
+    },
+
+    /// Some of the nodes queried by a [`Query`](crate::Query)'s consistency check failed to respond,
+    /// so the responses couldn't be compared.
+    #[error("{} nodes failed during a consistency check", failures.len())]
+    QueryConsistencyNodesFailed {
+        /// Each failed node's account ID and the error it failed with.
+        failures: Vec<(AccountId, Error)>,
+    },
+
+    /// The nodes queried by a [`Query`](crate::Query)'s consistency check returned different responses.
+    #[error("query responses diverged across {} nodes", responses.len())]
+    QueryResponseDivergence {
+        /// Each node's account ID and the response it returned.
+        responses: Vec<(AccountId, AnyQueryResponse)>,
+    },
+
+    /// A topic message's running hash doesn't follow from the running hash of the message before it.
+    ///
+    /// Either the message or one before it was altered, or the mirror node is faulty.
+    #[error("running hash of message {sequence_number} on topic `{topic_id}` doesn't follow from the previous message")]
+    TopicRunningHashMismatch {
+        /// The topic the message was sent to.
+        topic_id: TopicId,
+        /// The sequence number of the message with the unexpected running hash.
+        sequence_number: u64,
+    },
+
+    /// A topic message's running hash couldn't be checked, and strict running hash verification was requested.
+    ///
+    /// See [`TopicMessageQuery::strict_running_hash`](crate::TopicMessageQuery::strict_running_hash).
+    #[error("running hash of message {sequence_number} on topic `{topic_id}` can't be verified")]
+    TopicRunningHashUnverifiable {
+        /// The topic the message was sent to.
+        topic_id: TopicId,
+        /// The sequence number of the message that couldn't be verified.
+        sequence_number: u64,
+    },
+
+    /// A topic message was skipped while verifying running hashes.
+    #[error("expected message {expected} on topic `{topic_id}` but received message {actual}")]
+    TopicSequenceNumberGap {
+        /// The topic the messages were sent to.
+        topic_id: TopicId,
+        /// The sequence number that should've been received next.
+        expected: u64,
+        /// The sequence number that was received.
+        actual: u64,
+    },
+
+    /// A request had no nodes to be sent to.
+    #[error("no nodes are available in the configured network")]
+    NetworkEmpty,
+
+    /// A mirror node query was made without any mirror network addresses configured.
+    #[error("no mirror network addresses are configured")]
+    MirrorNetworkEmpty,
+
+    /// A transaction couldn't be added to a [`BatchTransaction`](crate::BatchTransaction).
+    #[error("transaction can't be an inner transaction of a batch: {reason}")]
+    BatchInnerTransactionInvalid {
+        /// Why the transaction can't be part of a batch.
+        reason: &'static str,
+    },
+
+    /// Loading or saving a [`SubscriptionCheckpoint`](crate::SubscriptionCheckpoint) failed.
+    #[error("failed to load or save a subscription checkpoint: {0}")]
+    SubscriptionCheckpoint(#[source] BoxStdError),
+
+    /// A request to the mirror node REST API failed.
+    #[cfg(feature = "mirror-rest")]
+    #[error("mirror node REST request failed: {0}")]
+    MirrorRest(#[source] BoxStdError),
+
+    /// A request to the mirror node REST API was made without a REST API url configured.
+    #[cfg(feature = "mirror-rest")]
+    #[error("no mirror node REST API url is configured")]
+    MirrorRestUrlUnset,
 }
 
 impl Error {
@@ -224,6 +330,15 @@ impl Error {
     pub(crate) fn signature_verify(error: impl Into<BoxStdError>) -> Self {
         Self::SignatureVerify(error.into())
     }
+
+    pub(crate) fn subscription_checkpoint(error: impl Into<BoxStdError>) -> Self {
+        Self::SubscriptionCheckpoint(error.into())
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    pub(crate) fn mirror_rest(error: impl Into<BoxStdError>) -> Self {
+        Self::MirrorRest(error.into())
+    }
 }
 
 /// Failed to parse a mnemonic.
@@ -51,6 +51,7 @@ use crate::{
     Error,
     Status,
     TransactionId,
+    TransactionIdGenerator,
     ValidateChecksums,
 };
 
@@ -87,6 +88,11 @@ pub(crate) trait Execute: ValidateChecksums {
         None
     }
 
+    /// Returns whether a `DuplicateTransaction` pre-check should be treated as the request having already succeeded.
+    fn idempotent_resubmission(&self) -> bool {
+        false
+    }
+
     /// Check whether to retry an pre-check status.
     fn should_retry_pre_check(&self, _status: Status) -> bool {
         false
@@ -144,6 +150,16 @@ struct ExecuteContext {
     max_attempts: usize,
     // timeout for a single grpc request.
     grpc_timeout: Option<Duration>,
+    transaction_id_generator: Option<Arc<dyn TransactionIdGenerator>>,
+}
+
+impl ExecuteContext {
+    fn generate_transaction_id(&self, account_id: AccountId) -> TransactionId {
+        match &self.transaction_id_generator {
+            Some(generator) => generator.generate(account_id),
+            None => TransactionId::generate(account_id),
+        }
+    }
 }
 
 pub(crate) async fn execute<E>(
@@ -151,6 +167,32 @@ pub(crate) async fn execute<E>(
     executable: &E,
     timeout: Option<Duration>,
 ) -> crate::Result<E::Response>
+where
+    E: Execute + Sync,
+{
+    let backoff = client.backoff();
+    let mut backoff_builder = ExponentialBackoffBuilder::new();
+
+    backoff_builder
+        .with_initial_interval(backoff.initial_backoff)
+        .with_max_interval(backoff.max_backoff);
+
+    if let Some(timeout) = timeout.or(backoff.request_timeout) {
+        backoff_builder.with_max_elapsed_time(Some(timeout));
+    }
+
+    execute_with_backoff(client, executable, backoff_builder.build(), backoff.max_attempts).await
+}
+
+/// Like [`execute`], but with an explicit backoff instead of the one configured on the `client`.
+///
+/// This is used by things that need to poll on their own schedule (ex. waiting for a receipt).
+pub(crate) async fn execute_with_backoff<E>(
+    client: &Client,
+    executable: &E,
+    backoff_config: ExponentialBackoff,
+    max_attempts: usize,
+) -> crate::Result<E::Response>
 where
     E: Execute + Sync,
 {
@@ -178,24 +220,14 @@ where
             .or_else(|| client.load_operator().as_ref().map(|it| it.account_id))
     };
 
-    let backoff = client.backoff();
-    let mut backoff_builder = ExponentialBackoffBuilder::new();
-
-    backoff_builder
-        .with_initial_interval(backoff.initial_backoff)
-        .with_max_interval(backoff.max_backoff);
-
-    if let Some(timeout) = timeout.or(backoff.request_timeout) {
-        backoff_builder.with_max_elapsed_time(Some(timeout));
-    }
-
     execute_inner(
         &ExecuteContext {
-            max_attempts: backoff.max_attempts,
-            backoff_config: backoff_builder.build(),
+            max_attempts,
+            backoff_config,
             operator_account_id,
             network: client.net().0.load_full(),
-            grpc_timeout: backoff.grpc_timeout,
+            grpc_timeout: client.backoff().grpc_timeout,
+            transaction_id_generator: client.full_load_transaction_id_generator(),
         },
         executable,
     )
@@ -214,6 +246,7 @@ where
                 backoff_config: ctx.backoff_config.clone(),
                 max_attempts: ctx.max_attempts,
                 grpc_timeout: ctx.grpc_timeout,
+                transaction_id_generator: None,
             };
             let ping_query = PingQuery::new(ctx.network.node_ids()[index]);
 
@@ -229,10 +262,10 @@ where
     // if we need to generate a transaction ID for this request (and one was not provided),
     // generate one now
     let explicit_transaction_id = executable.transaction_id();
-    let mut transaction_id = executable
-        .requires_transaction_id()
-        .then_some(explicit_transaction_id)
-        .and_then(|it| it.or_else(|| ctx.operator_account_id.map(TransactionId::generate)));
+    let mut transaction_id =
+        executable.requires_transaction_id().then_some(explicit_transaction_id).and_then(|it| {
+            it.or_else(|| ctx.operator_account_id.map(|id| ctx.generate_transaction_id(id)))
+        });
 
     // if we were explicitly given a list of nodes to use, we iterate through each
     // of the given nodes (in a random order)
@@ -430,6 +463,19 @@ async fn execute_single<E: Execute + Sync>(
             .map(ControlFlow::Break)
             .map_err(retry::Error::Permanent),
 
+        Status::DuplicateTransaction if executable.idempotent_resubmission() => {
+            // an earlier submission of this exact transaction already made it to the network
+            // (ex. we timed out before seeing the response), so this is the response we were waiting for.
+            log::debug!(
+                "Treating duplicate transaction `{transaction_id:?}` as a successful resubmission"
+            );
+
+            executable
+                .make_response(response, context, node_account_id, transaction_id.as_ref())
+                .map(ControlFlow::Break)
+                .map_err(retry::Error::Permanent)
+        }
+
         Status::Busy | Status::PlatformNotActive => {
             // NOTE: this is a "busy" node
             // try the next node in our allowed list, immediately
@@ -443,7 +489,7 @@ async fn execute_single<E: Execute + Sync>(
             // the transaction that was generated has since expired
             // re-generate the transaction ID and try again, immediately
 
-            let new = TransactionId::generate(ctx.operator_account_id.unwrap());
+            let new = ctx.generate_transaction_id(ctx.operator_account_id.unwrap());
 
             *transaction_id = Some(new);
 
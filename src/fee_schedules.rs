@@ -409,6 +409,51 @@ pub enum RequestType {
 
     /// Update the metadata of one or more NFT's of a specific token type.
     TokenUpdateNfts,
+
+    /// Create a node.
+    NodeCreate,
+
+    /// Update a node.
+    NodeUpdate,
+
+    /// Delete a node.
+    NodeDelete,
+
+    /// Reject tokens held by an account, returning them to the treasury.
+    TokenReject,
+
+    /// Airdrop one or more tokens to one or more accounts.
+    TokenAirdrop,
+
+    /// Cancel one or more pending airdrops.
+    TokenCancelAirdrop,
+
+    /// Claim one or more pending airdrops.
+    TokenClaimAirdrop,
+
+    /// Submit a hinTS key publication.
+    HintsKeyPublication,
+
+    /// Submit a vote on hinTS preprocessing.
+    HintsPreprocessingVote,
+
+    /// Submit a hinTS partial signature.
+    HintsPartialSignature,
+
+    /// Submit a signature on a proof history assembly.
+    HistoryAssemblySignature,
+
+    /// Submit a proof history key publication.
+    HistoryProofKeyPublication,
+
+    /// Submit a vote on a proof history.
+    HistoryProofVote,
+
+    /// Submit a CRS publication.
+    CrsPublication,
+
+    /// Execute a batch of transactions atomically.
+    AtomicBatch,
 }
 
 impl FromProtobuf<services::HederaFunctionality> for RequestType {
@@ -490,6 +535,21 @@ impl FromProtobuf<services::HederaFunctionality> for RequestType {
             HederaFunctionality::UtilPrng => Self::UtilPrng,
             HederaFunctionality::TransactionGetFastRecord => Self::TransactionGetFastRecord,
             HederaFunctionality::TokenUpdateNfts => Self::TokenUpdateNfts,
+            HederaFunctionality::NodeCreate => Self::NodeCreate,
+            HederaFunctionality::NodeUpdate => Self::NodeUpdate,
+            HederaFunctionality::NodeDelete => Self::NodeDelete,
+            HederaFunctionality::TokenReject => Self::TokenReject,
+            HederaFunctionality::TokenAirdrop => Self::TokenAirdrop,
+            HederaFunctionality::TokenCancelAirdrop => Self::TokenCancelAirdrop,
+            HederaFunctionality::TokenClaimAirdrop => Self::TokenClaimAirdrop,
+            HederaFunctionality::HintsKeyPublication => Self::HintsKeyPublication,
+            HederaFunctionality::HintsPreprocessingVote => Self::HintsPreprocessingVote,
+            HederaFunctionality::HintsPartialSignature => Self::HintsPartialSignature,
+            HederaFunctionality::HistoryAssemblySignature => Self::HistoryAssemblySignature,
+            HederaFunctionality::HistoryProofKeyPublication => Self::HistoryProofKeyPublication,
+            HederaFunctionality::HistoryProofVote => Self::HistoryProofVote,
+            HederaFunctionality::CrsPublication => Self::CrsPublication,
+            HederaFunctionality::AtomicBatch => Self::AtomicBatch,
         };
 
         Ok(value)
@@ -577,6 +637,21 @@ impl ToProtobuf for RequestType {
             Self::UtilPrng => HederaFunctionality::UtilPrng,
             Self::TransactionGetFastRecord => HederaFunctionality::TransactionGetFastRecord,
             Self::TokenUpdateNfts => HederaFunctionality::TokenUpdateNfts,
+            Self::NodeCreate => HederaFunctionality::NodeCreate,
+            Self::NodeUpdate => HederaFunctionality::NodeUpdate,
+            Self::NodeDelete => HederaFunctionality::NodeDelete,
+            Self::TokenReject => HederaFunctionality::TokenReject,
+            Self::TokenAirdrop => HederaFunctionality::TokenAirdrop,
+            Self::TokenCancelAirdrop => HederaFunctionality::TokenCancelAirdrop,
+            Self::TokenClaimAirdrop => HederaFunctionality::TokenClaimAirdrop,
+            Self::HintsKeyPublication => HederaFunctionality::HintsKeyPublication,
+            Self::HintsPreprocessingVote => HederaFunctionality::HintsPreprocessingVote,
+            Self::HintsPartialSignature => HederaFunctionality::HintsPartialSignature,
+            Self::HistoryAssemblySignature => HederaFunctionality::HistoryAssemblySignature,
+            Self::HistoryProofKeyPublication => HederaFunctionality::HistoryProofKeyPublication,
+            Self::HistoryProofVote => HederaFunctionality::HistoryProofVote,
+            Self::CrsPublication => HederaFunctionality::CrsPublication,
+            Self::AtomicBatch => HederaFunctionality::AtomicBatch,
         }
     }
 }
@@ -755,6 +830,10 @@ pub enum FeeDataType {
     /// The resource prices are scoped to a [`ScheduleCreateTransaction`](crate::ScheduleCreateTransaction)
     /// containing a [`ContractExecuteTransaction`](crate::ContractExecuteTransaction).
     ScheduleCreateContractCall,
+
+    /// The resource prices are scoped to a [`TopicCreateTransaction`](crate::TopicCreateTransaction)
+    /// with custom fees.
+    TopicCreateWithCustomFees,
 }
 
 impl FromProtobuf<services::SubType> for FeeDataType {
@@ -769,6 +848,7 @@ impl FromProtobuf<services::SubType> for FeeDataType {
                 Self::TokenNonFungibleUniqueWithCustomFees
             }
             SubType::ScheduleCreateContractCall => Self::ScheduleCreateContractCall,
+            SubType::TopicCreateWithCustomFees => Self::TopicCreateWithCustomFees,
         };
 
         Ok(value)
@@ -789,6 +869,7 @@ impl ToProtobuf for FeeDataType {
                 SubType::TokenNonFungibleUniqueWithCustomFees
             }
             Self::ScheduleCreateContractCall => SubType::ScheduleCreateContractCall,
+            Self::TopicCreateWithCustomFees => SubType::TopicCreateWithCustomFees,
         }
     }
 }
@@ -26,7 +26,7 @@ use crate::{
 };
 
 /// Response from [`FileContentsQuery`][crate::FileContentsQuery].
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FileContentsResponse {
     /// The file ID of the file whose contents are being returned.
     pub file_id: FileId,
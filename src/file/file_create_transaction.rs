@@ -243,6 +243,7 @@ impl FromProtobuf<services::FileCreateTransactionBody> for FileCreateTransaction
 impl ToProtobuf for FileCreateTransactionData {
     type Protobuf = services::FileCreateTransactionBody;
 
+    #[allow(deprecated)]
     fn to_protobuf(&self) -> Self::Protobuf {
         services::FileCreateTransactionBody {
             expiration_time: self.expiration_time.to_protobuf(),
@@ -34,7 +34,7 @@ use crate::{
 };
 
 /// Response from [`FileInfoQuery`][crate::FileInfoQuery].
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct FileInfo {
     /// The file ID of the file for which information is requested.
     pub file_id: FileId,
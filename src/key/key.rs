@@ -54,6 +54,90 @@ impl Key {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Returns `true` if signatures by `signers` satisfy this key.
+    ///
+    /// A [`KeyList`] without a threshold requires every key in it, and one with a threshold requires that many of them.
+    /// Contract and delegate contract keys can't be satisfied by signatures, so they're never satisfied here.
+    /// Neither are key lists the network rejects as invalid: empty ones, and ones with a threshold of `0`
+    /// or more than the number of keys.
+    #[must_use]
+    pub fn is_satisfied_by(&self, signers: &[PublicKey]) -> bool {
+        match self {
+            Self::Single(key) => signers.contains(key),
+            Self::ContractId(_) | Self::DelegateContractId(_) => false,
+            Self::KeyList(list) => list.required().is_some_and(|required| {
+                list.keys.iter().filter(|key| key.is_satisfied_by(signers)).count() >= required
+            }),
+        }
+    }
+
+    /// Returns every minimal set of additional keys that, together with `signers`, would satisfy this key.
+    ///
+    /// No set contains another, and they're ordered from smallest to largest.
+    /// The result is `[[]]` if `signers` already satisfy this key, and empty if no set of signatures can,
+    /// for instance because it only accepts contract keys. See [`is_satisfied_by`](Self::is_satisfied_by) for the rules.
+    #[must_use]
+    pub fn missing_signers(&self, signers: &[PublicKey]) -> Vec<Vec<PublicKey>> {
+        let mut missing = self.missing_signer_sets(signers);
+
+        missing.sort_by_key(Vec::len);
+
+        missing
+    }
+
+    fn missing_signer_sets(&self, signers: &[PublicKey]) -> Vec<Vec<PublicKey>> {
+        let list = match self {
+            Self::Single(key) if signers.contains(key) => return vec![Vec::new()],
+            Self::Single(key) => return vec![vec![*key]],
+            Self::ContractId(_) | Self::DelegateContractId(_) => return Vec::new(),
+            Self::KeyList(list) => list,
+        };
+
+        let Some(required) = list.required() else {
+            return Vec::new();
+        };
+
+        // `satisfying[n]` holds the minimal sets that satisfy `n` of the keys seen so far
+        // (with `satisfying[required]` also covering more than `required`).
+        let mut satisfying: Vec<Vec<Vec<PublicKey>>> = vec![Vec::new(); required + 1];
+        satisfying[0].push(Vec::new());
+
+        for key in &list.keys {
+            let key_sets = key.missing_signer_sets(signers);
+
+            for n in (1..=required).rev() {
+                let mut sets = std::mem::take(&mut satisfying[n]);
+
+                for set in &satisfying[n - 1] {
+                    for key_set in &key_sets {
+                        let mut union = set.clone();
+                        union.extend(key_set.iter().copied().filter(|it| !set.contains(it)));
+                        sets.push(union);
+                    }
+                }
+
+                satisfying[n] = minimal_sets(sets);
+            }
+        }
+
+        satisfying.swap_remove(required)
+    }
+}
+
+/// Removes every set that contains another (or is equal to an earlier one).
+fn minimal_sets(mut sets: Vec<Vec<PublicKey>>) -> Vec<Vec<PublicKey>> {
+    sets.sort_by_key(Vec::len);
+
+    let mut minimal: Vec<Vec<PublicKey>> = Vec::with_capacity(sets.len());
+
+    for set in sets {
+        if !minimal.iter().any(|it| it.iter().all(|key| set.contains(key))) {
+            minimal.push(set);
+        }
+    }
+
+    minimal
 }
 
 impl ToProtobuf for Key {
@@ -136,6 +220,8 @@ mod tests {
     use crate::protobuf::FromProtobuf;
     use crate::{
         Key,
+        KeyList,
+        PrivateKey,
         PublicKey,
     };
 
@@ -229,4 +315,60 @@ mod tests {
 
         assert_matches!(Key::from_protobuf(key), Err(crate::Error::FromProtobuf(_)));
     }
+
+    fn keys<const N: usize>() -> [PublicKey; N] {
+        std::array::from_fn(|_| PrivateKey::generate_ed25519().public_key())
+    }
+
+    fn threshold(threshold: u32, keys: impl IntoIterator<Item = Key>) -> Key {
+        Key::KeyList(KeyList { keys: keys.into_iter().collect(), threshold: Some(threshold) })
+    }
+
+    #[test]
+    fn key_list_requires_every_key() {
+        let [a, b] = keys();
+        let key = Key::KeyList(KeyList::from([a, b]));
+
+        assert!(key.is_satisfied_by(&[a, b]));
+        assert!(!key.is_satisfied_by(&[a]));
+        assert_eq!(key.missing_signers(&[a]), [vec![b]]);
+        assert_eq!(key.missing_signers(&[a, b]), [Vec::<PublicKey>::new()]);
+    }
+
+    #[test]
+    fn nested_threshold_key() {
+        let [a, b, c, d] = keys();
+
+        // 2 of: a, b, (all of c and d)
+        let key = threshold(2, [a.into(), b.into(), KeyList::from([c, d]).into()]);
+
+        assert!(key.is_satisfied_by(&[a, c, d]));
+        assert!(!key.is_satisfied_by(&[a, c]));
+
+        assert_eq!(key.missing_signers(&[a, c]), [vec![b], vec![d]]);
+        assert_eq!(key.missing_signers(&[c]), [vec![a, b], vec![a, d], vec![b, d]]);
+        assert_eq!(key.missing_signers(&[]), [vec![a, b], vec![a, c, d], vec![b, c, d]]);
+    }
+
+    #[test]
+    fn contract_keys_are_unsatisfiable() {
+        let [a] = keys();
+        let contract = Key::ContractId(crate::ContractId::new(0, 0, 1001));
+
+        let key = threshold(1, [contract.clone(), a.into()]);
+        assert_eq!(key.missing_signers(&[]), [vec![a]]);
+
+        let key = Key::KeyList(KeyList::from([contract, a.into()]));
+        assert!(!key.is_satisfied_by(&[a]));
+        assert!(key.missing_signers(&[a]).is_empty());
+    }
+
+    #[test]
+    fn invalid_key_lists_are_unsatisfiable() {
+        let [a] = keys();
+
+        assert!(!Key::KeyList(KeyList::new()).is_satisfied_by(&[a]));
+        assert!(!threshold(0, [a.into()]).is_satisfied_by(&[a]));
+        assert!(threshold(2, [a.into()]).missing_signers(&[a]).is_empty());
+    }
 }
@@ -54,6 +54,16 @@ impl KeyList {
         self.keys.remove(index)
     }
 
+    /// Returns how many of the keys must sign, or `None` if the network would reject this key list.
+    pub(crate) fn required(&self) -> Option<usize> {
+        let required = match self.threshold {
+            Some(threshold) => usize::try_from(threshold).ok()?,
+            None => self.keys.len(),
+        };
+
+        (1..=self.keys.len()).contains(&required).then_some(required)
+    }
+
     // why not `ToProtobuf`? because `ToProtobuf` should return a `KeyList`.
     pub(crate) fn to_protobuf_key(&self) -> services::key::Key {
         let key_list = services::KeyList { keys: self.keys.to_protobuf() };
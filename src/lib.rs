@@ -118,8 +118,10 @@
 mod protobuf;
 
 mod account;
+mod batch_transaction;
 mod client;
 mod contract;
+mod custom_fee_limit;
 mod downcast;
 mod entity_id;
 mod error;
@@ -132,10 +134,13 @@ mod hbar;
 mod key;
 mod ledger_id;
 mod mirror_query;
+#[cfg(feature = "mirror-rest")]
+mod mirror_rest;
 #[cfg(feature = "mnemonic")]
 mod mnemonic;
 mod network_version_info;
 mod network_version_info_query;
+mod node;
 mod node_address;
 mod node_address_book;
 mod node_address_book_query;
@@ -145,15 +150,18 @@ mod query;
 mod retry;
 mod schedule;
 mod semantic_version;
+mod service_endpoint;
 mod signer;
 mod staked_id;
 mod staking_info;
+mod submitter;
 mod system;
 mod token;
 mod topic;
 mod transaction;
 mod transaction_hash;
 mod transaction_id;
+mod transaction_id_generator;
 mod transaction_receipt;
 mod transaction_receipt_query;
 mod transaction_record;
@@ -177,8 +185,13 @@ pub use account::{
     AccountStakersQuery,
     AccountUpdateTransaction,
     AllProxyStakers,
+    LiveHash,
+    LiveHashAddTransaction,
+    LiveHashDeleteTransaction,
+    LiveHashQuery,
     ProxyStaker,
 };
+pub use batch_transaction::BatchTransaction;
 pub use client::Client;
 pub(crate) use client::Operator;
 pub use contract::{
@@ -198,6 +211,7 @@ pub use contract::{
     ContractUpdateTransaction,
     DelegateContractId,
 };
+pub use custom_fee_limit::CustomFeeLimit;
 pub use entity_id::EntityId;
 pub(crate) use entity_id::ValidateChecksums;
 pub use error::{
@@ -258,12 +272,43 @@ pub use mirror_query::{
     AnyMirrorQuery,
     AnyMirrorQueryResponse,
     MirrorQuery,
+    MirrorQueryPolicy,
+    SubscriptionHandle,
+    SubscriptionState,
+};
+#[cfg(feature = "mirror-rest")]
+pub use mirror_rest::{
+    ContractLogEntry,
+    ContractLogQuery,
+    MirrorAccount,
+    MirrorAccountBalance,
+    MirrorContract,
+    MirrorContractCallData,
+    MirrorContractCallQuery,
+    MirrorContractEstimateGasData,
+    MirrorContractEstimateGasQuery,
+    MirrorContractLog,
+    MirrorContractQuery,
+    MirrorContractResult,
+    MirrorNft,
+    MirrorRestClient,
+    MirrorToken,
+    MirrorTokenBalance,
+    MirrorTokenRelationship,
+    MirrorTokenTransfer,
+    MirrorTransaction,
+    MirrorTransfer,
 };
 #[cfg(feature = "mnemonic")]
 pub use mnemonic::Mnemonic;
 pub use network_version_info::NetworkVersionInfo;
 pub use network_version_info_query::NetworkVersionInfoQuery;
 pub(crate) use network_version_info_query::NetworkVersionInfoQueryData;
+pub use node::{
+    NodeCreateTransaction,
+    NodeDeleteTransaction,
+    NodeUpdateTransaction,
+};
 pub use node_address::NodeAddress;
 pub use node_address_book::NodeAddressBook;
 pub use node_address_book_query::NodeAddressBookQuery;
@@ -277,6 +322,7 @@ pub use query::{
     AnyQuery,
     AnyQueryResponse,
     Query,
+    QueryPaymentPolicy,
 };
 pub(crate) use retry::retry;
 pub use schedule::{
@@ -288,7 +334,12 @@ pub use schedule::{
     ScheduleSignTransaction,
 };
 pub use semantic_version::SemanticVersion;
+pub use service_endpoint::ServiceEndpoint;
 pub use staking_info::StakingInfo;
+pub use submitter::{
+    SubmitResult,
+    Submitter,
+};
 pub use system::{
     FreezeTransaction,
     FreezeType,
@@ -306,11 +357,16 @@ pub use token::{
     FractionalFee,
     FractionalFeeData,
     NftId,
+    PendingAirdropId,
+    PendingAirdropRecord,
     RoyaltyFee,
     RoyaltyFeeData,
+    TokenAirdropTransaction,
     TokenAssociateTransaction,
     TokenAssociation,
     TokenBurnTransaction,
+    TokenCancelAirdropTransaction,
+    TokenClaimAirdropTransaction,
     TokenCreateTransaction,
     TokenDeleteTransaction,
     TokenDissociateTransaction,
@@ -325,6 +381,7 @@ pub use token::{
     TokenNftInfoQuery,
     TokenNftTransfer,
     TokenPauseTransaction,
+    TokenRejectTransaction,
     TokenRevokeKycTransaction,
     TokenSupplyType,
     TokenType,
@@ -335,22 +392,39 @@ pub use token::{
     TokenWipeTransaction,
 };
 pub use topic::{
+    ChunkReassemblyPolicy,
+    FileSubscriptionCheckpoint,
+    IncompleteTopicMessage,
+    IncompleteTopicMessageChunk,
+    IncompleteTopicMessageReason,
+    MultiTopicSubscription,
+    SubscriptionCheckpoint,
+    TopicCheckpoint,
     TopicCreateTransaction,
     TopicDeleteTransaction,
     TopicId,
     TopicInfo,
     TopicInfoQuery,
     TopicMessage,
+    TopicMessageItem,
     TopicMessageQuery,
     TopicMessageSubmitTransaction,
     TopicUpdateTransaction,
 };
 pub use transaction::{
     AnyTransaction,
+    FinalityPhase,
+    FinalityPolling,
+    FinalityStrategy,
     Transaction,
+    TransactionOutcome,
 };
 pub use transaction_hash::TransactionHash;
 pub use transaction_id::TransactionId;
+pub use transaction_id_generator::{
+    MonotonicTransactionIdGenerator,
+    TransactionIdGenerator,
+};
 pub use transaction_receipt::TransactionReceipt;
 pub use transaction_receipt_query::TransactionReceiptQuery;
 pub use transaction_record::TransactionRecord;
@@ -23,12 +23,13 @@ use futures_core::stream::BoxStream;
 use futures_util::TryStreamExt;
 
 use super::subscribe::MirrorQueryExecute;
+use super::SubscriptionControl;
 use crate::topic::TopicMessageQueryData;
 use crate::{
     MirrorQuery,
     NodeAddress,
     NodeAddressBookQueryData,
-    TopicMessage,
+    TopicMessageItem,
 };
 
 /// Represents any possible query to the mirror network.
@@ -43,7 +44,7 @@ pub enum AnyMirrorQueryData {
 #[derive(Debug, Clone)]
 pub enum AnyMirrorQueryMessage {
     NodeAddressBook(NodeAddress),
-    TopicMessage(TopicMessage),
+    TopicMessage(TopicMessageItem),
 }
 
 /// Represents the response of any possible query to the mirror network.
@@ -59,26 +60,28 @@ impl MirrorQueryExecute for AnyMirrorQueryData {
 
     type Response = AnyMirrorQueryResponse;
 
-    type ItemStream<'a> = BoxStream<'a, crate::Result<Self::Item>>
-        where
-            Self: 'a;
+    type ItemStream<'a>
+        = BoxStream<'a, crate::Result<Self::Item>>
+    where
+        Self: 'a;
 
     fn subscribe_with_optional_timeout<'a>(
         &self,
         params: &crate::mirror_query::MirrorQueryCommon,
         client: &'a crate::Client,
         timeout: Option<std::time::Duration>,
+        control: SubscriptionControl,
     ) -> Self::ItemStream<'a>
     where
         Self: 'a,
     {
         match self {
             AnyMirrorQueryData::NodeAddressBook(it) => Box::pin(
-                it.subscribe_with_optional_timeout(params, client, timeout)
+                it.subscribe_with_optional_timeout(params, client, timeout, control)
                     .map_ok(Self::Item::from),
             ),
             AnyMirrorQueryData::TopicMessage(it) => Box::pin(
-                it.subscribe_with_optional_timeout(params, client, timeout)
+                it.subscribe_with_optional_timeout(params, client, timeout, control)
                     .map_ok(Self::Item::from),
             ),
         }
@@ -19,7 +19,11 @@
  */
 
 mod any;
+mod policy;
 mod subscribe;
+mod subscription_handle;
+
+use std::time::Duration;
 
 pub(crate) use any::AnyMirrorQueryData;
 pub use any::{
@@ -27,27 +31,79 @@ pub use any::{
     AnyMirrorQueryMessage,
     AnyMirrorQueryResponse,
 };
+pub use policy::MirrorQueryPolicy;
 pub(crate) use subscribe::{
     subscribe,
     MirrorRequest,
 };
+pub(crate) use subscription_handle::SubscriptionControl;
+pub use subscription_handle::{
+    SubscriptionHandle,
+    SubscriptionState,
+};
 
 use self::subscribe::MirrorQueryExecute;
+use crate::Client;
 
 /// A query that can be executed on the Hedera mirror network.
 #[derive(Clone, Debug, Default)]
 pub struct MirrorQuery<D> {
     pub(crate) data: D,
-    // Field needs to exist even though it currently does nothing
-    #[allow(dead_code)]
     pub(crate) common: MirrorQueryCommon,
 }
 
 // intentionally inaccessable despite publicity.
 #[derive(Clone, Debug, Default)]
 pub struct MirrorQueryCommon {
-    // empty for now
-    // TODO: request_timeout
+    request_timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    idle_timeout: Option<Duration>,
+    max_attempts: Option<usize>,
+    min_backoff: Option<Duration>,
+    max_backoff: Option<Duration>,
+    retry_codes: Option<Vec<tonic::Code>>,
+}
+
+impl MirrorQueryCommon {
+    /// Returns the explicitly passed `timeout`, falling back to this query's and then the client's request timeout.
+    pub(crate) fn request_timeout(
+        &self,
+        client: &Client,
+        timeout: Option<Duration>,
+    ) -> Option<Duration> {
+        timeout.or(self.request_timeout).or_else(|| client.request_timeout())
+    }
+
+    /// Returns the client's mirror query policy with this query's overrides applied.
+    pub(crate) fn policy(&self, client: &Client) -> MirrorQueryPolicy {
+        let mut policy = client.mirror_query_policy();
+
+        if let Some(timeout) = self.connect_timeout {
+            policy.connect_timeout(Some(timeout));
+        }
+
+        if let Some(timeout) = self.idle_timeout {
+            policy.idle_timeout(Some(timeout));
+        }
+
+        if let Some(attempts) = self.max_attempts {
+            policy.max_attempts(Some(attempts));
+        }
+
+        if let Some(backoff) = self.min_backoff {
+            policy.min_backoff(backoff);
+        }
+
+        if let Some(backoff) = self.max_backoff {
+            policy.max_backoff(backoff);
+        }
+
+        if let Some(codes) = &self.retry_codes {
+            policy.retry_codes(codes.iter().copied());
+        }
+
+        policy
+    }
 }
 
 impl<D> MirrorQuery<D>
@@ -60,3 +116,103 @@ where
         Self::default()
     }
 }
+
+impl<D> MirrorQuery<D> {
+    /// Returns the maximum amount of time spent retrying this query, if set on the query.
+    #[must_use]
+    pub fn get_request_timeout(&self) -> Option<Duration> {
+        self.common.request_timeout
+    }
+
+    /// Sets the maximum amount of time spent retrying this query.
+    ///
+    /// Overrides [`Client::request_timeout`].
+    pub fn request_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.common.request_timeout = Some(timeout);
+        self
+    }
+
+    /// Returns how long establishing a stream may take, if set on the query.
+    #[must_use]
+    pub fn get_connect_timeout(&self) -> Option<Duration> {
+        self.common.connect_timeout
+    }
+
+    /// Sets how long establishing a stream may take before the attempt is retried.
+    ///
+    /// Overrides [`MirrorQueryPolicy::connect_timeout`].
+    pub fn connect_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.common.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Returns how long a stream may go without receiving an item, if set on the query.
+    #[must_use]
+    pub fn get_idle_timeout(&self) -> Option<Duration> {
+        self.common.idle_timeout
+    }
+
+    /// Sets how long a stream may go without receiving an item before it's re-established.
+    ///
+    /// Overrides [`MirrorQueryPolicy::idle_timeout`].
+    pub fn idle_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.common.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the maximum number of consecutive failed attempts, if set on the query.
+    #[must_use]
+    pub fn get_max_attempts(&self) -> Option<usize> {
+        self.common.max_attempts
+    }
+
+    /// Sets the maximum number of consecutive failed attempts to establish a stream.
+    ///
+    /// Overrides [`MirrorQueryPolicy::max_attempts`].
+    pub fn max_attempts(&mut self, attempts: usize) -> &mut Self {
+        self.common.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Returns the initial backoff between attempts, if set on the query.
+    #[must_use]
+    pub fn get_min_backoff(&self) -> Option<Duration> {
+        self.common.min_backoff
+    }
+
+    /// Sets the initial backoff between attempts.
+    ///
+    /// Overrides [`MirrorQueryPolicy::min_backoff`].
+    pub fn min_backoff(&mut self, backoff: Duration) -> &mut Self {
+        self.common.min_backoff = Some(backoff);
+        self
+    }
+
+    /// Returns the maximum backoff between attempts, if set on the query.
+    #[must_use]
+    pub fn get_max_backoff(&self) -> Option<Duration> {
+        self.common.max_backoff
+    }
+
+    /// Sets the maximum backoff between attempts.
+    ///
+    /// Overrides [`MirrorQueryPolicy::max_backoff`].
+    pub fn max_backoff(&mut self, backoff: Duration) -> &mut Self {
+        self.common.max_backoff = Some(backoff);
+        self
+    }
+
+    /// Returns the gRPC status codes that cause a stream to be re-established, if set on the query.
+    #[must_use]
+    pub fn get_retry_codes(&self) -> Option<&[tonic::Code]> {
+        self.common.retry_codes.as_deref()
+    }
+
+    /// Sets the gRPC status codes that cause a stream to be re-established.
+    ///
+    /// Overrides [`MirrorQueryPolicy::retry_codes`].
+    pub fn retry_codes(&mut self, codes: impl IntoIterator<Item = tonic::Code>) -> &mut Self {
+        self.common.retry_codes = Some(codes.into_iter().collect());
+        self
+    }
+}
@@ -0,0 +1,156 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::time::Duration;
+
+use backoff::ExponentialBackoff;
+
+/// How mirror node subscriptions time out and re-establish their streams.
+///
+/// A client-wide default is configured with [`Client::set_mirror_query_policy`](crate::Client::set_mirror_query_policy),
+/// and every setting can be overridden per query on [`MirrorQuery`](crate::MirrorQuery).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MirrorQueryPolicy {
+    connect_timeout: Option<Duration>,
+    idle_timeout: Option<Duration>,
+    max_attempts: Option<usize>,
+    min_backoff: Duration,
+    max_backoff: Duration,
+    retry_codes: Vec<tonic::Code>,
+}
+
+impl Default for MirrorQueryPolicy {
+    fn default() -> Self {
+        Self {
+            connect_timeout: None,
+            idle_timeout: None,
+            max_attempts: None,
+            min_backoff: Duration::from_millis(backoff::default::INITIAL_INTERVAL_MILLIS),
+            max_backoff: Duration::from_millis(backoff::default::MAX_INTERVAL_MILLIS),
+            retry_codes: vec![tonic::Code::Unavailable, tonic::Code::ResourceExhausted],
+        }
+    }
+}
+
+impl MirrorQueryPolicy {
+    /// Create a new mirror query policy with the default values.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns how long establishing a stream may take before the attempt is retried.
+    #[must_use]
+    pub fn get_connect_timeout(&self) -> Option<Duration> {
+        self.connect_timeout
+    }
+
+    /// Sets how long establishing a stream may take before the attempt is retried.
+    ///
+    /// Defaults to `None`, which waits for as long as the connection takes.
+    pub fn connect_timeout(&mut self, timeout: Option<Duration>) -> &mut Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// Returns how long a stream may go without receiving an item before it's re-established.
+    #[must_use]
+    pub fn get_idle_timeout(&self) -> Option<Duration> {
+        self.idle_timeout
+    }
+
+    /// Sets how long a stream may go without receiving an item before it's re-established.
+    ///
+    /// Subscriptions resume after the last item they received, so nothing is lost when this happens.
+    /// Time spent paused doesn't count.
+    /// Defaults to `None`, which keeps an idle stream open indefinitely.
+    pub fn idle_timeout(&mut self, timeout: Option<Duration>) -> &mut Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// Returns the maximum number of consecutive failed attempts to establish a stream.
+    #[must_use]
+    pub fn get_max_attempts(&self) -> Option<usize> {
+        self.max_attempts
+    }
+
+    /// Sets the maximum number of consecutive failed attempts to establish a stream.
+    ///
+    /// Once exceeded the query fails with [`Error::TimedOut`](crate::Error::TimedOut).
+    /// Defaults to `None`, which keeps retrying.
+    pub fn max_attempts(&mut self, attempts: Option<usize>) -> &mut Self {
+        self.max_attempts = attempts;
+        self
+    }
+
+    /// Returns the initial backoff between attempts.
+    #[must_use]
+    pub fn get_min_backoff(&self) -> Duration {
+        self.min_backoff
+    }
+
+    /// Sets the initial backoff between attempts.
+    pub fn min_backoff(&mut self, backoff: Duration) -> &mut Self {
+        self.min_backoff = backoff;
+        self
+    }
+
+    /// Returns the maximum backoff between attempts.
+    #[must_use]
+    pub fn get_max_backoff(&self) -> Duration {
+        self.max_backoff
+    }
+
+    /// Sets the maximum backoff between attempts.
+    pub fn max_backoff(&mut self, backoff: Duration) -> &mut Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Returns the gRPC status codes that cause a stream to be re-established.
+    #[must_use]
+    pub fn get_retry_codes(&self) -> &[tonic::Code] {
+        &self.retry_codes
+    }
+
+    /// Sets the gRPC status codes that cause a stream to be re-established.
+    ///
+    /// Any other status ends the query with an error.
+    /// Defaults to `UNAVAILABLE` and `RESOURCE_EXHAUSTED`.
+    pub fn retry_codes(&mut self, codes: impl IntoIterator<Item = tonic::Code>) -> &mut Self {
+        self.retry_codes = codes.into_iter().collect();
+        self
+    }
+
+    pub(crate) fn should_retry(&self, code: tonic::Code) -> bool {
+        self.retry_codes.contains(&code)
+    }
+
+    pub(crate) fn backoff(&self, max_elapsed_time: Option<Duration>) -> ExponentialBackoff {
+        ExponentialBackoff {
+            initial_interval: self.min_backoff,
+            current_interval: self.min_backoff,
+            max_interval: self.max_backoff,
+            max_elapsed_time,
+            ..ExponentialBackoff::default()
+        }
+    }
+}
@@ -18,21 +18,36 @@
  * ‍
  */
 
+use std::time::Instant;
+
 use async_stream::stream;
 use backoff::backoff::Backoff;
-use backoff::ExponentialBackoff;
 use futures_core::future::BoxFuture;
 use futures_core::Stream;
 use futures_util::StreamExt;
-use tokio::time::sleep;
+use tokio::time::{
+    sleep,
+    sleep_until,
+};
 use tonic::transport::Channel;
 use tonic::Status;
+use triomphe::Arc;
 
-use crate::mirror_query::AnyMirrorQueryData;
+use crate::client::MirrorNetworkData;
+use crate::mirror_query::subscription_handle::{
+    SubscriptionControl,
+    CAUGHT_UP_IDLE,
+};
+use crate::mirror_query::{
+    AnyMirrorQueryData,
+    MirrorQueryPolicy,
+};
 use crate::{
     Client,
     Error,
     MirrorQuery,
+    SubscriptionHandle,
+    SubscriptionState,
 };
 
 impl<D> MirrorQuery<D>
@@ -83,12 +98,28 @@ where
         self.subscribe_with_optional_timeout(client, Some(timeout))
     }
 
+    /// Subscribe to this query with the provided client of the Hedera network,
+    /// returning a [`SubscriptionHandle`] alongside the stream to control it.
+    pub fn subscribe_with_handle<'a>(
+        &self,
+        client: &'a Client,
+    ) -> (D::ItemStream<'a>, SubscriptionHandle) {
+        let (handle, control) = SubscriptionHandle::new();
+
+        (self.data.subscribe_with_optional_timeout(&self.common, client, None, control), handle)
+    }
+
     pub(crate) fn subscribe_with_optional_timeout<'a>(
         &self,
         client: &'a Client,
         timeout: Option<std::time::Duration>,
     ) -> D::ItemStream<'a> {
-        self.data.subscribe_with_optional_timeout(&self.common, client, timeout)
+        self.data.subscribe_with_optional_timeout(
+            &self.common,
+            client,
+            timeout,
+            SubscriptionControl::detached(),
+        )
     }
 }
 
@@ -104,6 +135,7 @@ pub trait MirrorQueryExecute: Sized + Into<AnyMirrorQueryData> + Send + Sync {
         params: &crate::mirror_query::MirrorQueryCommon,
         client: &'a crate::Client,
         timeout: Option<std::time::Duration>,
+        control: SubscriptionControl,
     ) -> Self::ItemStream<'a>
     where
         Self: 'a;
@@ -124,41 +156,57 @@ where
 
     type Response = <Self as MirrorRequest>::Response;
 
-    type ItemStream<'a> = <Self as MirrorRequest>::ItemStream<'a> where Self: 'a;
+    type ItemStream<'a>
+        = <Self as MirrorRequest>::ItemStream<'a>
+    where
+        Self: 'a;
 
     fn subscribe_with_optional_timeout<'a>(
         &self,
-        _params: &crate::mirror_query::MirrorQueryCommon,
+        params: &crate::mirror_query::MirrorQueryCommon,
         client: &'a crate::Client,
         timeout: Option<std::time::Duration>,
+        control: SubscriptionControl,
     ) -> Self::ItemStream<'a>
     where
         Self: 'a,
     {
-        let timeout = timeout.or_else(|| client.request_timeout()).unwrap_or_else(|| {
+        let timeout = params.request_timeout(client, timeout).unwrap_or_else(|| {
             std::time::Duration::from_millis(backoff::default::MAX_ELAPSED_TIME_MILLIS)
         });
 
-        // note: we don't care about keeping the mirrornet around, so, we just take the channel (which is arc-like)
-        let channel = client.mirrornet().load().channel();
+        // note: the subscription keeps using this snapshot of the mirror network, even if it's replaced.
+        let mirror = client.mirrornet().load_full();
 
-        Self::make_item_stream(crate::mirror_query::subscribe(channel, timeout, self.clone()))
+        self.make_item_stream(crate::mirror_query::subscribe(
+            mirror,
+            timeout,
+            self.clone(),
+            params.policy(client),
+            control,
+        ))
     }
 
     fn execute_with_optional_timeout<'a>(
         &'a self,
-        _params: &'a crate::mirror_query::MirrorQueryCommon,
+        params: &'a crate::mirror_query::MirrorQueryCommon,
         client: &crate::Client,
         timeout: Option<std::time::Duration>,
     ) -> BoxFuture<'a, crate::Result<Self::Response>> {
-        let timeout = timeout.or_else(|| client.request_timeout()).unwrap_or_else(|| {
+        let timeout = params.request_timeout(client, timeout).unwrap_or_else(|| {
             std::time::Duration::from_millis(backoff::default::MAX_ELAPSED_TIME_MILLIS)
         });
 
-        // note: we don't care about keeping the mirrornet around, so, we just take the channel (which is arc-like)
-        let channel = client.mirrornet().load().channel();
+        // note: the query keeps using this snapshot of the mirror network, even if it's replaced.
+        let mirror = client.mirrornet().load_full();
 
-        Self::try_collect(crate::mirror_query::subscribe(channel, timeout, self.clone()))
+        self.try_collect(crate::mirror_query::subscribe(
+            mirror,
+            timeout,
+            self.clone(),
+            params.policy(client),
+            SubscriptionControl::detached(),
+        ))
     }
 }
 
@@ -184,42 +232,88 @@ pub trait MirrorRequest: Send {
         false
     }
 
-    fn make_item_stream<'a, S>(stream: S) -> Self::ItemStream<'a>
+    fn make_item_stream<'a, S>(&self, stream: S) -> Self::ItemStream<'a>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a;
 
+    /// Create the context for a new subscription, before the first connection attempt.
+    fn initial_context(&self) -> crate::Result<Self::Context> {
+        Ok(Self::Context::default())
+    }
+
+    /// Return `false` to drop `item`, for instance because it was already received before reconnecting.
+    #[allow(unused_variables)]
+    fn should_yield(context: &Self::Context, item: &Self::GrpcItem) -> bool {
+        true
+    }
+
     fn update_context(context: &mut Self::Context, item: &Self::GrpcItem);
 
-    fn try_collect<'a, S>(stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
+    fn try_collect<'a, S>(&self, stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a;
 }
 
 pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
-    channel: Channel,
+    mirror: Arc<MirrorNetworkData>,
     timeout: std::time::Duration,
     request: R,
+    policy: MirrorQueryPolicy,
+    mut control: SubscriptionControl,
 ) -> impl Stream<Item = crate::Result<I>> + Send {
     stream! {
         let request = request;
 
-        let mut backoff = ExponentialBackoff {
-            max_elapsed_time: Some(timeout),
-            ..ExponentialBackoff::default()
-        };
+        let mut backoff = policy.backoff(Some(timeout));
 
-        let mut backoff_inf = ExponentialBackoff {
-            max_elapsed_time: None,
-            // remove maximum elapsed time for # of back-offs on inf.
-            .. ExponentialBackoff::default()
+        // remove maximum elapsed time for # of back-offs on inf.
+        let mut backoff_inf = policy.backoff(None);
+
+        let mut context = match request.initial_context() {
+            Ok(context) => context,
+            Err(error) => {
+                control.set_state(SubscriptionState::Finished);
+                yield Err(error);
+                return;
+            }
         };
 
-        let mut context = R::Context::default();
+        // consecutive failed attempts, for `SubscriptionState::Retrying`.
+        let mut attempt = 0;
 
         loop {
+            // set when `status` comes from our own timeouts rather than the mirror node, those are always retried.
+            let mut timed_out = false;
+
+            // the endpoint is picked again for every attempt, so that a failing mirror node is skipped.
+            let Some(endpoint) = mirror.pick_endpoint(Instant::now()) else {
+                control.set_state(SubscriptionState::Finished);
+                yield Err(Error::MirrorNetworkEmpty);
+                return;
+            };
+
             let status: Status = 'request: loop {
+                if let Err(reason) = control.wait_while_paused().await {
+                    control.set_state(SubscriptionState::Cancelled { reason });
+                    return;
+                }
+
+                control.set_state(SubscriptionState::Connecting);
+
+                let connect_timeout = policy.get_connect_timeout();
+
                 // attempt to establish the stream
-                let response = request.connect(&context, channel.clone()).await;
+                let response = tokio::select! {
+                    response = request.connect(&context, mirror.channel(endpoint)) => response,
+                    () = sleep(connect_timeout.unwrap_or_default()), if connect_timeout.is_some() => {
+                        timed_out = true;
+                        break 'request Status::deadline_exceeded("timed out establishing the mirror node stream");
+                    }
+                    reason = control.cancelled() => {
+                        control.set_state(SubscriptionState::Cancelled { reason });
+                        return;
+                    }
+                };
 
                 let stream = match response {
                     // success, we now have a stream and may begin waiting for messages
@@ -234,16 +328,58 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
 
                 backoff.reset();
                 backoff_inf.reset();
+                attempt = 0;
+
+                mirror.mark_endpoint_healthy(endpoint);
+
+                control.set_state(SubscriptionState::Connected);
+
+                let mut caught_up = false;
+
+                let idle_timeout = policy.get_idle_timeout();
+
+                // only a received message (or resuming from a pause) pushes the idle deadline back,
+                // waking up for anything else doesn't.
+                let mut idle_deadline = idle_timeout.map(|it| tokio::time::Instant::now() + it);
 
-                #[allow(unused_labels)]
                 'message: loop {
-                    let message = stream.next().await.transpose();
+                    match control.wait_while_paused().await {
+                        // time spent paused doesn't count towards the idle timeout.
+                        Ok(true) => {
+                            idle_deadline = idle_timeout.map(|it| tokio::time::Instant::now() + it);
+                        }
+                        Ok(false) => {}
+                        Err(reason) => {
+                            control.set_state(SubscriptionState::Cancelled { reason });
+                            return;
+                        }
+                    }
+
+                    let message = tokio::select! {
+                        message = stream.next() => message.transpose(),
+                        reason = control.cancelled() => {
+                            control.set_state(SubscriptionState::Cancelled { reason });
+                            return;
+                        }
+                        () = sleep(CAUGHT_UP_IDLE), if !caught_up => {
+                            caught_up = true;
+                            control.set_state(SubscriptionState::CaughtUp);
+                            continue 'message;
+                        }
+                        () = sleep_until(idle_deadline.unwrap_or_else(tokio::time::Instant::now)), if idle_deadline.is_some() => {
+                            timed_out = true;
+                            break 'request Status::deadline_exceeded("no message received from the mirror node within the idle timeout");
+                        }
+                    };
+
+                    idle_deadline = idle_timeout.map(|it| tokio::time::Instant::now() + it);
 
                     let message = match message {
                         Ok(Some(message)) => message,
                         Ok(None) => {
                             // end of stream
                             // hopefully due to configured limits or expected conditions
+                            control.set_state(SubscriptionState::Finished);
                             return;
                         }
 
@@ -252,29 +388,41 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
                         }
                     };
 
-                    R::update_context(&mut context, &message);
+                    if R::should_yield(&context, &message) {
+                        R::update_context(&mut context, &message);
 
-                    yield Ok(message);
+                        yield Ok(message);
+                    }
                 }
             };
 
-            match status.code() {
-                tonic::Code::Unavailable | tonic::Code::ResourceExhausted => {
+            attempt += 1;
+
+            let delay = match status.code() {
+                _ if timed_out => {
+                    mirror.mark_endpoint_unhealthy(endpoint);
+                    backoff_inf.next_backoff().unwrap()
+                }
+
+                code if policy.should_retry(code) => {
                     // encountered a temporarily down or overloaded service
-                    sleep(backoff_inf.next_backoff().unwrap()).await;
+                    mirror.mark_endpoint_unhealthy(endpoint);
+                    backoff_inf.next_backoff().unwrap()
                 }
 
                 tonic::Code::Unknown if status.message() == "error reading a body from connection: connection reset" => {
                     // connection was aborted by the server
-                    sleep(backoff_inf.next_backoff().unwrap()).await;
+                    mirror.mark_endpoint_unhealthy(endpoint);
+                    backoff_inf.next_backoff().unwrap()
                 }
 
                 code if request.should_retry(code) => {
                     if let Some(duration) = backoff.next_backoff() {
-                        sleep(duration).await;
+                        duration
                     } else {
                         // maximum time allowed has elapsed
                         // NOTE: it should be impossible to reach here without capturing at least one error
+                        control.set_state(SubscriptionState::Finished);
                         yield Err(Error::TimedOut(Error::from(status).into()));
                         return;
                     }
@@ -283,10 +431,270 @@ pub(crate) fn subscribe<I: Send, R: MirrorRequest<GrpcItem = I> + Send + Sync>(
                 _ => {
                     // encountered an un-recoverable failure when attempting
                     // to establish the stream
+                    control.set_state(SubscriptionState::Finished);
                     yield Err(Error::from(status));
                     return;
                 }
+            };
+
+            if policy.get_max_attempts().is_some_and(|max| attempt >= max) {
+                control.set_state(SubscriptionState::Finished);
+                yield Err(Error::TimedOut(Error::from(status).into()));
+                return;
+            }
+
+            log::debug!("mirror node `{}` failed with {status:?}, retrying", mirror.address(endpoint));
+
+            control.set_state(SubscriptionState::Retrying { attempt, delay });
+
+            tokio::select! {
+                () = sleep(delay) => {}
+                reason = control.cancelled() => {
+                    control.set_state(SubscriptionState::Cancelled { reason });
+                    return;
+                }
             }
         }
     }
 }
+
+#[cfg(test)]
+pub(crate) mod test_helpers {
+    use std::collections::VecDeque;
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+    use std::time::Duration;
+
+    use futures_core::future::BoxFuture;
+    use futures_core::stream::BoxStream;
+    use futures_core::Stream;
+    use futures_util::{
+        StreamExt,
+        TryStreamExt,
+    };
+    use parking_lot::Mutex;
+    use tonic::transport::Channel;
+    use triomphe::Arc;
+
+    use super::MirrorRequest;
+    use crate::client::MirrorNetworkData;
+    use crate::{
+        MirrorQueryPolicy,
+        SubscriptionHandle,
+    };
+
+    /// Yields `1..=count`, resuming after the last item it was given, then stays open.
+    ///
+    /// Connecting fails with each of the configured failures in turn before it succeeds.
+    #[derive(Clone)]
+    pub(crate) struct CountingRequest {
+        count: u64,
+        failures: Arc<Mutex<VecDeque<tonic::Status>>>,
+        connects: Arc<AtomicUsize>,
+    }
+
+    impl CountingRequest {
+        pub(crate) fn new(count: u64) -> Self {
+            Self {
+                count,
+                failures: Arc::new(Mutex::new(VecDeque::new())),
+                connects: Arc::new(AtomicUsize::new(0)),
+            }
+        }
+
+        pub(crate) fn failing(self, failures: impl IntoIterator<Item = tonic::Status>) -> Self {
+            self.failures.lock().extend(failures);
+            self
+        }
+
+        /// Returns how many times `connect` was called.
+        pub(crate) fn connects(&self) -> usize {
+            self.connects.load(Ordering::Relaxed)
+        }
+    }
+
+    impl MirrorRequest for CountingRequest {
+        type GrpcItem = u64;
+        type ConnectStream = BoxStream<'static, tonic::Result<u64>>;
+        type Item = u64;
+        type Response = Vec<u64>;
+        type Context = u64;
+        type ItemStream<'a> = BoxStream<'a, crate::Result<u64>>;
+
+        fn connect(
+            &self,
+            context: &Self::Context,
+            _channel: Channel,
+        ) -> BoxFuture<'_, tonic::Result<Self::ConnectStream>> {
+            self.connects.fetch_add(1, Ordering::Relaxed);
+
+            let failure = self.failures.lock().pop_front();
+            let items = futures_util::stream::iter((context + 1..=self.count).map(Ok));
+
+            Box::pin(async move {
+                match failure {
+                    Some(status) => Err(status),
+                    None => Ok(items.chain(futures_util::stream::pending()).boxed() as _),
+                }
+            })
+        }
+
+        fn make_item_stream<'a, S>(&self, stream: S) -> Self::ItemStream<'a>
+        where
+            S: Stream<Item = crate::Result<u64>> + Send + 'a,
+        {
+            Box::pin(stream)
+        }
+
+        fn update_context(context: &mut Self::Context, item: &u64) {
+            *context = *item;
+        }
+
+        fn try_collect<'a, S>(&self, stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
+        where
+            S: Stream<Item = crate::Result<u64>> + Send + 'a,
+        {
+            Box::pin(stream.try_collect())
+        }
+    }
+
+    pub(crate) fn start(
+        request: CountingRequest,
+        policy: MirrorQueryPolicy,
+    ) -> (BoxStream<'static, crate::Result<u64>>, SubscriptionHandle) {
+        let (handle, control) = SubscriptionHandle::new();
+        let mirror = Arc::new(MirrorNetworkData::from_static(&["127.0.0.1:1"]));
+
+        let stream =
+            super::subscribe(mirror, Duration::from_secs(30), request, policy, control).boxed();
+
+        (stream, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use assert_matches::assert_matches;
+    use futures_util::TryStreamExt;
+
+    use super::test_helpers::{
+        start,
+        CountingRequest,
+    };
+    use crate::{
+        Error,
+        MirrorQueryPolicy,
+    };
+
+    fn fast_policy() -> MirrorQueryPolicy {
+        let mut policy = MirrorQueryPolicy::new();
+        policy.min_backoff(Duration::from_millis(1)).max_backoff(Duration::from_millis(1));
+        policy
+    }
+
+    #[tokio::test]
+    async fn retries_configured_codes() {
+        let request =
+            CountingRequest::new(2).failing([tonic::Status::not_found("topic not found yet")]);
+
+        let mut policy = fast_policy();
+        policy.retry_codes([tonic::Code::NotFound]);
+
+        let (mut stream, _handle) = start(request.clone(), policy);
+
+        assert_eq!(stream.try_next().await.unwrap(), Some(1));
+        assert_eq!(request.connects(), 2);
+    }
+
+    #[tokio::test]
+    async fn fails_on_other_codes() {
+        let request = CountingRequest::new(2).failing([tonic::Status::not_found("no such topic")]);
+
+        let (mut stream, _handle) = start(request.clone(), fast_policy());
+
+        assert_matches!(stream.try_next().await, Err(Error::GrpcStatus(status)) if status.code() == tonic::Code::NotFound);
+        assert_eq!(request.connects(), 1);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let request = CountingRequest::new(2)
+            .failing(std::iter::repeat_with(|| tonic::Status::unavailable("down")).take(5));
+
+        let mut policy = fast_policy();
+        policy.max_attempts(Some(3));
+
+        let (mut stream, _handle) = start(request.clone(), policy);
+
+        assert_matches!(stream.try_next().await, Err(Error::TimedOut(_)));
+        assert_eq!(request.connects(), 3);
+    }
+
+    #[tokio::test]
+    async fn reconnects_idle_streams_after_last_item() {
+        let request = CountingRequest::new(2);
+
+        let mut policy = fast_policy();
+        policy.idle_timeout(Some(Duration::from_millis(20)));
+
+        let (mut stream, _handle) = start(request.clone(), policy);
+
+        assert_eq!(stream.try_next().await.unwrap(), Some(1));
+        assert_eq!(stream.try_next().await.unwrap(), Some(2));
+
+        let next = tokio::time::timeout(Duration::from_millis(200), stream.try_next()).await;
+
+        // reconnecting resumes after `2`, so nothing is delivered twice.
+        assert!(next.is_err(), "expected no more items, got {next:?}");
+        assert!(request.connects() > 1);
+    }
+
+    #[tokio::test]
+    async fn caught_up_does_not_extend_idle_timeout() {
+        let request = CountingRequest::new(1);
+
+        // longer than `CAUGHT_UP_IDLE`, so that the stream is caught up before it's idle.
+        let mut policy = fast_policy();
+        policy.idle_timeout(Some(Duration::from_millis(1200)));
+
+        let (mut stream, _handle) = start(request.clone(), policy);
+
+        assert_eq!(stream.try_next().await.unwrap(), Some(1));
+
+        // restarting the idle timeout when caught up would delay the reconnect until 2.2s.
+        let next = tokio::time::timeout(Duration::from_millis(1900), stream.try_next()).await;
+
+        assert!(next.is_err(), "expected no more items, got {next:?}");
+        assert_eq!(request.connects(), 2);
+    }
+
+    #[tokio::test]
+    async fn pause_does_not_count_towards_idle_timeout() {
+        let request = CountingRequest::new(4);
+
+        let mut policy = fast_policy();
+        policy.idle_timeout(Some(Duration::from_millis(100)));
+
+        let (mut stream, handle) = start(request.clone(), policy);
+
+        assert_eq!(stream.try_next().await.unwrap(), Some(1));
+
+        // an expired idle deadline races the next message, so pause a few times to make a reconnect likely.
+        for expected in 2..=4 {
+            handle.pause();
+
+            let paused = tokio::time::timeout(Duration::from_millis(200), stream.try_next()).await;
+            assert!(paused.is_err(), "paused subscription yielded {paused:?}");
+
+            handle.resume();
+
+            assert_eq!(stream.try_next().await.unwrap(), Some(expected));
+        }
+
+        assert_eq!(request.connects(), 1);
+    }
+}
@@ -0,0 +1,289 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::time::Duration;
+
+use tokio::sync::watch;
+use triomphe::Arc;
+
+/// How long a subscription may go without receiving an item before it's considered caught up.
+pub(crate) const CAUGHT_UP_IDLE: Duration = Duration::from_secs(1);
+
+/// The connection state of a mirror node subscription.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SubscriptionState {
+    /// The subscription is establishing its stream with the mirror node.
+    Connecting,
+
+    /// The stream is established and the mirror node is delivering the backlog.
+    Connected,
+
+    /// The mirror node has delivered everything it had, the subscription is waiting for new items.
+    ///
+    /// This is reported once the stream has been idle for a short while.
+    CaughtUp,
+
+    /// The stream failed with a recoverable error and will be re-established after `delay`.
+    Retrying {
+        /// The number of consecutive failed attempts, starting at `1`.
+        attempt: usize,
+
+        /// How long until the next attempt.
+        delay: Duration,
+    },
+
+    /// The subscription was cancelled with [`SubscriptionHandle::cancel`].
+    Cancelled {
+        /// The reason passed to [`SubscriptionHandle::cancel`].
+        reason: String,
+    },
+
+    /// The subscription ended, either because the mirror node completed the stream or because of an unrecoverable error.
+    Finished,
+}
+
+impl SubscriptionState {
+    /// Returns `true` if the subscription won't change state anymore.
+    #[must_use]
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Cancelled { .. } | Self::Finished)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+struct Control {
+    paused: bool,
+    cancelled: Option<String>,
+}
+
+/// Controls a running mirror node subscription and observes its [`SubscriptionState`].
+///
+/// Returned by [`MirrorQuery::subscribe_with_handle`](crate::MirrorQuery::subscribe_with_handle).
+/// Cloning the handle is cheap, every clone controls the same subscription.
+///
+/// Items are only requested from the mirror node as the stream is polled,
+/// so a slow consumer slows down the mirror node rather than buffering items in memory.
+/// Dropping every handle leaves the subscription running.
+#[derive(Clone, Debug)]
+pub struct SubscriptionHandle {
+    control: Arc<watch::Sender<Control>>,
+    state: watch::Receiver<SubscriptionState>,
+}
+
+impl SubscriptionHandle {
+    pub(crate) fn new() -> (Self, SubscriptionControl) {
+        let (control_tx, control_rx) = watch::channel(Control::default());
+        let (state_tx, state_rx) = watch::channel(SubscriptionState::Connecting);
+
+        (
+            Self { control: Arc::new(control_tx), state: state_rx },
+            SubscriptionControl { control: control_rx, state: state_tx },
+        )
+    }
+
+    /// Stop receiving items until [`resume`](Self::resume) is called.
+    ///
+    /// The subscription keeps its position, no items are skipped or repeated after resuming.
+    pub fn pause(&self) {
+        self.control.send_if_modified(|it| !std::mem::replace(&mut it.paused, true));
+    }
+
+    /// Continue receiving items after [`pause`](Self::pause).
+    pub fn resume(&self) {
+        self.control.send_if_modified(|it| std::mem::replace(&mut it.paused, false));
+    }
+
+    /// Returns `true` if the subscription is paused.
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.control.borrow().paused
+    }
+
+    /// Stop the subscription, the stream ends once the current item has been delivered.
+    ///
+    /// Only the first reason is kept if this is called more than once.
+    pub fn cancel(&self, reason: impl Into<String>) {
+        let reason = reason.into();
+
+        self.control.send_if_modified(|it| match it.cancelled {
+            Some(_) => false,
+            None => {
+                it.cancelled = Some(reason);
+                true
+            }
+        });
+    }
+
+    /// Returns `true` if [`cancel`](Self::cancel) was called.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.control.borrow().cancelled.is_some()
+    }
+
+    /// Returns the current state of the subscription.
+    #[must_use]
+    pub fn state(&self) -> SubscriptionState {
+        self.state.borrow().clone()
+    }
+
+    /// Wait for the state of the subscription to change, and return the new state.
+    ///
+    /// Returns `None` once the subscription has reached a terminal state or its stream has been dropped.
+    pub async fn next_state(&mut self) -> Option<SubscriptionState> {
+        if self.state.borrow_and_update().is_terminal() {
+            return None;
+        }
+
+        self.state.changed().await.ok()?;
+
+        Some(self.state.borrow_and_update().clone())
+    }
+}
+
+/// The subscription's side of a [`SubscriptionHandle`].
+// intentionally inaccessable despite publicity.
+#[derive(Debug)]
+pub struct SubscriptionControl {
+    control: watch::Receiver<Control>,
+    state: watch::Sender<SubscriptionState>,
+}
+
+impl SubscriptionControl {
+    /// A control for a subscription that nobody holds a handle to.
+    pub(crate) fn detached() -> Self {
+        SubscriptionHandle::new().1
+    }
+
+    pub(crate) fn set_state(&self, state: SubscriptionState) {
+        self.state.send_if_modified(|it| {
+            if *it == state {
+                return false;
+            }
+
+            *it = state;
+            true
+        });
+    }
+
+    /// Wait until the subscription isn't paused, returning whether it was paused.
+    ///
+    /// Returns the cancellation reason if the subscription was cancelled instead.
+    pub(crate) async fn wait_while_paused(&mut self) -> Result<bool, String> {
+        let paused = self.control.borrow().paused;
+
+        let control = self
+            .control
+            .wait_for(|it| !it.paused || it.cancelled.is_some())
+            .await
+            .map(|it| it.clone());
+
+        // if every handle is gone nobody can resume the subscription anymore, so don't stay paused.
+        let control = control.unwrap_or_else(|_| self.control.borrow().clone());
+
+        match control.cancelled {
+            Some(reason) => Err(reason),
+            None => Ok(paused),
+        }
+    }
+
+    /// Wait until the subscription is cancelled and return the reason.
+    ///
+    /// Never resolves if every handle was dropped without cancelling.
+    pub(crate) async fn cancelled(&mut self) -> String {
+        let reason = self
+            .control
+            .wait_for(|it| it.cancelled.is_some())
+            .await
+            .map(|it| it.cancelled.clone().unwrap_or_default());
+
+        match reason {
+            Ok(reason) => reason,
+            Err(_) => std::future::pending().await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures_util::{
+        StreamExt,
+        TryStreamExt,
+    };
+
+    use crate::mirror_query::subscribe::test_helpers::{
+        start,
+        CountingRequest,
+    };
+    use crate::{
+        MirrorQueryPolicy,
+        SubscriptionHandle,
+        SubscriptionState,
+    };
+
+    async fn wait_for_state(handle: &mut SubscriptionHandle, state: &SubscriptionState) {
+        let wait = async {
+            while handle.state() != *state {
+                handle.next_state().await;
+            }
+        };
+
+        tokio::time::timeout(Duration::from_secs(5), wait).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn pause_and_resume_keeps_position() {
+        let (mut stream, handle) = start(CountingRequest::new(3), MirrorQueryPolicy::new());
+
+        assert_eq!(stream.try_next().await.unwrap(), Some(1));
+
+        handle.pause();
+        assert!(handle.is_paused());
+
+        let paused = tokio::time::timeout(Duration::from_millis(50), stream.next()).await;
+        assert!(paused.is_err(), "paused subscription yielded {paused:?}");
+
+        handle.resume();
+
+        assert_eq!(stream.try_next().await.unwrap(), Some(2));
+        assert_eq!(stream.try_next().await.unwrap(), Some(3));
+    }
+
+    #[tokio::test]
+    async fn reports_caught_up_and_cancelled() {
+        let (stream, mut handle) = start(CountingRequest::new(2), MirrorQueryPolicy::new());
+
+        let items = tokio::spawn(stream.try_collect::<Vec<_>>());
+
+        wait_for_state(&mut handle, &SubscriptionState::CaughtUp).await;
+
+        handle.cancel("shutting down");
+        handle.cancel("ignored");
+
+        assert_eq!(items.await.unwrap().unwrap(), [1, 2]);
+        assert_eq!(
+            handle.state(),
+            SubscriptionState::Cancelled { reason: "shutting down".to_owned() }
+        );
+        assert_eq!(handle.next_state().await, None);
+    }
+}
@@ -0,0 +1,203 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use futures_core::stream::BoxStream;
+use time::OffsetDateTime;
+
+use super::{
+    de,
+    MirrorRestClient,
+};
+use crate::{
+    AccountId,
+    Hbar,
+    NftId,
+    TokenId,
+};
+
+/// An account, as returned by the mirror node's `/accounts/{id}` endpoint.
+#[derive(Debug, Clone, serde_derive::Deserialize)]
+pub struct MirrorAccount {
+    /// The ID of the account.
+    #[serde(rename = "account", deserialize_with = "de::from_str")]
+    pub account_id: AccountId,
+
+    /// The account's alias, as a base32 encoded string.
+    #[serde(default)]
+    pub alias: Option<String>,
+
+    /// The account's EVM address, as a `0x` prefixed hex string.
+    #[serde(default)]
+    pub evm_address: Option<String>,
+
+    /// The account's hbar and token balances.
+    pub balance: MirrorAccountBalance,
+
+    /// Whether the account has been deleted.
+    #[serde(default)]
+    pub deleted: bool,
+
+    /// The account's memo.
+    #[serde(default)]
+    pub memo: String,
+
+    /// Whether the account declines receiving staking rewards.
+    #[serde(default)]
+    pub decline_reward: bool,
+
+    /// The account this account is staked to, if any.
+    #[serde(default, deserialize_with = "de::option_from_str")]
+    pub staked_account_id: Option<AccountId>,
+
+    /// The node this account is staked to, if any.
+    #[serde(default)]
+    pub staked_node_id: Option<u64>,
+
+    /// The maximum number of tokens that can be automatically associated with the account.
+    #[serde(default)]
+    pub max_automatic_token_associations: i32,
+
+    /// When the account expires.
+    #[serde(default, deserialize_with = "de::option_timestamp")]
+    pub expiry_timestamp: Option<OffsetDateTime>,
+}
+
+/// The balances of an account at a point in time.
+#[derive(Debug, Clone, serde_derive::Deserialize)]
+pub struct MirrorAccountBalance {
+    /// The account's hbar balance.
+    #[serde(deserialize_with = "de::tinybars")]
+    pub balance: Hbar,
+
+    /// When the balance was taken.
+    #[serde(default, deserialize_with = "de::option_timestamp")]
+    pub timestamp: Option<OffsetDateTime>,
+
+    /// The account's token balances.
+    #[serde(default)]
+    pub tokens: Vec<MirrorTokenBalance>,
+}
+
+/// An account's balance of a token.
+#[derive(Debug, Clone, serde_derive::Deserialize)]
+pub struct MirrorTokenBalance {
+    /// The ID of the token.
+    #[serde(deserialize_with = "de::from_str")]
+    pub token_id: TokenId,
+
+    /// The balance, in the token's smallest denomination.
+    pub balance: u64,
+}
+
+/// An account's relationship with a token, as returned by the `/accounts/{id}/tokens` endpoint.
+#[derive(Debug, Clone, serde_derive::Deserialize)]
+pub struct MirrorTokenRelationship {
+    /// The ID of the token.
+    #[serde(deserialize_with = "de::from_str")]
+    pub token_id: TokenId,
+
+    /// The balance, in the token's smallest denomination.
+    pub balance: u64,
+
+    /// The token's decimals.
+    #[serde(default)]
+    pub decimals: u32,
+
+    /// Whether the token was associated automatically.
+    #[serde(default)]
+    pub automatic_association: bool,
+
+    /// The freeze status of the account for the token (`FROZEN`, `UNFROZEN` or `NOT_APPLICABLE`).
+    #[serde(default)]
+    pub freeze_status: String,
+
+    /// The KYC status of the account for the token (`GRANTED`, `REVOKED` or `NOT_APPLICABLE`).
+    #[serde(default)]
+    pub kyc_status: String,
+
+    /// When the relationship was created.
+    #[serde(default, deserialize_with = "de::option_timestamp")]
+    pub created_timestamp: Option<OffsetDateTime>,
+}
+
+/// A non-fungible token, as returned by the `/accounts/{id}/nfts` and `/tokens/{id}/nfts` endpoints.
+#[derive(Debug, Clone, serde_derive::Deserialize)]
+pub struct MirrorNft {
+    /// The ID of the NFT's token.
+    #[serde(deserialize_with = "de::from_str")]
+    pub token_id: TokenId,
+
+    /// The NFT's serial number.
+    pub serial_number: u64,
+
+    /// The account that owns the NFT.
+    #[serde(default, deserialize_with = "de::option_from_str")]
+    pub account_id: Option<AccountId>,
+
+    /// The account approved to spend the NFT, if any.
+    #[serde(default, deserialize_with = "de::option_from_str")]
+    pub spender: Option<AccountId>,
+
+    /// The NFT's metadata, as a base64 encoded string.
+    #[serde(default)]
+    pub metadata: String,
+
+    /// Whether the NFT has been deleted (burned or wiped).
+    #[serde(default)]
+    pub deleted: bool,
+
+    /// When the NFT was minted.
+    #[serde(default, deserialize_with = "de::option_timestamp")]
+    pub created_timestamp: Option<OffsetDateTime>,
+}
+
+impl MirrorNft {
+    /// Returns the ID of this NFT.
+    #[must_use]
+    pub fn nft_id(&self) -> NftId {
+        self.token_id.nft(self.serial_number)
+    }
+}
+
+impl MirrorRestClient {
+    /// Fetch the account `account_id`, including its balances.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRest`](crate::Error::MirrorRest) if the request fails or the response can't be decoded.
+    pub async fn account(&self, account_id: AccountId) -> crate::Result<MirrorAccount> {
+        self.get(&format!("/accounts/{account_id}")).await
+    }
+
+    /// Stream the tokens associated with `account_id`.
+    pub fn account_tokens(
+        &self,
+        account_id: AccountId,
+    ) -> BoxStream<'static, crate::Result<MirrorTokenRelationship>> {
+        self.paginate(format!("/accounts/{account_id}/tokens"), "tokens")
+    }
+
+    /// Stream the NFTs owned by `account_id`.
+    pub fn account_nfts(
+        &self,
+        account_id: AccountId,
+    ) -> BoxStream<'static, crate::Result<MirrorNft>> {
+        self.paginate(format!("/accounts/{account_id}/nfts"), "nfts")
+    }
+}
@@ -0,0 +1,201 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use futures_core::stream::BoxStream;
+use time::OffsetDateTime;
+
+use super::{
+    de,
+    MirrorRestClient,
+};
+use crate::{
+    AccountId,
+    ContractId,
+    FileId,
+};
+
+/// A contract, as returned by the mirror node's `/contracts/{id}` endpoint.
+#[derive(Debug, Clone, serde_derive::Deserialize)]
+pub struct MirrorContract {
+    /// The ID of the contract.
+    #[serde(deserialize_with = "de::from_str")]
+    pub contract_id: ContractId,
+
+    /// The contract's EVM address, as a `0x` prefixed hex string.
+    #[serde(default)]
+    pub evm_address: Option<String>,
+
+    /// The file the contract's bytecode was created from, if any.
+    #[serde(default, deserialize_with = "de::option_from_str")]
+    pub file_id: Option<FileId>,
+
+    /// The contract's memo.
+    #[serde(default)]
+    pub memo: String,
+
+    /// Whether the contract has been deleted.
+    #[serde(default)]
+    pub deleted: bool,
+
+    /// The account that pays for the contract's auto renewal, if any.
+    #[serde(default, deserialize_with = "de::option_from_str")]
+    pub auto_renew_account: Option<AccountId>,
+
+    /// When the contract was created.
+    #[serde(default, deserialize_with = "de::option_timestamp")]
+    pub created_timestamp: Option<OffsetDateTime>,
+
+    /// When the contract expires.
+    #[serde(default, deserialize_with = "de::option_timestamp")]
+    pub expiration_timestamp: Option<OffsetDateTime>,
+
+    /// The contract's initcode.
+    #[serde(default, deserialize_with = "de::hex")]
+    pub bytecode: Vec<u8>,
+
+    /// The contract's deployed bytecode.
+    #[serde(default, deserialize_with = "de::hex")]
+    pub runtime_bytecode: Vec<u8>,
+}
+
+/// The result of a contract call or create, as returned by the `/contracts/{id}/results` endpoint.
+#[derive(Debug, Clone, serde_derive::Deserialize)]
+pub struct MirrorContractResult {
+    /// The contract that was called.
+    #[serde(default, deserialize_with = "de::option_from_str")]
+    pub contract_id: Option<ContractId>,
+
+    /// When the call reached consensus.
+    #[serde(deserialize_with = "de::timestamp")]
+    pub timestamp: OffsetDateTime,
+
+    /// The EVM address of the caller, as a `0x` prefixed hex string.
+    #[serde(default)]
+    pub from: Option<String>,
+
+    /// The EVM address of the callee, as a `0x` prefixed hex string.
+    #[serde(default)]
+    pub to: Option<String>,
+
+    /// The amount of tinybars sent with the call.
+    #[serde(default)]
+    pub amount: i64,
+
+    /// The gas limit of the call.
+    #[serde(default)]
+    pub gas_limit: u64,
+
+    /// The gas used by the call.
+    #[serde(default)]
+    pub gas_used: u64,
+
+    /// The output of the call.
+    #[serde(default, deserialize_with = "de::hex")]
+    pub call_result: Vec<u8>,
+
+    /// The input of the call.
+    #[serde(default, deserialize_with = "de::hex")]
+    pub function_parameters: Vec<u8>,
+
+    /// The error message of the call, if it failed.
+    #[serde(default)]
+    pub error_message: Option<String>,
+
+    /// The result of the transaction (ex. `SUCCESS`).
+    #[serde(default)]
+    pub result: String,
+
+    /// The hash of the transaction, as a `0x` prefixed hex string.
+    #[serde(default)]
+    pub hash: String,
+
+    /// The contracts created by the call.
+    #[serde(default, deserialize_with = "de::vec_from_str")]
+    pub created_contract_ids: Vec<ContractId>,
+}
+
+/// A log emitted by a contract, as returned by the `/contracts/{id}/results/logs` endpoint.
+#[derive(Debug, Clone, serde_derive::Deserialize)]
+pub struct MirrorContractLog {
+    /// The EVM address of the contract that emitted the log, as a `0x` prefixed hex string.
+    pub address: String,
+
+    /// The contract that emitted the log.
+    #[serde(default, deserialize_with = "de::option_from_str")]
+    pub contract_id: Option<ContractId>,
+
+    /// The log's bloom filter.
+    #[serde(default, deserialize_with = "de::hex")]
+    pub bloom: Vec<u8>,
+
+    /// The log's data.
+    #[serde(default, deserialize_with = "de::hex")]
+    pub data: Vec<u8>,
+
+    /// The index of the log within its block.
+    #[serde(default)]
+    pub index: u32,
+
+    /// The log's topics.
+    #[serde(default, deserialize_with = "de::vec_hex")]
+    pub topics: Vec<Vec<u8>>,
+
+    /// When the log was emitted.
+    #[serde(deserialize_with = "de::timestamp")]
+    pub timestamp: OffsetDateTime,
+
+    /// The hash of the transaction that emitted the log, as a `0x` prefixed hex string.
+    #[serde(default)]
+    pub transaction_hash: String,
+
+    /// The number of the block containing the log.
+    #[serde(default)]
+    pub block_number: u64,
+
+    /// The contract at the root of the call that emitted the log.
+    #[serde(default, deserialize_with = "de::option_from_str")]
+    pub root_contract_id: Option<ContractId>,
+}
+
+impl MirrorRestClient {
+    /// Fetch the contract `contract_id`.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRest`](crate::Error::MirrorRest) if the request fails or the response can't be decoded.
+    pub async fn contract(&self, contract_id: ContractId) -> crate::Result<MirrorContract> {
+        self.get(&format!("/contracts/{contract_id}")).await
+    }
+
+    /// Stream the results of calls to the contract `contract_id`.
+    pub fn contract_results(
+        &self,
+        contract_id: ContractId,
+    ) -> BoxStream<'static, crate::Result<MirrorContractResult>> {
+        self.paginate(format!("/contracts/{contract_id}/results"), "results")
+    }
+
+    /// Stream the logs emitted by the contract `contract_id`.
+    pub fn contract_logs(
+        &self,
+        contract_id: ContractId,
+    ) -> BoxStream<'static, crate::Result<MirrorContractLog>> {
+        self.paginate(format!("/contracts/{contract_id}/results/logs"), "logs")
+    }
+}
@@ -0,0 +1,439 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::marker::PhantomData;
+
+use super::{
+    de,
+    MirrorRestClient,
+};
+use crate::{
+    AccountId,
+    ContractFunctionParameters,
+    ContractFunctionResult,
+    ContractId,
+    Error,
+    Hbar,
+};
+
+/// Simulate a call to a function of a smart contract on the mirror node.
+///
+/// Unlike [`ContractCallQuery`](crate::ContractCallQuery), this doesn't cost anything and doesn't
+/// touch the consensus nodes, the call is executed by the mirror node's EVM against its view of the network state.
+pub type MirrorContractCallQuery = MirrorContractQuery<MirrorContractCallData>;
+
+/// Estimate the gas a call to a function of a smart contract would use, by simulating it on the mirror node.
+///
+/// The estimate is suitable for use as the gas of a [`ContractExecuteTransaction`](crate::ContractExecuteTransaction).
+pub type MirrorContractEstimateGasQuery = MirrorContractQuery<MirrorContractEstimateGasData>;
+
+/// A request to the mirror node's `/contracts/call` endpoint.
+#[derive(Debug, Clone, Default)]
+pub struct MirrorContractQuery<D> {
+    /// The contract to call.
+    contract_id: Option<ContractId>,
+
+    /// The account the call is made from.
+    sender_account_id: Option<AccountId>,
+
+    /// The gas limit of the call.
+    gas: u64,
+
+    /// The gas price of the call, in tinybars.
+    gas_price: Option<u64>,
+
+    /// The amount of hbar sent with the call.
+    value: Hbar,
+
+    /// The function parameters as their raw bytes.
+    function_parameters: Vec<u8>,
+
+    /// The block to simulate the call at, the latest block if unset.
+    block_number: Option<u64>,
+
+    kind: PhantomData<D>,
+}
+
+/// Data specific to [`MirrorContractCallQuery`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MirrorContractCallData;
+
+/// Data specific to [`MirrorContractEstimateGasQuery`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MirrorContractEstimateGasData;
+
+impl<D: Default> MirrorContractQuery<D> {
+    /// Create a new query, ready for configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<D> MirrorContractQuery<D> {
+    /// Returns the contract to call.
+    #[must_use]
+    pub fn get_contract_id(&self) -> Option<ContractId> {
+        self.contract_id
+    }
+
+    /// Sets the contract to call.
+    pub fn contract_id(&mut self, contract_id: ContractId) -> &mut Self {
+        self.contract_id = Some(contract_id);
+        self
+    }
+
+    /// Returns the account the call is made from.
+    #[must_use]
+    pub fn get_sender_account_id(&self) -> Option<AccountId> {
+        self.sender_account_id
+    }
+
+    /// Sets the account the call is made from.
+    pub fn sender_account_id(&mut self, sender_account_id: AccountId) -> &mut Self {
+        self.sender_account_id = Some(sender_account_id);
+        self
+    }
+
+    /// Returns the gas limit of the call.
+    #[must_use]
+    pub fn get_gas(&self) -> u64 {
+        self.gas
+    }
+
+    /// Sets the gas limit of the call.
+    ///
+    /// When unset the mirror node's default gas limit is used.
+    pub fn gas(&mut self, gas: u64) -> &mut Self {
+        self.gas = gas;
+        self
+    }
+
+    /// Returns the gas price of the call, in tinybars.
+    #[must_use]
+    pub fn get_gas_price(&self) -> Option<u64> {
+        self.gas_price
+    }
+
+    /// Sets the gas price of the call, in tinybars.
+    pub fn gas_price(&mut self, gas_price: u64) -> &mut Self {
+        self.gas_price = Some(gas_price);
+        self
+    }
+
+    /// Returns the amount of hbar sent with the call.
+    #[must_use]
+    pub fn get_value(&self) -> Hbar {
+        self.value
+    }
+
+    /// Sets the amount of hbar sent with the call.
+    pub fn value(&mut self, value: Hbar) -> &mut Self {
+        self.value = value;
+        self
+    }
+
+    /// Returns the function parameters as their raw bytes.
+    #[must_use]
+    pub fn get_contract_parameters(&self) -> &[u8] {
+        &self.function_parameters
+    }
+
+    /// Sets the function parameters as their raw bytes.
+    pub fn function_parameters(&mut self, data: Vec<u8>) -> &mut Self {
+        self.function_parameters = data;
+        self
+    }
+
+    /// Sets the function with no parameters.
+    pub fn function(&mut self, name: &str) -> &mut Self {
+        self.function_with_parameters(name, &ContractFunctionParameters::new())
+    }
+
+    /// Sets the function with parameters.
+    pub fn function_with_parameters(
+        &mut self,
+        name: &str,
+        parameters: &ContractFunctionParameters,
+    ) -> &mut Self {
+        self.function_parameters(parameters.to_bytes(Some(name)))
+    }
+
+    /// Returns the block to simulate the call at.
+    #[must_use]
+    pub fn get_block_number(&self) -> Option<u64> {
+        self.block_number
+    }
+
+    /// Sets the block to simulate the call at, by default the latest block is used.
+    pub fn block_number(&mut self, block_number: u64) -> &mut Self {
+        self.block_number = Some(block_number);
+        self
+    }
+
+    fn to_request(
+        &self,
+        contract_id: ContractId,
+        estimate: bool,
+    ) -> crate::Result<ContractCallRequest> {
+        let from = match self.sender_account_id {
+            Some(AccountId { evm_address: Some(evm_address), .. }) => Some(evm_address.to_string()),
+            Some(sender) => Some(format!("0x{}", sender.to_solidity_address()?)),
+            None => None,
+        };
+
+        Ok(ContractCallRequest {
+            block: self.block_number.map_or_else(|| "latest".to_owned(), |it| it.to_string()),
+            data: format!("0x{}", hex::encode(&self.function_parameters)),
+            estimate,
+            from,
+            gas: (self.gas != 0).then_some(self.gas),
+            gas_price: self.gas_price,
+            to: format!("0x{}", contract_id.to_solidity_address()?),
+            value: self.value.to_tinybars(),
+        })
+    }
+
+    async fn call(
+        &self,
+        client: &MirrorRestClient,
+        estimate: bool,
+    ) -> crate::Result<(ContractId, Vec<u8>)>
+    where
+        D: Sync,
+    {
+        let contract_id = self
+            .contract_id
+            .ok_or_else(|| Error::mirror_rest("`contract_id` must be set for a contract call"))?;
+
+        let response: ContractCallResponse =
+            client.post("/contracts/call", &self.to_request(contract_id, estimate)?).await?;
+
+        Ok((contract_id, response.result))
+    }
+}
+
+impl MirrorContractCallQuery {
+    /// Simulate the call on the mirror node `client`.
+    ///
+    /// Only the fields of [`ContractFunctionResult`] the mirror node reports are populated,
+    /// notably `gas_used` is always `0`, use a [`MirrorContractEstimateGasQuery`] for that.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRest`] if the contract ID is unset, the call reverts, or the request otherwise fails.
+    pub async fn execute(
+        &self,
+        client: &MirrorRestClient,
+    ) -> crate::Result<ContractFunctionResult> {
+        let (contract_id, bytes) = self.call(client, false).await?;
+
+        Ok(ContractFunctionResult {
+            contract_id,
+            evm_address: None,
+            bytes,
+            error_message: None,
+            bloom: Vec::new(),
+            gas_used: 0,
+            gas: self.gas,
+            hbar_amount: self.value.to_tinybars().unsigned_abs(),
+            contract_function_parameters_bytes: self.function_parameters.clone(),
+            sender_account_id: self.sender_account_id,
+            logs: Vec::new(),
+            contract_nonces: Vec::new(),
+            signer_nonce: None,
+        })
+    }
+}
+
+impl MirrorContractEstimateGasQuery {
+    /// Estimate the gas used by the call on the mirror node `client`.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRest`] if the contract ID is unset, the call reverts, or the request otherwise fails.
+    pub async fn execute(&self, client: &MirrorRestClient) -> crate::Result<u64> {
+        let (_, result) = self.call(client, true).await?;
+
+        // the estimate is a big endian integer, with however many leading zeroes.
+        let start = result.iter().position(|it| *it != 0).unwrap_or(result.len());
+        let result = &result[start..];
+
+        if result.len() > 8 {
+            return Err(Error::mirror_rest(format!(
+                "gas estimate `0x{}` does not fit in a u64",
+                hex::encode(result)
+            )));
+        }
+
+        Ok(result.iter().fold(0, |acc, it| (acc << 8) | u64::from(*it)))
+    }
+}
+
+#[derive(Debug, serde_derive::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ContractCallRequest {
+    block: String,
+    data: String,
+    estimate: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gas: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gas_price: Option<u64>,
+    to: String,
+    value: i64,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct ContractCallResponse {
+    #[serde(deserialize_with = "de::hex")]
+    result: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use super::{
+        MirrorContractCallQuery,
+        MirrorContractEstimateGasQuery,
+    };
+    use crate::mirror_rest::tests::{
+        serve,
+        serve_with_status,
+    };
+    use crate::{
+        AccountId,
+        ContractFunctionParameters,
+        ContractId,
+        Hbar,
+        MirrorRestClient,
+    };
+
+    fn make_query<D: Default>() -> super::MirrorContractQuery<D> {
+        let mut query = super::MirrorContractQuery::new();
+
+        query
+            .contract_id(ContractId::new(0, 0, 5005))
+            .sender_account_id(AccountId::new(0, 0, 5006))
+            .gas(100_000)
+            .value(Hbar::from_tinybars(10))
+            .function_with_parameters("set", ContractFunctionParameters::new().add_uint32(7));
+
+        query
+    }
+
+    #[test]
+    fn request() {
+        let request = make_query::<super::MirrorContractEstimateGasData>()
+            .to_request(ContractId::new(0, 0, 5005), true)
+            .unwrap();
+
+        expect![[r#"
+            {
+              "block": "latest",
+              "data": "0x58e3ca1c0000000000000000000000000000000000000000000000000000000000000007",
+              "estimate": true,
+              "from": "0x000000000000000000000000000000000000138e",
+              "gas": 100000,
+              "to": "0x000000000000000000000000000000000000138d",
+              "value": 10
+            }"#]]
+        .assert_eq(&serde_json::to_string_pretty(&request).unwrap());
+    }
+
+    #[test]
+    fn request_defaults() {
+        let mut query = MirrorContractCallQuery::new();
+        query.contract_id(ContractId::new(0, 0, 5005)).block_number(42);
+
+        let request = query.to_request(ContractId::new(0, 0, 5005), false).unwrap();
+
+        expect![[r#"
+            {
+              "block": "42",
+              "data": "0x",
+              "estimate": false,
+              "to": "0x000000000000000000000000000000000000138d",
+              "value": 0
+            }"#]]
+        .assert_eq(&serde_json::to_string_pretty(&request).unwrap());
+    }
+
+    #[tokio::test]
+    async fn call() {
+        let base_url = serve(vec![(
+            "/api/v1/contracts/call",
+            r#"{"result": "0x0000000000000000000000000000000000000000000000000000000000000007"}"#,
+        )]);
+
+        let result = make_query::<super::MirrorContractCallData>()
+            .execute(&MirrorRestClient::new(base_url))
+            .await
+            .unwrap();
+
+        assert_eq!(result.contract_id, ContractId::new(0, 0, 5005));
+        assert_eq!(result.sender_account_id, Some(AccountId::new(0, 0, 5006)));
+        assert_eq!(result.get_u32(0), Some(7));
+    }
+
+    #[tokio::test]
+    async fn estimate_gas() {
+        let base_url = serve(vec![(
+            "/api/v1/contracts/call",
+            r#"{"result": "0x000000000000000000000000000000000000000000000000000000000000a410"}"#,
+        )]);
+
+        let gas = MirrorContractEstimateGasQuery::new()
+            .contract_id(ContractId::new(0, 0, 5005))
+            .execute(&MirrorRestClient::new(base_url))
+            .await
+            .unwrap();
+
+        assert_eq!(gas, 42000);
+    }
+
+    #[tokio::test]
+    async fn revert_is_an_error() {
+        let base_url = serve_with_status(vec![(
+            "/api/v1/contracts/call",
+            400,
+            r#"{"_status": {"messages": [{"message": "CONTRACT_REVERT_EXECUTED", "detail": "", "data": "0x"}]}}"#,
+        )]);
+
+        let error = make_query::<super::MirrorContractCallData>()
+            .execute(&MirrorRestClient::new(base_url))
+            .await
+            .unwrap_err();
+
+        expect!["mirror node REST request failed: 400 Bad Request: CONTRACT_REVERT_EXECUTED"]
+            .assert_eq(&error.to_string());
+    }
+
+    #[tokio::test]
+    async fn missing_contract_id_is_an_error() {
+        let error = MirrorContractCallQuery::new()
+            .execute(&MirrorRestClient::new("http://127.0.0.1:1/api/v1"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, crate::Error::MirrorRest(_)));
+    }
+}
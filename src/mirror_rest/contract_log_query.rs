@@ -0,0 +1,578 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::time::Duration;
+
+use futures_core::stream::BoxStream;
+use futures_util::TryStreamExt;
+use time::OffsetDateTime;
+
+use super::{
+    de,
+    timestamp_param,
+    MirrorContractLog,
+    MirrorRestClient,
+};
+use crate::{
+    ContractId,
+    ContractLogInfo,
+};
+
+/// Query the event logs emitted by smart contracts, including those from calls made by others, through the mirror node.
+///
+/// Logs are returned oldest first.
+///
+/// Note that the mirror node only accepts topic filters together with a timestamp (or block) range.
+#[derive(Debug, Clone)]
+pub struct ContractLogQuery {
+    contract_id: Option<ContractId>,
+    topics: [Option<Vec<u8>>; 4],
+    start_time: Option<OffsetDateTime>,
+    end_time: Option<OffsetDateTime>,
+    start_block: Option<u64>,
+    end_block: Option<u64>,
+    limit: Option<u32>,
+    poll_interval: Duration,
+}
+
+impl Default for ContractLogQuery {
+    fn default() -> Self {
+        Self {
+            contract_id: None,
+            topics: Default::default(),
+            start_time: None,
+            end_time: None,
+            start_block: None,
+            end_block: None,
+            limit: None,
+            poll_interval: Self::DEFAULT_POLL_INTERVAL,
+        }
+    }
+}
+
+impl ContractLogQuery {
+    const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+    /// Create a new query, ready for configuration.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the contract to get the logs of.
+    #[must_use]
+    pub fn get_contract_id(&self) -> Option<ContractId> {
+        self.contract_id
+    }
+
+    /// Sets the contract to get the logs of, by default the logs of every contract are returned.
+    pub fn contract_id(&mut self, contract_id: ContractId) -> &mut Self {
+        self.contract_id = Some(contract_id);
+        self
+    }
+
+    /// Returns the filter on the log's first topic, usually the event signature hash.
+    #[must_use]
+    pub fn get_topic0(&self) -> Option<&[u8]> {
+        self.topics[0].as_deref()
+    }
+
+    /// Sets the filter on the log's first topic, usually the event signature hash.
+    pub fn topic0(&mut self, topic: impl Into<Vec<u8>>) -> &mut Self {
+        self.topics[0] = Some(topic.into());
+        self
+    }
+
+    /// Returns the filter on the log's second topic.
+    #[must_use]
+    pub fn get_topic1(&self) -> Option<&[u8]> {
+        self.topics[1].as_deref()
+    }
+
+    /// Sets the filter on the log's second topic.
+    pub fn topic1(&mut self, topic: impl Into<Vec<u8>>) -> &mut Self {
+        self.topics[1] = Some(topic.into());
+        self
+    }
+
+    /// Returns the filter on the log's third topic.
+    #[must_use]
+    pub fn get_topic2(&self) -> Option<&[u8]> {
+        self.topics[2].as_deref()
+    }
+
+    /// Sets the filter on the log's third topic.
+    pub fn topic2(&mut self, topic: impl Into<Vec<u8>>) -> &mut Self {
+        self.topics[2] = Some(topic.into());
+        self
+    }
+
+    /// Returns the filter on the log's fourth topic.
+    #[must_use]
+    pub fn get_topic3(&self) -> Option<&[u8]> {
+        self.topics[3].as_deref()
+    }
+
+    /// Sets the filter on the log's fourth topic.
+    pub fn topic3(&mut self, topic: impl Into<Vec<u8>>) -> &mut Self {
+        self.topics[3] = Some(topic.into());
+        self
+    }
+
+    /// Returns the consensus time from which to include logs.
+    #[must_use]
+    pub fn get_start_time(&self) -> Option<OffsetDateTime> {
+        self.start_time
+    }
+
+    /// Sets the consensus time from which to include logs (inclusive).
+    pub fn start_time(&mut self, start_time: OffsetDateTime) -> &mut Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Returns the consensus time up to which to include logs.
+    #[must_use]
+    pub fn get_end_time(&self) -> Option<OffsetDateTime> {
+        self.end_time
+    }
+
+    /// Sets the consensus time up to which to include logs (inclusive).
+    pub fn end_time(&mut self, end_time: OffsetDateTime) -> &mut Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Returns the block from which to include logs.
+    #[must_use]
+    pub fn get_start_block(&self) -> Option<u64> {
+        self.start_block
+    }
+
+    /// Sets the block from which to include logs (inclusive).
+    ///
+    /// The block is resolved to the consensus time it starts at, when combined with
+    /// [`start_time`](Self::start_time) the later of the two is used.
+    pub fn start_block(&mut self, start_block: u64) -> &mut Self {
+        self.start_block = Some(start_block);
+        self
+    }
+
+    /// Returns the block up to which to include logs.
+    #[must_use]
+    pub fn get_end_block(&self) -> Option<u64> {
+        self.end_block
+    }
+
+    /// Sets the block up to which to include logs (inclusive).
+    ///
+    /// The block is resolved to the consensus time it ends at, when combined with
+    /// [`end_time`](Self::end_time) the earlier of the two is used.
+    pub fn end_block(&mut self, end_block: u64) -> &mut Self {
+        self.end_block = Some(end_block);
+        self
+    }
+
+    /// Returns the number of logs requested per page.
+    #[must_use]
+    pub fn get_limit(&self) -> Option<u32> {
+        self.limit
+    }
+
+    /// Sets the number of logs requested per page, by default the mirror node decides.
+    pub fn limit(&mut self, limit: u32) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns how long [`subscribe`](Self::subscribe) waits between polls.
+    #[must_use]
+    pub fn get_poll_interval(&self) -> Duration {
+        self.poll_interval
+    }
+
+    /// Sets how long [`subscribe`](Self::subscribe) waits between polls.
+    ///
+    /// Defaults to 2 seconds.
+    pub fn poll_interval(&mut self, poll_interval: Duration) -> &mut Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Collect every matching log.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRest`](crate::Error::MirrorRest) if any request fails or a response can't be decoded.
+    pub async fn execute(&self, client: &MirrorRestClient) -> crate::Result<Vec<ContractLogInfo>> {
+        self.stream(client).try_collect().await
+    }
+
+    /// Stream every matching log, fetching pages as they're needed.
+    pub fn stream(
+        &self,
+        client: &MirrorRestClient,
+    ) -> BoxStream<'static, crate::Result<ContractLogInfo>> {
+        let this = self.clone();
+        let client = client.clone();
+
+        Box::pin(async_stream::try_stream! {
+            let (start, end) = this.resolve_range(&client).await?;
+
+            let mut logs = client.paginate::<MirrorContractLog>(this.path(start, end), "logs");
+
+            while let Some(log) = logs.try_next().await? {
+                yield log_info(log)?;
+            }
+        })
+    }
+
+    /// Stream matching logs as they appear, polling the mirror node every [`poll_interval`](Self::poll_interval).
+    ///
+    /// Starts from [`start_time`](Self::start_time) (or the start block), or from now if neither is set.
+    /// With an end time (or block) the stream ends after the first poll made once that time has passed,
+    /// otherwise it doesn't end by itself.
+    ///
+    /// Each log comes with its position, which can be used as a [`start_time`](Self::start_time) to
+    /// resume after a restart.
+    pub fn subscribe(
+        &self,
+        client: &MirrorRestClient,
+    ) -> BoxStream<'static, crate::Result<ContractLogEntry>> {
+        let this = self.clone();
+        let client = client.clone();
+
+        Box::pin(async_stream::try_stream! {
+            let (start, end) = this.resolve_range(&client).await?;
+
+            // `(consensus timestamp, index)` of the last log yielded, polls start at its timestamp
+            // (inclusive) since other logs from the same transaction may not have been seen yet.
+            let mut cursor: Option<(OffsetDateTime, u32)> = None;
+            let mut start = start.unwrap_or_else(OffsetDateTime::now_utc);
+
+            loop {
+                let polled_at = OffsetDateTime::now_utc();
+                let mut logs = client.paginate::<MirrorContractLog>(this.path(Some(start), end), "logs");
+
+                while let Some(log) = logs.try_next().await? {
+                    let position = (log.timestamp, log.index);
+
+                    if cursor.is_some_and(|cursor| position <= cursor) {
+                        continue;
+                    }
+
+                    cursor = Some(position);
+                    start = log.timestamp;
+
+                    yield ContractLogEntry {
+                        consensus_timestamp: log.timestamp,
+                        index: log.index,
+                        log: log_info(log)?,
+                    };
+                }
+
+                if end.is_some_and(|end| end <= polled_at) {
+                    break;
+                }
+
+                tokio::time::sleep(this.poll_interval).await;
+            }
+        })
+    }
+
+    /// Resolves the block range (if any) and combines it with the time range.
+    async fn resolve_range(
+        &self,
+        client: &MirrorRestClient,
+    ) -> crate::Result<(Option<OffsetDateTime>, Option<OffsetDateTime>)> {
+        let mut start = self.start_time;
+        let mut end = self.end_time;
+
+        if let Some(block) = self.start_block {
+            let block: MirrorBlock = client.get(&format!("/blocks/{block}")).await?;
+            start = start.max(Some(block.timestamp.from));
+        }
+
+        if let Some(block) = self.end_block {
+            let block: MirrorBlock = client.get(&format!("/blocks/{block}")).await?;
+            end = Some(end.map_or(block.timestamp.to, |end| end.min(block.timestamp.to)));
+        }
+
+        Ok((start, end))
+    }
+
+    fn path(&self, start: Option<OffsetDateTime>, end: Option<OffsetDateTime>) -> String {
+        let mut path = match self.contract_id {
+            Some(contract_id) => format!("/contracts/{contract_id}/results/logs?order=asc"),
+            None => "/contracts/results/logs?order=asc".to_owned(),
+        };
+
+        if let Some(start) = start {
+            path.push_str(&format!("&timestamp=gte:{}", timestamp_param(start)));
+        }
+
+        if let Some(end) = end {
+            path.push_str(&format!("&timestamp=lte:{}", timestamp_param(end)));
+        }
+
+        for (index, topic) in self.topics.iter().enumerate() {
+            if let Some(topic) = topic {
+                path.push_str(&format!("&topic{index}=0x{}", hex::encode(topic)));
+            }
+        }
+
+        if let Some(limit) = self.limit {
+            path.push_str(&format!("&limit={limit}"));
+        }
+
+        path
+    }
+}
+
+/// A log yielded by [`ContractLogQuery::subscribe`], along with its position.
+#[derive(Debug, Clone)]
+pub struct ContractLogEntry {
+    /// The consensus time of the transaction that emitted the log.
+    pub consensus_timestamp: OffsetDateTime,
+
+    /// The index of the log within its block.
+    pub index: u32,
+
+    /// The log itself.
+    pub log: ContractLogInfo,
+}
+
+fn log_info(log: MirrorContractLog) -> crate::Result<ContractLogInfo> {
+    let contract_id = match log.contract_id {
+        Some(contract_id) => contract_id,
+        None => ContractId::from_evm_address(0, 0, &log.address)?,
+    };
+
+    Ok(ContractLogInfo { contract_id, bloom: log.bloom, topics: log.topics, data: log.data })
+}
+
+#[derive(serde_derive::Deserialize)]
+struct MirrorBlock {
+    timestamp: MirrorBlockTimestamp,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct MirrorBlockTimestamp {
+    #[serde(deserialize_with = "de::timestamp")]
+    from: OffsetDateTime,
+
+    #[serde(deserialize_with = "de::timestamp")]
+    to: OffsetDateTime,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use expect_test::expect;
+    use futures_util::StreamExt;
+    use time::OffsetDateTime;
+
+    use super::ContractLogQuery;
+    use crate::mirror_rest::tests::serve;
+    use crate::{
+        ContractId,
+        MirrorRestClient,
+    };
+
+    const LOG_1: &str = r#"{
+        "address": "0x0000000000000000000000000000000000000bb8",
+        "bloom": "0x01",
+        "contract_id": "0.0.3000",
+        "data": "0x0102",
+        "index": 0,
+        "topics": ["0xabcd"],
+        "timestamp": "1700000000.000000001"
+    }"#;
+
+    const LOG_2: &str = r#"{
+        "address": "0x0000000000000000000000000000000000000bb9",
+        "bloom": "0x",
+        "contract_id": null,
+        "data": "0x03",
+        "index": 1,
+        "topics": ["0xabcd", "0xef01"],
+        "timestamp": "1700000000.000000001"
+    }"#;
+
+    fn page(logs: &[&str], next: Option<&str>) -> &'static str {
+        let next = next.map_or_else(|| "null".to_owned(), |it| format!("\"{it}\""));
+
+        format!(r#"{{"logs": [{}], "links": {{"next": {next}}}}}"#, logs.join(",")).leak()
+    }
+
+    #[test]
+    fn path() {
+        let mut query = ContractLogQuery::new();
+        query
+            .contract_id(ContractId::new(0, 0, 3000))
+            .topic0([0xab, 0xcd])
+            .topic2([0xef])
+            .limit(25);
+
+        expect!["/contracts/0.0.3000/results/logs?order=asc&timestamp=gte:1700000000.000000001&timestamp=lte:1700000100.000000000&topic0=0xabcd&topic2=0xef&limit=25"]
+            .assert_eq(&query.path(
+                Some(OffsetDateTime::from_unix_timestamp_nanos(1_700_000_000_000_000_001).unwrap()),
+                Some(OffsetDateTime::from_unix_timestamp(1_700_000_100).unwrap()),
+            ));
+
+        expect!["/contracts/results/logs?order=asc"]
+            .assert_eq(&ContractLogQuery::new().path(None, None));
+    }
+
+    #[tokio::test]
+    async fn execute_follows_pages() {
+        let base_url = serve(vec![
+            (
+                "/api/v1/contracts/0.0.3000/results/logs?order=asc",
+                page(
+                    &[LOG_1],
+                    Some("/api/v1/contracts/0.0.3000/results/logs?order=asc&index=gt:0"),
+                ),
+            ),
+            ("/api/v1/contracts/0.0.3000/results/logs?order=asc&index=gt:0", page(&[LOG_2], None)),
+        ]);
+
+        let logs = ContractLogQuery::new()
+            .contract_id(ContractId::new(0, 0, 3000))
+            .execute(&MirrorRestClient::new(base_url))
+            .await
+            .unwrap();
+
+        expect![[r#"
+            [
+                ContractLogInfo {
+                    contract_id: "0.0.3000",
+                    bloom: [
+                        1,
+                    ],
+                    topics: [
+                        [
+                            171,
+                            205,
+                        ],
+                    ],
+                    data: [
+                        1,
+                        2,
+                    ],
+                },
+                ContractLogInfo {
+                    contract_id: "0.0.0000000000000000000000000000000000000bb9",
+                    bloom: [],
+                    topics: [
+                        [
+                            171,
+                            205,
+                        ],
+                        [
+                            239,
+                            1,
+                        ],
+                    ],
+                    data: [
+                        3,
+                    ],
+                },
+            ]
+        "#]]
+        .assert_debug_eq(&logs);
+    }
+
+    #[tokio::test]
+    async fn block_range() {
+        let base_url = serve(vec![
+            ("/api/v1/blocks/10", r#"{"timestamp": {"from": "1700000000.000000000", "to": "1700000001.999999999"}}"#),
+            ("/api/v1/blocks/12", r#"{"timestamp": {"from": "1700000004.000000000", "to": "1700000005.999999999"}}"#),
+            (
+                "/api/v1/contracts/results/logs?order=asc&timestamp=gte:1700000000.000000000&timestamp=lte:1700000005.999999999&topic0=0xabcd",
+                page(&[LOG_1], None),
+            ),
+        ]);
+
+        let logs = ContractLogQuery::new()
+            .start_block(10)
+            .end_block(12)
+            .topic0([0xab, 0xcd])
+            .execute(&MirrorRestClient::new(base_url))
+            .await
+            .unwrap();
+
+        assert_eq!(logs.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn subscribe_yields_each_log_once() {
+        let base_url = serve(vec![
+            (
+                "/api/v1/contracts/0.0.3000/results/logs?order=asc&timestamp=gte:1699999999.000000000",
+                page(&[LOG_1], None),
+            ),
+            // the second log appears in a later poll, alongside the already seen first one.
+            (
+                "/api/v1/contracts/0.0.3000/results/logs?order=asc&timestamp=gte:1700000000.000000001",
+                page(&[LOG_1, LOG_2], None),
+            ),
+        ]);
+
+        let logs: Vec<_> = ContractLogQuery::new()
+            .contract_id(ContractId::new(0, 0, 3000))
+            .start_time(OffsetDateTime::from_unix_timestamp(1_699_999_999).unwrap())
+            .poll_interval(Duration::from_millis(10))
+            .subscribe(&MirrorRestClient::new(base_url))
+            .take(2)
+            .collect()
+            .await;
+
+        let logs: Vec<_> = logs.into_iter().map(|it| it.unwrap()).collect();
+
+        let positions: Vec<_> = logs.iter().map(|it| (it.consensus_timestamp, it.index)).collect();
+        let data: Vec<_> = logs.into_iter().map(|it| it.log.data).collect();
+
+        let timestamp =
+            OffsetDateTime::from_unix_timestamp_nanos(1_700_000_000_000_000_001).unwrap();
+
+        assert_eq!(positions, [(timestamp, 0), (timestamp, 1)]);
+        assert_eq!(data, [vec![1, 2], vec![3]]);
+    }
+
+    #[tokio::test]
+    async fn subscribe_ends_after_end_time() {
+        let base_url = serve(vec![(
+            "/api/v1/contracts/0.0.3000/results/logs?order=asc&timestamp=gte:1699999999.000000000&timestamp=lte:1700000100.000000000",
+            page(&[LOG_1, LOG_2], None),
+        )]);
+
+        let logs: Vec<_> = ContractLogQuery::new()
+            .contract_id(ContractId::new(0, 0, 3000))
+            .start_time(OffsetDateTime::from_unix_timestamp(1_699_999_999).unwrap())
+            .end_time(OffsetDateTime::from_unix_timestamp(1_700_000_100).unwrap())
+            .poll_interval(Duration::from_millis(10))
+            .subscribe(&MirrorRestClient::new(base_url))
+            .collect()
+            .await;
+
+        assert_eq!(logs.len(), 2);
+    }
+}
@@ -0,0 +1,176 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Deserialization helpers for the formats used by the mirror node REST API.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::de::Error as _;
+use serde::{
+    Deserialize,
+    Deserializer,
+};
+use time::OffsetDateTime;
+
+use crate::{
+    Hbar,
+    Status,
+    TokenSupplyType,
+    TokenType,
+    TransactionId,
+};
+
+/// Deserializes a `T` from its string representation (ex. an entity ID of `"0.0.1001"`).
+pub(super) fn from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    String::deserialize(deserializer)?.parse().map_err(D::Error::custom)
+}
+
+/// Like [`from_str`], but `null` (or a missing field, with `#[serde(default)]`) becomes `None`.
+pub(super) fn option_from_str<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|it| it.parse().map_err(D::Error::custom))
+        .transpose()
+}
+
+/// Like [`from_str`], but for a list of values.
+pub(super) fn vec_from_str<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    Option::<Vec<String>>::deserialize(deserializer)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|it| it.parse().map_err(D::Error::custom))
+        .collect()
+}
+
+/// Parses a mirror node timestamp (`"<seconds>.<nanoseconds>"`).
+pub(super) fn parse_timestamp(s: &str) -> crate::Result<OffsetDateTime> {
+    let (seconds, nanos) = s.split_once('.').unwrap_or((s, "0"));
+
+    let seconds = i64::from_str(seconds).map_err(crate::Error::basic_parse)?;
+    let nanos = i64::from_str(nanos).map_err(crate::Error::basic_parse)?;
+
+    OffsetDateTime::from_unix_timestamp_nanos(
+        i128::from(seconds) * 1_000_000_000 + i128::from(nanos),
+    )
+    .map_err(crate::Error::basic_parse)
+}
+
+pub(super) fn timestamp<'de, D>(deserializer: D) -> Result<OffsetDateTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    parse_timestamp(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+}
+
+pub(super) fn option_timestamp<'de, D>(deserializer: D) -> Result<Option<OffsetDateTime>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|it| parse_timestamp(&it).map_err(D::Error::custom))
+        .transpose()
+}
+
+/// Deserializes an amount of tinybars.
+pub(super) fn tinybars<'de, D>(deserializer: D) -> Result<Hbar, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    i64::deserialize(deserializer).map(Hbar::from_tinybars)
+}
+
+/// Decodes a `0x` prefixed hex string, where `null` is treated as empty.
+pub(super) fn hex<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+
+    ::hex::decode(s.strip_prefix("0x").unwrap_or(&s)).map_err(D::Error::custom)
+}
+
+/// Like [`hex`], but for a list of values.
+pub(super) fn vec_hex<'de, D>(deserializer: D) -> Result<Vec<Vec<u8>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<Vec<String>>::deserialize(deserializer)?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|s| ::hex::decode(s.strip_prefix("0x").unwrap_or(&s)).map_err(D::Error::custom))
+        .collect()
+}
+
+/// Deserializes a response code name (ex. `"SUCCESS"`).
+///
+/// Names this SDK doesn't know yet (ex. from a newer network version) become [`Status::Unknown`].
+pub(super) fn status<'de, D>(deserializer: D) -> Result<Status, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+
+    Ok(Status::from_str_name(&s).unwrap_or(Status::Unknown))
+}
+
+pub(super) fn token_type<'de, D>(deserializer: D) -> Result<TokenType, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match String::deserialize(deserializer)?.as_str() {
+        "FUNGIBLE_COMMON" => Ok(TokenType::FungibleCommon),
+        "NON_FUNGIBLE_UNIQUE" => Ok(TokenType::NonFungibleUnique),
+        s => Err(D::Error::custom(format!("unknown token type `{s}`"))),
+    }
+}
+
+pub(super) fn token_supply_type<'de, D>(deserializer: D) -> Result<TokenSupplyType, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match String::deserialize(deserializer)?.as_str() {
+        "INFINITE" => Ok(TokenSupplyType::Infinite),
+        "FINITE" => Ok(TokenSupplyType::Finite),
+        s => Err(D::Error::custom(format!("unknown token supply type `{s}`"))),
+    }
+}
+
+/// Deserializes a transaction ID in the mirror node's format (`"0.0.3-1234567890-000000001"`).
+pub(super) fn transaction_id<'de, D>(deserializer: D) -> Result<TransactionId, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    super::parse_transaction_id_path(&String::deserialize(deserializer)?).map_err(D::Error::custom)
+}
@@ -0,0 +1,547 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Access to the mirror node REST API.
+
+use futures_core::stream::BoxStream;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    TransactionId,
+};
+
+mod account;
+mod contract;
+mod contract_call;
+mod contract_log_query;
+mod de;
+mod token;
+mod transaction;
+
+pub use account::{
+    MirrorAccount,
+    MirrorAccountBalance,
+    MirrorNft,
+    MirrorTokenBalance,
+    MirrorTokenRelationship,
+};
+pub use contract::{
+    MirrorContract,
+    MirrorContractLog,
+    MirrorContractResult,
+};
+pub use contract_call::{
+    MirrorContractCallData,
+    MirrorContractCallQuery,
+    MirrorContractEstimateGasData,
+    MirrorContractEstimateGasQuery,
+    MirrorContractQuery,
+};
+pub use contract_log_query::{
+    ContractLogEntry,
+    ContractLogQuery,
+};
+pub use token::MirrorToken;
+pub use transaction::{
+    MirrorTokenTransfer,
+    MirrorTransaction,
+    MirrorTransfer,
+};
+
+/// REST API url of the public mainnet mirror node.
+pub(crate) const MAINNET_URL: &str = "https://mainnet-public.mirrornode.hedera.com/api/v1";
+
+/// REST API url of the public testnet mirror node.
+pub(crate) const TESTNET_URL: &str = "https://testnet.mirrornode.hedera.com/api/v1";
+
+/// REST API url of the public previewnet mirror node.
+pub(crate) const PREVIEWNET_URL: &str = "https://previewnet.mirrornode.hedera.com/api/v1";
+
+/// A typed client for the `/api/v1` endpoints of a mirror node's REST API.
+///
+/// Endpoints that return lists follow the mirror node's `links.next` pagination, yielding every
+/// item as a [`Stream`](futures_core::Stream).
+#[derive(Debug, Clone)]
+pub struct MirrorRestClient {
+    http: reqwest::Client,
+    base_url: String,
+}
+
+impl MirrorRestClient {
+    /// Create a client for the REST API at `base_url` (ex. `https://testnet.mirrornode.hedera.com/api/v1`).
+    #[must_use]
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let base_url: String = base_url.into();
+
+        Self { http: reqwest::Client::new(), base_url: base_url.trim_end_matches('/').to_owned() }
+    }
+
+    /// Create a client for the REST API configured on `client` (see [`Client::set_mirror_rest_url`]).
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRestUrlUnset`] if `client` has no mirror node REST API url configured.
+    pub fn for_client(client: &Client) -> crate::Result<Self> {
+        client.mirror_rest_url().map(Self::new).ok_or(Error::MirrorRestUrlUnset)
+    }
+
+    /// Returns the base url of the REST API.
+    #[must_use]
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    async fn get_url<T: DeserializeOwned>(&self, url: &str) -> crate::Result<T> {
+        let response = self.http.get(url).send().await.map_err(Error::mirror_rest)?;
+
+        decode_response(response).await
+    }
+
+    /// `GET`s `path` (relative to the base url) and decodes the response.
+    pub(crate) async fn get<T: DeserializeOwned>(&self, path: &str) -> crate::Result<T> {
+        self.get_url(&format!("{}{path}", self.base_url)).await
+    }
+
+    /// `POST`s `body` as JSON to `path` (relative to the base url) and decodes the response.
+    pub(crate) async fn post<B: Serialize + Sync, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> crate::Result<T> {
+        let response = self
+            .http
+            .post(format!("{}{path}", self.base_url))
+            .json(body)
+            .send()
+            .await
+            .map_err(Error::mirror_rest)?;
+
+        decode_response(response).await
+    }
+
+    /// Streams every item of the list named `key` from `path` and each of its following pages.
+    pub(crate) fn paginate<T>(
+        &self,
+        path: String,
+        key: &'static str,
+    ) -> BoxStream<'static, crate::Result<T>>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let this = self.clone();
+
+        Box::pin(async_stream::try_stream! {
+            let mut next = Some(format!("{}{path}", this.base_url));
+
+            while let Some(url) = next.take() {
+                let mut page: serde_json::Value = this.get_url(&url).await?;
+
+                let items: Vec<T> = serde_json::from_value(page[key].take())
+                    .map_err(Error::mirror_rest)?;
+
+                next = match page["links"]["next"].as_str() {
+                    Some(next) => Some(this.next_url(next)?),
+                    None => None,
+                };
+
+                for item in items {
+                    yield item;
+                }
+            }
+        })
+    }
+
+    /// Resolves a `links.next` path (ex. `/api/v1/accounts?limit=25&account.id=gt:0.0.10`)
+    /// against the base url.
+    fn next_url(&self, next: &str) -> crate::Result<String> {
+        let base = reqwest::Url::parse(&self.base_url).map_err(Error::mirror_rest)?;
+
+        base.join(next).map(String::from).map_err(Error::mirror_rest)
+    }
+}
+
+/// Decodes a successful response, or turns an unsuccessful one into an error.
+///
+/// The mirror node describes failures as `{"_status": {"messages": [{"message": ...}]}}`,
+/// when it does the first message is included in the error.
+async fn decode_response<T: DeserializeOwned>(response: reqwest::Response) -> crate::Result<T> {
+    let status = response.status();
+
+    if status.is_success() {
+        return response.json().await.map_err(Error::mirror_rest);
+    }
+
+    let body: serde_json::Value = response.json().await.unwrap_or_default();
+
+    let message = match body["_status"]["messages"][0]["message"].as_str() {
+        Some(message) => format!("{status}: {message}"),
+        None => status.to_string(),
+    };
+
+    Err(Error::mirror_rest(message))
+}
+
+/// Formats `timestamp` the way the mirror node expects it in a query parameter (`1234567890.000000001`).
+pub(crate) fn timestamp_param(timestamp: OffsetDateTime) -> String {
+    format!("{}.{:09}", timestamp.unix_timestamp(), timestamp.nanosecond())
+}
+
+/// Formats `transaction_id` the way the mirror node expects it in a url path (`0.0.3-1234567890-000000001`).
+pub(crate) fn transaction_id_path(transaction_id: &TransactionId) -> String {
+    let valid_start = transaction_id.valid_start;
+
+    format!(
+        "{}-{}-{:09}",
+        transaction_id.account_id,
+        valid_start.unix_timestamp(),
+        valid_start.nanosecond()
+    )
+}
+
+/// Parses a transaction ID formatted the way the mirror node returns it (`0.0.3-1234567890-000000001`).
+pub(crate) fn parse_transaction_id_path(s: &str) -> crate::Result<TransactionId> {
+    const EXPECTED: &str = "expecting <accountId>-<seconds>-<nanos>";
+
+    let (account_id, s) = s.split_once('-').ok_or_else(|| Error::basic_parse(EXPECTED))?;
+    let (seconds, nanos) = s.split_once('-').ok_or_else(|| Error::basic_parse(EXPECTED))?;
+
+    let valid_start = de::parse_timestamp(&format!("{seconds}.{nanos}"))?;
+
+    Ok(TransactionId {
+        account_id: account_id.parse::<AccountId>()?,
+        valid_start,
+        nonce: None,
+        scheduled: false,
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::collections::HashMap;
+    use std::io::{
+        BufRead,
+        BufReader,
+        Read,
+        Write,
+    };
+    use std::net::TcpListener;
+
+    use expect_test::expect;
+    use futures_util::TryStreamExt;
+
+    use super::MirrorRestClient;
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::{
+        AccountId,
+        Client,
+        ContractId,
+        Error,
+    };
+
+    /// Serves canned JSON bodies by path on a local port, returning the base url to use.
+    pub(crate) fn serve(routes: Vec<(&'static str, &'static str)>) -> String {
+        serve_with_status(routes.into_iter().map(|(path, body)| (path, 200, body)).collect())
+    }
+
+    /// Like [`serve`], but each route also has the status code to respond with.
+    pub(crate) fn serve_with_status(routes: Vec<(&'static str, u16, &'static str)>) -> String {
+        serve_fn(move |path| {
+            routes
+                .iter()
+                .find(|(route, ..)| *route == path)
+                .map_or((404, String::new()), |(_, status, body)| (*status, (*body).to_owned()))
+        })
+    }
+
+    /// Like [`serve`], but responds with whatever `handler` returns for the request's path.
+    pub(crate) fn serve_fn(
+        mut handler: impl FnMut(&str) -> (u16, String) + Send + 'static,
+    ) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&stream);
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+
+                // the stand-in only cares about the length of the body, so that it can be drained.
+                let mut content_length = 0;
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            content_length = value.trim().parse().unwrap();
+                        }
+                    }
+
+                    line.clear();
+                }
+
+                reader.read_exact(&mut vec![0; content_length]).unwrap();
+
+                let path = request_line.split(' ').nth(1).unwrap_or_default();
+
+                let (status, body) = handler(path);
+
+                let response = format!(
+                    "HTTP/1.1 {status} Stand-in\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        format!("http://{address}/api/v1")
+    }
+
+    #[tokio::test]
+    async fn for_client() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        assert!(matches!(MirrorRestClient::for_client(&client), Err(Error::MirrorRestUrlUnset)));
+
+        client.set_mirror_rest_url(Some("http://127.0.0.1:5551/api/v1/".to_owned()));
+
+        expect!["http://127.0.0.1:5551/api/v1"]
+            .assert_eq(MirrorRestClient::for_client(&client).unwrap().base_url());
+
+        expect!["https://testnet.mirrornode.hedera.com/api/v1"]
+            .assert_eq(MirrorRestClient::for_client(&Client::for_testnet()).unwrap().base_url());
+    }
+
+    #[test]
+    fn transaction_id_path() {
+        expect!["0.0.5006-1554158542-000000000"]
+            .assert_eq(&super::transaction_id_path(&TEST_TX_ID));
+    }
+
+    #[test]
+    fn parse_transaction_id_path() {
+        let transaction_id =
+            super::parse_transaction_id_path("0.0.5006-1554158542-000000000").unwrap();
+
+        assert_eq!(transaction_id, TEST_TX_ID);
+
+        assert!(super::parse_transaction_id_path("0.0.5006@1554158542.000000000").is_err());
+    }
+
+    #[tokio::test]
+    async fn account() {
+        let base_url = serve(vec![(
+            "/api/v1/accounts/0.0.1001",
+            r#"{
+                "account": "0.0.1001",
+                "alias": null,
+                "balance": {
+                    "balance": 100000000,
+                    "timestamp": "1700000000.000000001",
+                    "tokens": [{ "token_id": "0.0.2000", "balance": 5 }]
+                },
+                "decline_reward": false,
+                "deleted": false,
+                "evm_address": "0x00000000000000000000000000000000000003e9",
+                "expiry_timestamp": "1707776000.000000000",
+                "max_automatic_token_associations": 10,
+                "memo": "hello",
+                "staked_account_id": null,
+                "staked_node_id": 3
+            }"#,
+        )]);
+
+        let account =
+            MirrorRestClient::new(base_url).account(AccountId::new(0, 0, 1001)).await.unwrap();
+
+        expect![[r#"
+            MirrorAccount {
+                account_id: "0.0.1001",
+                alias: None,
+                evm_address: Some(
+                    "0x00000000000000000000000000000000000003e9",
+                ),
+                balance: MirrorAccountBalance {
+                    balance: "1 ℏ",
+                    timestamp: Some(
+                        2023-11-14 22:13:20.000000001 +00:00:00,
+                    ),
+                    tokens: [
+                        MirrorTokenBalance {
+                            token_id: "0.0.2000",
+                            balance: 5,
+                        },
+                    ],
+                },
+                deleted: false,
+                memo: "hello",
+                decline_reward: false,
+                staked_account_id: None,
+                staked_node_id: Some(
+                    3,
+                ),
+                max_automatic_token_associations: 10,
+                expiry_timestamp: Some(
+                    2024-02-12 22:13:20.0 +00:00:00,
+                ),
+            }
+        "#]]
+        .assert_debug_eq(&account);
+    }
+
+    #[tokio::test]
+    async fn account_tokens_follows_next_links() {
+        let base_url = serve(vec![
+            (
+                "/api/v1/accounts/0.0.1001/tokens",
+                r#"{
+                    "tokens": [{ "token_id": "0.0.2000", "balance": 5, "freeze_status": "UNFROZEN" }],
+                    "links": { "next": "/api/v1/accounts/0.0.1001/tokens?limit=1&token.id=gt:0.0.2000" }
+                }"#,
+            ),
+            (
+                "/api/v1/accounts/0.0.1001/tokens?limit=1&token.id=gt:0.0.2000",
+                r#"{
+                    "tokens": [{ "token_id": "0.0.2001", "balance": 7, "kyc_status": "GRANTED" }],
+                    "links": { "next": null }
+                }"#,
+            ),
+        ]);
+
+        let tokens: Vec<_> = MirrorRestClient::new(base_url)
+            .account_tokens(AccountId::new(0, 0, 1001))
+            .try_collect()
+            .await
+            .unwrap();
+
+        let tokens: Vec<_> =
+            tokens.iter().map(|it| (it.token_id.to_string(), it.balance)).collect();
+
+        assert_eq!(tokens, [("0.0.2000".to_owned(), 5), ("0.0.2001".to_owned(), 7)]);
+    }
+
+    #[tokio::test]
+    async fn transaction() {
+        let base_url = serve(vec![(
+            "/api/v1/transactions/0.0.5006-1554158542-000000000",
+            r#"{
+                "transactions": [{
+                    "transaction_id": "0.0.5006-1554158542-000000000",
+                    "consensus_timestamp": "1554158550.000000001",
+                    "name": "CRYPTOTRANSFER",
+                    "result": "SUCCESS",
+                    "charged_tx_fee": 84000,
+                    "memo_base64": "",
+                    "node": "0.0.3",
+                    "nonce": 0,
+                    "scheduled": false,
+                    "transfers": [
+                        { "account": "0.0.5006", "amount": -84001, "is_approval": false },
+                        { "account": "0.0.3", "amount": 84001, "is_approval": false }
+                    ],
+                    "token_transfers": [
+                        { "token_id": "0.0.2000", "account": "0.0.5006", "amount": -1, "is_approval": true }
+                    ]
+                }]
+            }"#,
+        )]);
+
+        let transactions = MirrorRestClient::new(base_url).transaction(&TEST_TX_ID).await.unwrap();
+
+        assert_eq!(transactions.len(), 1);
+
+        let transaction = &transactions[0];
+
+        assert_eq!(transaction.transaction_id, TEST_TX_ID);
+        assert_eq!(transaction.result, crate::Status::Success);
+        assert_eq!(transaction.charged_tx_fee, crate::Hbar::from_tinybars(84000));
+        assert_eq!(transaction.node, Some(AccountId::new(0, 0, 3)));
+        assert_eq!(transaction.transfers[0].amount, crate::Hbar::from_tinybars(-84001));
+        assert_eq!(transaction.token_transfers[0].amount, -1);
+        assert!(transaction.token_transfers[0].is_approval);
+    }
+
+    #[tokio::test]
+    async fn transaction_with_unrecognized_status() {
+        let base_url = serve(vec![(
+            "/api/v1/transactions/0.0.5006-1554158542-000000000",
+            r#"{
+                "transactions": [{
+                    "transaction_id": "0.0.5006-1554158542-000000000",
+                    "consensus_timestamp": "1554158550.000000001",
+                    "name": "CRYPTOTRANSFER",
+                    "result": "SOME_FUTURE_RESPONSE_CODE",
+                    "charged_tx_fee": 84000
+                }]
+            }"#,
+        )]);
+
+        let transactions = MirrorRestClient::new(base_url).transaction(&TEST_TX_ID).await.unwrap();
+
+        assert_eq!(transactions[0].result, crate::Status::Unknown);
+    }
+
+    #[tokio::test]
+    async fn contract_logs() {
+        let base_url = serve(vec![(
+            "/api/v1/contracts/0.0.3000/results/logs",
+            r#"{
+                "logs": [{
+                    "address": "0x0000000000000000000000000000000000000bb8",
+                    "contract_id": "0.0.3000",
+                    "data": "0x0102",
+                    "index": 0,
+                    "topics": ["0xabcd", "0xef01"],
+                    "timestamp": "1700000000.000000001",
+                    "transaction_hash": "0x00",
+                    "block_number": 10,
+                    "root_contract_id": "0.0.3000"
+                }],
+                "links": { "next": null }
+            }"#,
+        )]);
+
+        let logs: Vec<_> = MirrorRestClient::new(base_url)
+            .contract_logs(ContractId::new(0, 0, 3000))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].contract_id, Some(ContractId::new(0, 0, 3000)));
+        assert_eq!(logs[0].data, [1, 2]);
+        assert_eq!(logs[0].topics, [vec![0xab, 0xcd], vec![0xef, 0x01]]);
+    }
+
+    #[tokio::test]
+    async fn missing_entity_is_an_error() {
+        let base_url = serve(Vec::new());
+
+        let error = MirrorRestClient::new(base_url).token(crate::TokenId::new(0, 0, 1)).await;
+
+        assert!(matches!(error, Err(crate::Error::MirrorRest(_))));
+    }
+}
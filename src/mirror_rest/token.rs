@@ -0,0 +1,99 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use futures_core::stream::BoxStream;
+use time::OffsetDateTime;
+
+use super::{
+    de,
+    MirrorNft,
+    MirrorRestClient,
+};
+use crate::{
+    AccountId,
+    TokenId,
+    TokenSupplyType,
+    TokenType,
+};
+
+/// A token, as returned by the mirror node's `/tokens/{id}` endpoint.
+#[derive(Debug, Clone, serde_derive::Deserialize)]
+pub struct MirrorToken {
+    /// The ID of the token.
+    #[serde(deserialize_with = "de::from_str")]
+    pub token_id: TokenId,
+
+    /// The name of the token.
+    pub name: String,
+
+    /// The symbol of the token.
+    pub symbol: String,
+
+    /// The number of decimal places the token is divisible by.
+    #[serde(deserialize_with = "de::from_str")]
+    pub decimals: u32,
+
+    /// The total supply of the token, in its smallest denomination.
+    #[serde(deserialize_with = "de::from_str")]
+    pub total_supply: u64,
+
+    /// The maximum supply of the token, `0` if the supply is infinite.
+    #[serde(default, deserialize_with = "de::option_from_str")]
+    pub max_supply: Option<u64>,
+
+    /// The treasury account of the token.
+    #[serde(default, deserialize_with = "de::option_from_str")]
+    pub treasury_account_id: Option<AccountId>,
+
+    /// Whether the token is fungible or non-fungible.
+    #[serde(rename = "type", deserialize_with = "de::token_type")]
+    pub token_type: TokenType,
+
+    /// Whether the token's supply is finite or infinite.
+    #[serde(deserialize_with = "de::token_supply_type")]
+    pub supply_type: TokenSupplyType,
+
+    /// The token's memo.
+    #[serde(default)]
+    pub memo: String,
+
+    /// Whether the token has been deleted.
+    #[serde(default)]
+    pub deleted: bool,
+
+    /// When the token was created.
+    #[serde(default, deserialize_with = "de::option_timestamp")]
+    pub created_timestamp: Option<OffsetDateTime>,
+}
+
+impl MirrorRestClient {
+    /// Fetch the token `token_id`.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRest`](crate::Error::MirrorRest) if the request fails or the response can't be decoded.
+    pub async fn token(&self, token_id: TokenId) -> crate::Result<MirrorToken> {
+        self.get(&format!("/tokens/{token_id}")).await
+    }
+
+    /// Stream the NFTs of the token `token_id`.
+    pub fn token_nfts(&self, token_id: TokenId) -> BoxStream<'static, crate::Result<MirrorNft>> {
+        self.paginate(format!("/tokens/{token_id}/nfts"), "nfts")
+    }
+}
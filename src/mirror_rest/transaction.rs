@@ -0,0 +1,187 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use futures_core::stream::BoxStream;
+use time::OffsetDateTime;
+
+use super::{
+    de,
+    transaction_id_path,
+    MirrorRestClient,
+};
+use crate::{
+    AccountId,
+    Error,
+    Hbar,
+    Status,
+    TokenId,
+    TransactionId,
+};
+
+/// A transaction, as returned by the mirror node's `/transactions` endpoints.
+#[derive(Debug, Clone, serde_derive::Deserialize)]
+pub struct MirrorTransaction {
+    /// The ID of the transaction.
+    ///
+    /// The mirror node reports the nonce and scheduled flag separately, see [`nonce`](Self::nonce)
+    /// and [`scheduled`](Self::scheduled).
+    #[serde(deserialize_with = "de::transaction_id")]
+    pub transaction_id: TransactionId,
+
+    /// When the transaction reached consensus.
+    #[serde(deserialize_with = "de::timestamp")]
+    pub consensus_timestamp: OffsetDateTime,
+
+    /// The kind of transaction (ex. `CRYPTOTRANSFER`).
+    pub name: String,
+
+    /// The result of the transaction.
+    ///
+    /// Response codes that are newer than this SDK are reported as [`Status::Unknown`].
+    #[serde(deserialize_with = "de::status")]
+    pub result: Status,
+
+    /// The fee charged for the transaction.
+    #[serde(deserialize_with = "de::tinybars")]
+    pub charged_tx_fee: Hbar,
+
+    /// The transaction's memo, as a base64 encoded string.
+    #[serde(default)]
+    pub memo_base64: String,
+
+    /// The node the transaction was submitted to.
+    #[serde(default, deserialize_with = "de::option_from_str")]
+    pub node: Option<AccountId>,
+
+    /// Whether this is a scheduled transaction.
+    #[serde(default)]
+    pub scheduled: bool,
+
+    /// The nonce of the transaction ID, `0` for non-child transactions.
+    #[serde(default)]
+    pub nonce: i32,
+
+    /// The hbar transfers made by the transaction.
+    #[serde(default)]
+    pub transfers: Vec<MirrorTransfer>,
+
+    /// The fungible token transfers made by the transaction.
+    #[serde(default)]
+    pub token_transfers: Vec<MirrorTokenTransfer>,
+}
+
+/// An hbar transfer made by a transaction.
+#[derive(Debug, Clone, serde_derive::Deserialize)]
+pub struct MirrorTransfer {
+    /// The account the hbar was transferred to or from.
+    #[serde(deserialize_with = "de::from_str")]
+    pub account: AccountId,
+
+    /// The amount transferred, negative when `account` is sending.
+    #[serde(deserialize_with = "de::tinybars")]
+    pub amount: Hbar,
+
+    /// Whether the transfer was made with an allowance.
+    #[serde(default)]
+    pub is_approval: bool,
+}
+
+/// A fungible token transfer made by a transaction.
+#[derive(Debug, Clone, serde_derive::Deserialize)]
+pub struct MirrorTokenTransfer {
+    /// The token transferred.
+    #[serde(deserialize_with = "de::from_str")]
+    pub token_id: TokenId,
+
+    /// The account the token was transferred to or from.
+    #[serde(deserialize_with = "de::from_str")]
+    pub account: AccountId,
+
+    /// The amount transferred, in the token's smallest denomination, negative when `account` is sending.
+    pub amount: i64,
+
+    /// Whether the transfer was made with an allowance.
+    #[serde(default)]
+    pub is_approval: bool,
+}
+
+#[derive(serde_derive::Deserialize)]
+struct TransactionsResponse {
+    transactions: Vec<MirrorTransaction>,
+}
+
+impl MirrorRestClient {
+    /// Stream transactions, most recent first, optionally only those involving `account_id`.
+    pub fn transactions(
+        &self,
+        account_id: Option<AccountId>,
+    ) -> BoxStream<'static, crate::Result<MirrorTransaction>> {
+        let path = match account_id {
+            Some(account_id) => format!("/transactions?account.id={account_id}"),
+            None => "/transactions".to_owned(),
+        };
+
+        self.paginate(path, "transactions")
+    }
+
+    /// Fetch the transaction `transaction_id` along with any child transactions it created.
+    ///
+    /// # Errors
+    /// - [`Error::MirrorRest`](crate::Error::MirrorRest) if the request fails or the response can't be decoded.
+    pub async fn transaction(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> crate::Result<Vec<MirrorTransaction>> {
+        let response: TransactionsResponse =
+            self.get(&format!("/transactions/{}", transaction_id_path(transaction_id))).await?;
+
+        Ok(response.transactions)
+    }
+
+    /// Checks whether the mirror node knows about `transaction_id` yet.
+    pub(crate) async fn transaction_exists(
+        &self,
+        transaction_id: &TransactionId,
+    ) -> crate::Result<bool> {
+        let mut request = self.http.get(format!(
+            "{}/transactions/{}",
+            self.base_url,
+            transaction_id_path(transaction_id)
+        ));
+
+        if let Some(nonce) = transaction_id.nonce {
+            request = request.query(&[("nonce", nonce)]);
+        }
+
+        if transaction_id.scheduled {
+            request = request.query(&[("scheduled", true)]);
+        }
+
+        let response = request.send().await.map_err(Error::mirror_rest)?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+
+        response.error_for_status().map_err(Error::mirror_rest)?;
+
+        Ok(true)
+    }
+}
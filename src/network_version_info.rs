@@ -27,7 +27,7 @@ use crate::{
 };
 
 /// Versions of Hedera Services, and the protobuf schema.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct NetworkVersionInfo {
     /// Version of the protobuf schema in use by the network.
     pub protobuf_version: SemanticVersion,
@@ -0,0 +1,30 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+mod node_create_transaction;
+mod node_delete_transaction;
+mod node_update_transaction;
+
+pub use node_create_transaction::NodeCreateTransaction;
+pub(crate) use node_create_transaction::NodeCreateTransactionData;
+pub use node_delete_transaction::NodeDeleteTransaction;
+pub(crate) use node_delete_transaction::NodeDeleteTransactionData;
+pub use node_update_transaction::NodeUpdateTransaction;
+pub(crate) use node_update_transaction::NodeUpdateTransactionData;
@@ -0,0 +1,488 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use hedera_proto::services::address_book_service_client::AddressBookServiceClient;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToSchedulableTransactionDataProtobuf,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Error,
+    Key,
+    ServiceEndpoint,
+    Transaction,
+    ValidateChecksums,
+};
+
+/// Create a new consensus node in the network address book.
+///
+/// This transaction must be signed by the network's governing council.
+/// The node is added to network state, but is not part of network consensus
+/// until the next `FreezeTransaction` of type `PrepareUpgrade`.
+///
+/// On success, the resulting `TransactionReceipt` contains the `node_id` of the new node.
+pub type NodeCreateTransaction = Transaction<NodeCreateTransactionData>;
+
+#[derive(Debug, Clone, Default)]
+pub struct NodeCreateTransactionData {
+    account_id: Option<AccountId>,
+    description: String,
+    gossip_endpoints: Vec<ServiceEndpoint>,
+    service_endpoints: Vec<ServiceEndpoint>,
+    gossip_ca_certificate: Option<Vec<u8>>,
+    grpc_certificate_hash: Option<Vec<u8>>,
+    admin_key: Option<Key>,
+}
+
+impl NodeCreateTransaction {
+    /// Returns the account to be paid for queries and transactions sent to the node.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.data().account_id
+    }
+
+    /// Sets the account to be paid for queries and transactions sent to the node.
+    pub fn account_id(&mut self, id: AccountId) -> &mut Self {
+        self.data_mut().account_id = Some(id);
+        self
+    }
+
+    /// Returns the short description of the node.
+    #[must_use]
+    pub fn get_description(&self) -> &str {
+        &self.data().description
+    }
+
+    /// Sets a short description of the node, up to 100 bytes.
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.data_mut().description = description.into();
+        self
+    }
+
+    /// Returns the endpoints used for gossip between consensus nodes.
+    #[must_use]
+    pub fn get_gossip_endpoints(&self) -> &[ServiceEndpoint] {
+        &self.data().gossip_endpoints
+    }
+
+    /// Sets the endpoints used for gossip between consensus nodes.
+    ///
+    /// The first endpoint is the internal address and the second the external address.
+    pub fn gossip_endpoints(
+        &mut self,
+        endpoints: impl IntoIterator<Item = ServiceEndpoint>,
+    ) -> &mut Self {
+        self.data_mut().gossip_endpoints = endpoints.into_iter().collect();
+        self
+    }
+
+    /// Adds an endpoint used for gossip between consensus nodes.
+    pub fn add_gossip_endpoint(&mut self, endpoint: impl Into<ServiceEndpoint>) -> &mut Self {
+        self.data_mut().gossip_endpoints.push(endpoint.into());
+        self
+    }
+
+    /// Returns the endpoints at which the node serves gRPC requests.
+    #[must_use]
+    pub fn get_service_endpoints(&self) -> &[ServiceEndpoint] {
+        &self.data().service_endpoints
+    }
+
+    /// Sets the endpoints at which the node serves gRPC requests.
+    pub fn service_endpoints(
+        &mut self,
+        endpoints: impl IntoIterator<Item = ServiceEndpoint>,
+    ) -> &mut Self {
+        self.data_mut().service_endpoints = endpoints.into_iter().collect();
+        self
+    }
+
+    /// Adds an endpoint at which the node serves gRPC requests.
+    pub fn add_service_endpoint(&mut self, endpoint: impl Into<ServiceEndpoint>) -> &mut Self {
+        self.data_mut().service_endpoints.push(endpoint.into());
+        self
+    }
+
+    /// Returns the DER encoded certificate used to sign gossip events.
+    #[must_use]
+    pub fn get_gossip_ca_certificate(&self) -> Option<&[u8]> {
+        self.data().gossip_ca_certificate.as_deref()
+    }
+
+    /// Sets the DER encoded certificate used to sign gossip events.
+    pub fn gossip_ca_certificate(&mut self, certificate: impl Into<Vec<u8>>) -> &mut Self {
+        self.data_mut().gossip_ca_certificate = Some(certificate.into());
+        self
+    }
+
+    /// Returns the SHA-384 hash of the node's gRPC TLS certificate.
+    #[must_use]
+    pub fn get_grpc_certificate_hash(&self) -> Option<&[u8]> {
+        self.data().grpc_certificate_hash.as_deref()
+    }
+
+    /// Sets the SHA-384 hash of the node's gRPC TLS certificate.
+    pub fn grpc_certificate_hash(&mut self, hash: impl Into<Vec<u8>>) -> &mut Self {
+        self.data_mut().grpc_certificate_hash = Some(hash.into());
+        self
+    }
+
+    /// Returns the key required to update or delete the node.
+    #[must_use]
+    pub fn get_admin_key(&self) -> Option<&Key> {
+        self.data().admin_key.as_ref()
+    }
+
+    /// Sets the key required to update or delete the node.
+    pub fn admin_key(&mut self, key: impl Into<Key>) -> &mut Self {
+        self.data_mut().admin_key = Some(key.into());
+        self
+    }
+}
+
+impl TransactionData for NodeCreateTransactionData {}
+
+impl TransactionExecute for NodeCreateTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { AddressBookServiceClient::new(channel).create_node(request).await })
+    }
+}
+
+impl ValidateChecksums for NodeCreateTransactionData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.account_id.validate_checksums(ledger_id)
+    }
+}
+
+impl ToTransactionDataProtobuf for NodeCreateTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::NodeCreate(self.to_protobuf())
+    }
+}
+
+impl ToSchedulableTransactionDataProtobuf for NodeCreateTransactionData {
+    fn to_schedulable_transaction_data_protobuf(
+        &self,
+    ) -> services::schedulable_transaction_body::Data {
+        services::schedulable_transaction_body::Data::NodeCreate(self.to_protobuf())
+    }
+}
+
+impl From<NodeCreateTransactionData> for AnyTransactionData {
+    fn from(transaction: NodeCreateTransactionData) -> Self {
+        Self::NodeCreate(transaction)
+    }
+}
+
+impl FromProtobuf<services::NodeCreateTransactionBody> for NodeCreateTransactionData {
+    fn from_protobuf(pb: services::NodeCreateTransactionBody) -> crate::Result<Self> {
+        Ok(Self {
+            account_id: Option::from_protobuf(pb.account_id)?,
+            description: pb.description,
+            gossip_endpoints: Vec::from_protobuf(pb.gossip_endpoint)?,
+            service_endpoints: Vec::from_protobuf(pb.service_endpoint)?,
+            gossip_ca_certificate: Some(pb.gossip_ca_certificate).filter(|it| !it.is_empty()),
+            grpc_certificate_hash: Some(pb.grpc_certificate_hash).filter(|it| !it.is_empty()),
+            admin_key: Option::from_protobuf(pb.admin_key)?,
+        })
+    }
+}
+
+impl ToProtobuf for NodeCreateTransactionData {
+    type Protobuf = services::NodeCreateTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::NodeCreateTransactionBody {
+            account_id: self.account_id.to_protobuf(),
+            description: self.description.clone(),
+            gossip_endpoint: self.gossip_endpoints.to_protobuf(),
+            service_endpoint: self.service_endpoints.to_protobuf(),
+            gossip_ca_certificate: self.gossip_ca_certificate.clone().unwrap_or_default(),
+            grpc_certificate_hash: self.grpc_certificate_hash.clone().unwrap_or_default(),
+            admin_key: self.admin_key.to_protobuf(),
+            decline_reward: false,
+            grpc_proxy_endpoint: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{
+        Ipv4Addr,
+        SocketAddrV4,
+    };
+
+    use expect_test::expect;
+    use hedera_proto::services;
+
+    use super::NodeCreateTransactionData;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::transaction::test_helpers::{
+        check_body,
+        transaction_body,
+        unused_private_key,
+    };
+    use crate::{
+        AccountId,
+        AnyTransaction,
+        NodeCreateTransaction,
+        PublicKey,
+        ServiceEndpoint,
+    };
+
+    const ACCOUNT_ID: AccountId = AccountId::new(0, 0, 5007);
+    const DESCRIPTION: &str = "test description";
+    const GOSSIP_CA_CERTIFICATE: [u8; 4] = [0, 1, 2, 3];
+    const GRPC_CERTIFICATE_HASH: [u8; 4] = [4, 5, 6, 7];
+
+    fn key() -> PublicKey {
+        unused_private_key().public_key()
+    }
+
+    fn gossip_endpoint() -> ServiceEndpoint {
+        SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 50211).into()
+    }
+
+    fn service_endpoint() -> ServiceEndpoint {
+        ServiceEndpoint::domain_name("unit.test.com", 50212)
+    }
+
+    fn make_transaction() -> NodeCreateTransaction {
+        let mut tx = NodeCreateTransaction::new_for_tests();
+
+        tx.account_id(ACCOUNT_ID)
+            .description(DESCRIPTION)
+            .gossip_endpoints([gossip_endpoint()])
+            .service_endpoints([service_endpoint()])
+            .gossip_ca_certificate(GOSSIP_CA_CERTIFICATE)
+            .grpc_certificate_hash(GRPC_CERTIFICATE_HASH)
+            .admin_key(key())
+            .freeze()
+            .unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn serialize() {
+        let tx = make_transaction();
+
+        let tx = transaction_body(tx);
+
+        let tx = check_body(tx);
+
+        expect![[r#"
+            NodeCreate(
+                NodeCreateTransactionBody {
+                    account_id: Some(
+                        AccountId {
+                            shard_num: 0,
+                            realm_num: 0,
+                            account: Some(
+                                AccountNum(
+                                    5007,
+                                ),
+                            ),
+                        },
+                    ),
+                    description: "test description",
+                    gossip_endpoint: [
+                        ServiceEndpoint {
+                            ip_address_v4: [
+                                127,
+                                0,
+                                0,
+                                1,
+                            ],
+                            port: 50211,
+                            domain_name: "",
+                        },
+                    ],
+                    service_endpoint: [
+                        ServiceEndpoint {
+                            ip_address_v4: [],
+                            port: 50212,
+                            domain_name: "unit.test.com",
+                        },
+                    ],
+                    gossip_ca_certificate: [
+                        0,
+                        1,
+                        2,
+                        3,
+                    ],
+                    grpc_certificate_hash: [
+                        4,
+                        5,
+                        6,
+                        7,
+                    ],
+                    admin_key: Some(
+                        Key {
+                            key: Some(
+                                Ed25519(
+                                    [
+                                        224,
+                                        200,
+                                        236,
+                                        39,
+                                        88,
+                                        165,
+                                        135,
+                                        159,
+                                        250,
+                                        194,
+                                        38,
+                                        161,
+                                        60,
+                                        12,
+                                        81,
+                                        107,
+                                        121,
+                                        158,
+                                        114,
+                                        227,
+                                        81,
+                                        65,
+                                        160,
+                                        221,
+                                        130,
+                                        143,
+                                        148,
+                                        211,
+                                        121,
+                                        136,
+                                        164,
+                                        183,
+                                    ],
+                                ),
+                            ),
+                        },
+                    ),
+                    decline_reward: false,
+                    grpc_proxy_endpoint: None,
+                },
+            )
+        "#]]
+        .assert_debug_eq(&tx)
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_body(tx);
+
+        let tx2 = transaction_body(tx2);
+
+        assert_eq!(tx, tx2);
+    }
+
+    #[test]
+    fn from_proto_body() {
+        let tx = services::NodeCreateTransactionBody {
+            account_id: Some(ACCOUNT_ID.to_protobuf()),
+            description: DESCRIPTION.to_owned(),
+            gossip_endpoint: vec![gossip_endpoint().to_protobuf()],
+            service_endpoint: vec![service_endpoint().to_protobuf()],
+            gossip_ca_certificate: GOSSIP_CA_CERTIFICATE.to_vec(),
+            grpc_certificate_hash: GRPC_CERTIFICATE_HASH.to_vec(),
+            admin_key: Some(key().to_protobuf()),
+            decline_reward: false,
+            grpc_proxy_endpoint: None,
+        };
+
+        let tx = NodeCreateTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(tx.account_id, Some(ACCOUNT_ID));
+        assert_eq!(tx.description, DESCRIPTION);
+        assert_eq!(tx.gossip_endpoints, [gossip_endpoint()]);
+        assert_eq!(tx.service_endpoints, [service_endpoint()]);
+        assert_eq!(tx.gossip_ca_certificate.as_deref(), Some(GOSSIP_CA_CERTIFICATE.as_slice()));
+        assert_eq!(tx.grpc_certificate_hash.as_deref(), Some(GRPC_CERTIFICATE_HASH.as_slice()));
+        assert_eq!(tx.admin_key, Some(key().into()));
+    }
+
+    #[test]
+    fn get_set_account_id() {
+        let mut tx = NodeCreateTransaction::new();
+        tx.account_id(ACCOUNT_ID);
+
+        assert_eq!(tx.get_account_id(), Some(ACCOUNT_ID));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_account_id_frozen_panics() {
+        make_transaction().account_id(ACCOUNT_ID);
+    }
+
+    #[test]
+    fn get_set_endpoints() {
+        let mut tx = NodeCreateTransaction::new();
+        tx.add_gossip_endpoint(gossip_endpoint()).add_service_endpoint(service_endpoint());
+
+        assert_eq!(tx.get_gossip_endpoints(), [gossip_endpoint()]);
+        assert_eq!(tx.get_service_endpoints(), [service_endpoint()]);
+    }
+
+    #[test]
+    fn get_set_admin_key() {
+        let mut tx = NodeCreateTransaction::new();
+        tx.admin_key(key());
+
+        assert_eq!(tx.get_admin_key(), Some(&key().into()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_admin_key_frozen_panics() {
+        make_transaction().admin_key(key());
+    }
+}
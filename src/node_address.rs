@@ -18,32 +18,17 @@
  * ‍
  */
 
-use std::net::SocketAddrV4;
-
 use hedera_proto::services;
 
 use crate::protobuf::ToProtobuf;
+use crate::service_endpoint::parse_socket_addr_v4;
 use crate::{
     AccountId,
     Error,
     FromProtobuf,
+    ServiceEndpoint,
 };
 
-fn parse_socket_addr_v4(ip: Vec<u8>, port: i32) -> crate::Result<SocketAddrV4> {
-    let octets: Result<[u8; 4], _> = ip.try_into();
-    let octets = octets.map_err(|v| {
-        Error::from_protobuf(format!("expected 4 byte ip address, got `{}` bytes", v.len()))
-    })?;
-
-    let port = u16::try_from(port).map_err(|_| {
-        Error::from_protobuf(format!(
-            "expected 16 bit non-negative port number, but the port was actually `{port}`",
-        ))
-    })?;
-
-    Ok(SocketAddrV4::new(octets.into(), port))
-}
-
 /// The data about a node, including its service endpoints and the Hedera account to be paid for
 /// services provided by the node (that is, queries answered and transactions submitted.).
 #[derive(Debug, Clone)]
@@ -66,8 +51,8 @@ pub struct NodeAddress {
     /// Its value can be used to verify the node's certificate it presents during TLS negotiations.
     pub tls_certificate_hash: Vec<u8>,
 
-    /// A node's service IP addresses and ports.
-    pub service_endpoints: Vec<SocketAddrV4>,
+    /// A node's service IP addresses or domain names, and ports.
+    pub service_endpoints: Vec<ServiceEndpoint>,
 
     /// A description of the node, up to 100 bytes.
     pub description: String,
@@ -84,11 +69,11 @@ impl FromProtobuf<services::NodeAddress> for NodeAddress {
         // `ip_address`/`portno` are deprecated, but lets handle them anyway.
         #[allow(deprecated)]
         if !pb.ip_address.is_empty() {
-            addresses.push(parse_socket_addr_v4(pb.ip_address, pb.portno)?);
+            addresses.push(parse_socket_addr_v4(pb.ip_address, pb.portno)?.into());
         }
 
         for address in pb.service_endpoint {
-            addresses.push(parse_socket_addr_v4(address.ip_address_v4, address.port)?);
+            addresses.push(ServiceEndpoint::from_protobuf(address)?);
         }
 
         let node_account_id = AccountId::from_protobuf(pb_getf!(pb, node_account_id)?)?;
@@ -108,21 +93,12 @@ impl ToProtobuf for NodeAddress {
     type Protobuf = services::NodeAddress;
 
     fn to_protobuf(&self) -> Self::Protobuf {
-        let service_endpoint = self
-            .service_endpoints
-            .iter()
-            .map(|it| services::ServiceEndpoint {
-                ip_address_v4: it.ip().octets().to_vec(),
-                port: i32::from(it.port()),
-            })
-            .collect();
-
         services::NodeAddress {
             rsa_pub_key: hex::encode(&self.rsa_public_key),
             node_id: self.node_id as i64,
             node_account_id: Some(self.node_account_id.to_protobuf()),
             node_cert_hash: self.tls_certificate_hash.clone(),
-            service_endpoint,
+            service_endpoint: self.service_endpoints.to_protobuf(),
             description: self.description.clone(),
 
             // deprecated fields
@@ -34,11 +34,15 @@ use hedera_proto::{
 use mirror::network_service_client::NetworkServiceClient;
 use tonic::transport::Channel;
 use tonic::Response;
+use triomphe::Arc;
 
+use crate::client::MirrorNetworkData;
 use crate::mirror_query::{
     AnyMirrorQueryData,
     AnyMirrorQueryMessage,
+    MirrorQueryPolicy,
     MirrorRequest,
+    SubscriptionControl,
 };
 use crate::protobuf::FromProtobuf;
 use crate::{
@@ -143,14 +147,14 @@ impl MirrorRequest for NodeAddressBookQueryData {
         })
     }
 
-    fn make_item_stream<'a, S>(stream: S) -> Self::ItemStream<'a>
+    fn make_item_stream<'a, S>(&self, stream: S) -> Self::ItemStream<'a>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a,
     {
         Box::pin(Self::map_stream(stream))
     }
 
-    fn try_collect<'a, S>(stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
+    fn try_collect<'a, S>(&self, stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a,
     {
@@ -180,19 +184,23 @@ impl From<NodeAddressBook> for AnyMirrorQueryResponse {
 impl NodeAddressBookQuery {
     pub(crate) async fn execute_mirrornet(
         &self,
-        channel: Channel,
+        mirror: Arc<MirrorNetworkData>,
         timeout: Option<Duration>,
+        policy: MirrorQueryPolicy,
     ) -> crate::Result<NodeAddressBook> {
         let timeout = timeout.unwrap_or_else(|| {
             std::time::Duration::from_millis(backoff::default::MAX_ELAPSED_TIME_MILLIS)
         });
 
-        NodeAddressBookQueryData::try_collect(crate::mirror_query::subscribe(
-            channel,
-            timeout,
-            self.data.clone(),
-        ))
-        .await
+        self.data
+            .try_collect(crate::mirror_query::subscribe(
+                mirror,
+                timeout,
+                self.data.clone(),
+                policy,
+                SubscriptionControl::detached(),
+            ))
+            .await
     }
 }
 
@@ -27,6 +27,7 @@ use crate::account::{
     AccountInfoQueryData,
     AccountRecordsQueryData,
     AccountStakersQueryData,
+    LiveHashQueryData,
 };
 use crate::contract::{
     ContractBytecodeQueryData,
@@ -59,6 +60,7 @@ use crate::{
     FileInfo,
     FromProtobuf,
     Hbar,
+    LiveHash,
     NetworkVersionInfo,
     NetworkVersionInfoQueryData,
     Query,
@@ -92,11 +94,12 @@ pub enum AnyQueryData {
     TopicInfo(TopicInfoQueryData),
     ScheduleInfo(ScheduleInfoQueryData),
     NetworkVersionInfo(NetworkVersionInfoQueryData),
+    LiveHash(LiveHashQueryData),
 }
 
 // todo: strategically box fields of variants, rather than the entire structs.
 /// Common response type for *all* queries.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AnyQueryResponse {
     /// Response from [`AccountBalanceQuery`](crate::AccountBalanceQuery).
     AccountBalance(AccountBalance),
@@ -145,6 +148,9 @@ pub enum AnyQueryResponse {
 
     /// Response from [`NetworkVersionInfoQuery`](crate::NetworkVersionInfoQuery).
     NetworkVersionInfo(NetworkVersionInfo),
+
+    /// Response from [`LiveHashQuery`](crate::LiveHashQuery).
+    LiveHash(LiveHash),
 }
 
 impl ToQueryProtobuf for AnyQueryData {
@@ -166,6 +172,7 @@ impl ToQueryProtobuf for AnyQueryData {
             Self::TopicInfo(data) => data.to_query_protobuf(header),
             Self::ScheduleInfo(data) => data.to_query_protobuf(header),
             Self::NetworkVersionInfo(data) => data.to_query_protobuf(header),
+            Self::LiveHash(data) => data.to_query_protobuf(header),
         }
     }
 }
@@ -191,6 +198,7 @@ impl QueryExecute for AnyQueryData {
             Self::TopicInfo(query) => query.is_payment_required(),
             Self::ScheduleInfo(query) => query.is_payment_required(),
             Self::NetworkVersionInfo(query) => query.is_payment_required(),
+            Self::LiveHash(query) => query.is_payment_required(),
         }
     }
 
@@ -212,6 +220,7 @@ impl QueryExecute for AnyQueryData {
             Self::TopicInfo(query) => query.map_cost(cost),
             Self::ScheduleInfo(query) => query.map_cost(cost),
             Self::NetworkVersionInfo(query) => query.map_cost(cost),
+            Self::LiveHash(query) => query.map_cost(cost),
         }
     }
 
@@ -237,6 +246,7 @@ impl QueryExecute for AnyQueryData {
             Self::TopicInfo(query) => query.execute(channel, request),
             Self::ScheduleInfo(query) => query.execute(channel, request),
             Self::NetworkVersionInfo(query) => query.execute(channel, request),
+            Self::LiveHash(query) => query.execute(channel, request),
         }
     }
 
@@ -258,6 +268,7 @@ impl QueryExecute for AnyQueryData {
             Self::TopicInfo(query) => query.should_retry_pre_check(status),
             Self::ScheduleInfo(query) => query.should_retry_pre_check(status),
             Self::NetworkVersionInfo(query) => query.should_retry_pre_check(status),
+            Self::LiveHash(query) => query.should_retry_pre_check(status),
         }
     }
 
@@ -279,6 +290,7 @@ impl QueryExecute for AnyQueryData {
             Self::TopicInfo(query) => query.should_retry(response),
             Self::ScheduleInfo(query) => query.should_retry(response),
             Self::NetworkVersionInfo(query) => query.should_retry(response),
+            Self::LiveHash(query) => query.should_retry(response),
         }
     }
 
@@ -300,6 +312,7 @@ impl QueryExecute for AnyQueryData {
             Self::TopicInfo(query) => query.transaction_id(),
             Self::ScheduleInfo(query) => query.transaction_id(),
             Self::NetworkVersionInfo(query) => query.transaction_id(),
+            Self::LiveHash(query) => query.transaction_id(),
         }
     }
 
@@ -354,6 +367,7 @@ impl QueryExecute for AnyQueryData {
             Self::NetworkVersionInfo(query) => {
                 query.make_response(response).map(AnyQueryResponse::NetworkVersionInfo)
             }
+            Self::LiveHash(query) => query.make_response(response).map(AnyQueryResponse::LiveHash),
         }
     }
 }
@@ -377,6 +391,7 @@ impl ValidateChecksums for AnyQueryData {
             Self::TopicInfo(query) => query.validate_checksums(ledger_id),
             Self::ScheduleInfo(query) => query.validate_checksums(ledger_id),
             Self::NetworkVersionInfo(query) => query.validate_checksums(ledger_id),
+            Self::LiveHash(query) => query.validate_checksums(ledger_id),
         }
     }
 }
@@ -421,13 +436,13 @@ impl FromProtobuf<services::response::Response> for AnyQueryResponse {
                 Self::NetworkVersionInfo(NetworkVersionInfo::from_protobuf(response)?)
             }
             FileGetInfo(_) => Self::FileInfo(FileInfo::from_protobuf(response)?),
+            CryptoGetLiveHash(_) => Self::LiveHash(LiveHash::from_protobuf(response)?),
             TokenGetInfo(_) => Self::TokenInfo(Box::new(TokenInfo::from_protobuf(response)?)),
             TokenGetNftInfo(_) | TokenGetNftInfos(_) => {
                 Self::TokenNftInfo(TokenNftInfo::from_protobuf(response)?)
             }
             // Unimplemented on hedera services
             TransactionGetFastRecord(_)
-            | CryptoGetLiveHash(_)
             | GetBySolidityId(_)
             | TokenGetAccountNftInfos(_)
             | NetworkGetExecutionTime(_)
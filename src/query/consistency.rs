@@ -0,0 +1,338 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashSet;
+
+use futures_util::future::join_all;
+use hedera_proto::services;
+use rand::seq::SliceRandom;
+use rand::thread_rng;
+use tonic::transport::Channel;
+
+use crate::entity_id::ValidateChecksums;
+use crate::execute::{
+    execute,
+    Execute,
+};
+use crate::ledger_id::RefLedgerId;
+use crate::query::QueryExecute;
+use crate::{
+    AccountId,
+    AnyQueryResponse,
+    BoxGrpcFuture,
+    Client,
+    Error,
+    FromProtobuf,
+    Query,
+    Status,
+    TransactionId,
+};
+
+/// A [`Query`] pinned to a single node, returning the raw response so that it can be
+/// compared against the responses of other nodes before being decoded.
+struct NodeQuery<'a, D: QueryExecute> {
+    query: &'a Query<D>,
+    node_account_ids: [AccountId; 1],
+}
+
+impl<D: QueryExecute> ValidateChecksums for NodeQuery<'_, D> {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.query.validate_checksums(ledger_id)
+    }
+}
+
+impl<D: QueryExecute> Execute for NodeQuery<'_, D> {
+    type GrpcRequest = services::Query;
+
+    type GrpcResponse = services::Response;
+
+    type Context = ();
+
+    type Response = services::response::Response;
+
+    fn operator_account_id(&self) -> Option<&AccountId> {
+        self.query.operator_account_id()
+    }
+
+    fn node_account_ids(&self) -> Option<&[AccountId]> {
+        Some(&self.node_account_ids)
+    }
+
+    fn transaction_id(&self) -> Option<TransactionId> {
+        Execute::transaction_id(self.query)
+    }
+
+    fn requires_transaction_id(&self) -> bool {
+        self.query.requires_transaction_id()
+    }
+
+    fn should_retry_pre_check(&self, status: Status) -> bool {
+        Execute::should_retry_pre_check(self.query, status)
+    }
+
+    fn should_retry(&self, response: &Self::GrpcResponse) -> bool {
+        Execute::should_retry(self.query, response)
+    }
+
+    fn make_request(
+        &self,
+        transaction_id: Option<&TransactionId>,
+        node_account_id: AccountId,
+    ) -> crate::Result<(Self::GrpcRequest, Self::Context)> {
+        self.query.make_request(transaction_id, node_account_id)
+    }
+
+    fn execute(
+        &self,
+        channel: Channel,
+        request: Self::GrpcRequest,
+    ) -> BoxGrpcFuture<'_, Self::GrpcResponse> {
+        Execute::execute(self.query, channel, request)
+    }
+
+    fn make_response(
+        &self,
+        response: Self::GrpcResponse,
+        _context: Self::Context,
+        _node_account_id: AccountId,
+        _transaction_id: Option<&TransactionId>,
+    ) -> crate::Result<Self::Response> {
+        pb_getf!(response, response)
+    }
+
+    fn make_error_pre_check(
+        &self,
+        status: Status,
+        transaction_id: Option<&TransactionId>,
+    ) -> crate::Error {
+        self.query.make_error_pre_check(status, transaction_id)
+    }
+
+    fn response_pre_check_status(response: &Self::GrpcResponse) -> crate::Result<i32> {
+        Query::<D>::response_pre_check_status(response)
+    }
+}
+
+/// Picks `count` distinct nodes for a consistency check, preferring the explicitly
+/// configured nodes (if any) over the healthy nodes of the network.
+pub(super) fn select_node_account_ids(
+    explicit: Option<&[AccountId]>,
+    healthy: impl IntoIterator<Item = AccountId>,
+    count: usize,
+) -> crate::Result<Vec<AccountId>> {
+    let mut seen = HashSet::new();
+
+    let mut candidates: Vec<_> = match explicit {
+        Some(ids) => ids.iter().copied().filter(|id| seen.insert(*id)).collect(),
+        None => healthy.into_iter().filter(|id| seen.insert(*id)).collect(),
+    };
+
+    if candidates.len() < count {
+        return Err(Error::QueryConsistencyNodesUnavailable {
+            required: count,
+            available: candidates.len(),
+        });
+    }
+
+    candidates.shuffle(&mut thread_rng());
+    candidates.truncate(count);
+
+    Ok(candidates)
+}
+
+/// Sends `query` to every node in `node_account_ids` and returns the response they agree on.
+///
+/// Responses are compared after decoding, so node specific details (like the response header)
+/// don't count as a divergence.
+///
+/// The payment (if any) must already be frozen for those nodes.
+pub(super) async fn execute_consistent<D: QueryExecute>(
+    client: &Client,
+    query: &Query<D>,
+    node_account_ids: Vec<AccountId>,
+    timeout: Option<std::time::Duration>,
+) -> crate::Result<D::Response> {
+    let results = join_all(node_account_ids.into_iter().map(|node_account_id| {
+        let node_query = NodeQuery { query, node_account_ids: [node_account_id] };
+
+        async move { (node_account_id, execute(client, &node_query, timeout).await) }
+    }))
+    .await;
+
+    let mut first = None;
+    let mut responses = Vec::with_capacity(results.len());
+    let mut failures = Vec::new();
+
+    for (node_account_id, result) in results {
+        let decoded = result.and_then(|response| {
+            let decoded = AnyQueryResponse::from_protobuf(response.clone())?;
+            first.get_or_insert(response);
+            Ok(decoded)
+        });
+
+        match decoded {
+            Ok(response) => responses.push((node_account_id, response)),
+            Err(error) => failures.push((node_account_id, error)),
+        }
+    }
+
+    if !failures.is_empty() {
+        return Err(Error::QueryConsistencyNodesFailed { failures });
+    }
+
+    let agreed = responses
+        .split_first()
+        .is_some_and(|((_, first), rest)| rest.iter().all(|(_, response)| response == first));
+
+    if !agreed {
+        return Err(Error::QueryResponseDivergence { responses });
+    }
+
+    query.data.make_response(first.expect("consistency check requires nodes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hedera_proto::services;
+    use prost::Message;
+
+    use super::select_node_account_ids;
+    use crate::client::mock_node;
+    use crate::{
+        AccountBalanceQuery,
+        AccountId,
+        Error,
+        Hbar,
+        ToProtobuf,
+    };
+
+    /// Spawns a node that reports a balance of `tinybars`, with `cost` in the response header.
+    async fn balance_node(tinybars: u64, cost: u64) -> String {
+        mock_node::spawn(move |_, _| {
+            let response = services::response::Response::CryptogetAccountBalance(
+                services::CryptoGetAccountBalanceResponse {
+                    header: Some(services::ResponseHeader { cost, ..mock_node::ok_header() }),
+                    account_id: Some(AccountId::new(0, 0, 1001).to_protobuf()),
+                    balance: tinybars,
+                    ..Default::default()
+                },
+            );
+
+            Ok(services::Response { response: Some(response) }.encode_to_vec())
+        })
+        .await
+    }
+
+    async fn execute_balance(addresses: &[String]) -> crate::Result<crate::AccountBalance> {
+        let client = mock_node::client(addresses);
+
+        let node_account_ids = (3..).take(addresses.len()).map(|num| AccountId::new(0, 0, num));
+
+        AccountBalanceQuery::new()
+            .account_id(AccountId::new(0, 0, 1001))
+            .node_account_ids(node_account_ids)
+            .consistency_nodes(addresses.len())
+            .execute(&client)
+            .await
+    }
+
+    #[tokio::test]
+    async fn nodes_agree() {
+        // only the header differs, which isn't part of the decoded response.
+        let balance =
+            execute_balance(&[balance_node(10, 0).await, balance_node(10, 5).await]).await.unwrap();
+
+        assert_eq!(balance.hbars, Hbar::from_tinybars(10));
+    }
+
+    #[tokio::test]
+    async fn nodes_diverge() {
+        let err = execute_balance(&[balance_node(10, 0).await, balance_node(11, 0).await])
+            .await
+            .unwrap_err();
+
+        assert_matches!(err, Error::QueryResponseDivergence { responses } if responses.len() == 2);
+    }
+
+    #[tokio::test]
+    async fn node_fails() {
+        let failing = mock_node::spawn(|_, _| {
+            let header = services::ResponseHeader {
+                node_transaction_precheck_code: services::ResponseCodeEnum::InvalidAccountId as i32,
+                ..mock_node::ok_header()
+            };
+
+            let response = services::response::Response::CryptogetAccountBalance(
+                services::CryptoGetAccountBalanceResponse {
+                    header: Some(header),
+                    ..Default::default()
+                },
+            );
+
+            Ok(services::Response { response: Some(response) }.encode_to_vec())
+        })
+        .await;
+
+        let err = execute_balance(&[balance_node(10, 0).await, failing]).await.unwrap_err();
+
+        assert_matches!(
+            err,
+            Error::QueryConsistencyNodesFailed { failures }
+                if failures.len() == 1 && failures[0].0 == AccountId::new(0, 0, 4)
+        );
+    }
+
+    fn nodes() -> Vec<AccountId> {
+        (3..=8).map(AccountId::from).collect()
+    }
+
+    #[test]
+    fn select_from_healthy() {
+        let mut selected = select_node_account_ids(None, nodes(), 3).unwrap();
+
+        assert_eq!(selected.len(), 3);
+
+        selected.sort_by_key(|id| id.num);
+        selected.dedup();
+
+        assert_eq!(selected.len(), 3);
+        assert!(selected.iter().all(|id| nodes().contains(id)));
+    }
+
+    #[test]
+    fn select_prefers_explicit() {
+        let explicit = [AccountId::from(10), AccountId::from(11)];
+
+        let mut selected = select_node_account_ids(Some(&explicit), nodes(), 2).unwrap();
+        selected.sort_by_key(|id| id.num);
+
+        assert_eq!(selected, explicit);
+    }
+
+    #[test]
+    fn select_ignores_duplicates() {
+        let explicit = [AccountId::from(10), AccountId::from(10), AccountId::from(11)];
+
+        let err = select_node_account_ids(Some(&explicit), nodes(), 3).unwrap_err();
+
+        assert_matches!(err, Error::QueryConsistencyNodesUnavailable { required: 3, available: 2 });
+    }
+}
@@ -61,7 +61,8 @@ where
 
     type GrpcResponse = services::Response;
 
-    type Response = Hbar;
+    /// The cost, and the node that quoted it.
+    type Response = (Hbar, AccountId);
 
     type Context = ();
 
@@ -106,12 +107,12 @@ where
         &self,
         response: Self::GrpcResponse,
         _context: Self::Context,
-        _node_account_id: AccountId,
+        node_account_id: AccountId,
         _transaction_id: Option<&TransactionId>,
     ) -> crate::Result<Self::Response> {
         let cost = Hbar::from_tinybars(response_header(&response.response)?.cost as Tinybar);
 
-        Ok(self.0.data.map_cost(cost))
+        Ok((self.0.data.map_cost(cost), node_account_id))
     }
 
     fn make_error_pre_check(
@@ -152,6 +153,15 @@ where
         client: &Client,
         timeout: Option<std::time::Duration>,
     ) -> crate::Result<Hbar> {
+        self.execute_quote(client, timeout).await.map(|(cost, _)| cost)
+    }
+
+    /// Like [`execute`](Self::execute), but also returns the node that quoted the cost.
+    pub(crate) async fn execute_quote(
+        &mut self,
+        client: &Client,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<(Hbar, AccountId)> {
         execute(client, self, timeout).await
     }
 }
@@ -18,6 +18,8 @@
  * ‍
  */
 
+use std::time::Instant;
+
 use futures_core::future::BoxFuture;
 use time::Duration;
 
@@ -29,13 +31,16 @@ use crate::{
     Client,
     Error,
     Hbar,
+    Status,
     TransactionId,
     TransactionReceiptQuery,
 };
 
 mod any;
+mod consistency;
 mod cost;
 mod execute;
+mod payment_policy;
 pub(super) mod payment_transaction;
 mod protobuf;
 
@@ -48,6 +53,8 @@ pub(crate) use execute::{
     response_header,
     QueryExecute,
 };
+pub(crate) use payment_policy::QueryCostCache;
+pub use payment_policy::QueryPaymentPolicy;
 pub(crate) use protobuf::ToQueryProtobuf;
 
 /// A query that can be executed on the Hedera network.
@@ -58,6 +65,7 @@ where
 {
     pub(crate) data: D,
     pub(crate) payment: PaymentTransaction,
+    pub(crate) consistency_nodes: usize,
 }
 
 impl<D> Query<D>
@@ -195,6 +203,26 @@ where
         self
     }
 
+    /// Returns the number of distinct nodes this query will be sent to, for a consistency check.
+    #[must_use]
+    pub fn get_consistency_nodes(&self) -> usize {
+        self.consistency_nodes
+    }
+
+    /// Sets the number of distinct nodes this query will be sent to, for a consistency check.
+    ///
+    /// When greater than `1`, the query is sent to that many nodes (chosen from the explicit
+    /// node account IDs if set, otherwise from the healthy nodes of the client's network),
+    /// paying each of them. The response is only returned if every node agrees; otherwise a
+    /// [`QueryResponseDivergence`](crate::Error::QueryResponseDivergence) error lists
+    /// each node's response.
+    ///
+    /// Defaults to `0`, which (like `1`) sends the query to a single node.
+    pub fn consistency_nodes(&mut self, count: usize) -> &mut Self {
+        self.consistency_nodes = count;
+        self
+    }
+
     /// Fetch the cost of this query.
     pub async fn get_cost(&self, client: &Client) -> crate::Result<Hbar> {
         self.get_cost_with_optional_timeout(client, None).await
@@ -260,26 +288,140 @@ where
             }
         }
 
+        let policy = client.query_payment_policy();
+
+        let mut unfrozen_payment = None;
+
         if self.payment.get_amount().is_none() && self.data.is_payment_required() {
-            // should this inherit the timeout?
-            // payment is required but none was specified, query the cost
-            let cost = QueryCost::new(self).execute(client, None).await?;
+            self.pay_quoted_cost(client, policy.as_ref(), false).await?;
 
-            if self.payment.get_max_amount().is_none() {
-                // N.B. This can still be `None`.
-                self.payment.max_amount(client.default_max_query_payment());
+            // keep an unfrozen copy around so that the payment can be redone with a fresh quote.
+            if policy.as_ref().is_some_and(QueryPaymentPolicy::get_refresh_on_insufficient_fee) {
+                unfrozen_payment = Some(self.payment.clone());
             }
+        }
+
+        let result = self.execute_paid(client, timeout).await;
+
+        match (result, unfrozen_payment) {
+            (
+                Err(
+                    Error::QueryPaymentPreCheckStatus { status: Status::InsufficientTxFee, .. }
+                    | Error::QueryPreCheckStatus { status: Status::InsufficientTxFee, .. },
+                ),
+                Some(unfrozen_payment),
+            ) => {
+                // our quote was stale, get a new one and try again (once).
+                self.payment = unfrozen_payment;
+                self.payment.clear_amount();
+                self.pay_quoted_cost(client, policy.as_ref(), true).await?;
+
+                self.execute_paid(client, timeout).await
+            }
+            (result, _) => result,
+        }
+    }
 
-            if let Some(max_amount) = self.payment.get_max_amount() {
-                if cost > max_amount {
-                    return Err(Error::MaxQueryPaymentExceeded {
-                        query_cost: cost,
-                        max_query_payment: max_amount,
-                    });
+    /// Sets the payment amount from the cost of this query, asking a node for it unless `policy`
+    /// has a usable quote cached (and `refresh` is `false`).
+    async fn pay_quoted_cost(
+        &mut self,
+        client: &Client,
+        policy: Option<&QueryPaymentPolicy>,
+        refresh: bool,
+    ) -> crate::Result<()> {
+        let kind = policy.and_then(|_| payment_policy::query_kind(&self.data));
+        let cache = client.query_cost_cache();
+
+        let cached = match (policy, kind) {
+            (Some(_), Some(kind)) if refresh => {
+                cache.invalidate(kind);
+                None
+            }
+            (Some(policy), Some(kind)) => cache.get(
+                kind,
+                self.payment.get_node_account_ids(),
+                policy.get_cost_ttl(),
+                Instant::now(),
+            ),
+            _ => None,
+        };
+
+        let cost = match cached {
+            Some(cost) => cost,
+            None => {
+                // should this inherit the timeout?
+                // payment is required but none was specified, query the cost
+                let (cost, node_account_id) =
+                    QueryCost::new(self).execute_quote(client, None).await?;
+
+                if let Some(kind) = kind {
+                    cache.insert(kind, node_account_id, cost, Instant::now());
                 }
+
+                cost
+            }
+        };
+
+        if self.payment.get_max_amount().is_none() {
+            // N.B. This can still be `None`.
+            self.payment.max_amount(client.default_max_query_payment());
+        }
+
+        let max_amount = self.payment.get_max_amount();
+
+        if let Some(max_amount) = max_amount {
+            if cost > max_amount {
+                return Err(Error::MaxQueryPaymentExceeded {
+                    query_cost: cost,
+                    max_query_payment: max_amount,
+                });
+            }
+        }
+
+        let amount = match policy {
+            Some(policy) => {
+                let amount = policy.apply_headroom(cost);
+                max_amount.map_or(amount, |max_amount| amount.min(max_amount))
+            }
+            None => cost,
+        };
+
+        self.payment.amount(amount);
+
+        Ok(())
+    }
+
+    /// Freezes the payment (if needed) and executes this query.
+    async fn execute_paid(
+        &mut self,
+        client: &Client,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<D::Response> {
+        if self.consistency_nodes > 1 {
+            let node_account_ids = consistency::select_node_account_ids(
+                self.payment.get_node_account_ids(),
+                client.net().0.load().healthy_node_ids(),
+                self.consistency_nodes,
+            )?;
+
+            // pin a copy rather than `self`, so that later executions aren't stuck with these nodes.
+            let mut pinned = Self {
+                data: self.data.clone(),
+                payment: self.payment.clone(),
+                consistency_nodes: self.consistency_nodes,
+            };
+
+            if pinned.payment.get_node_account_ids().is_none() {
+                pinned.payment.node_account_ids(node_account_ids.iter().copied());
+            }
+
+            if pinned.data.is_payment_required() {
+                pinned.payment.freeze_with(client)?;
             }
 
-            self.payment.amount(cost);
+            return consistency::execute_consistent(client, &pinned, node_account_ids, timeout)
+                .await;
         }
 
         if self.data.is_payment_required() {
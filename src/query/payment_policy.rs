@@ -0,0 +1,245 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+use std::mem::Discriminant;
+use std::time::{
+    Duration,
+    Instant,
+};
+
+use hedera_proto::services;
+use parking_lot::Mutex;
+
+use crate::query::QueryExecute;
+use crate::{
+    AccountId,
+    Hbar,
+};
+
+/// How a [`Client`](crate::Client) pays for queries that have no explicit payment amount.
+///
+/// By default, every paid query asks a node for its cost before being executed. With a policy
+/// configured, cost quotes are cached per query type and node for [`cost_ttl`](Self::cost_ttl),
+/// and a percentage of [`headroom`](Self::headroom_percent) is added to each payment.
+#[derive(Debug, Clone)]
+pub struct QueryPaymentPolicy {
+    cost_ttl: Duration,
+    headroom_percent: u32,
+    refresh_on_insufficient_fee: bool,
+}
+
+impl Default for QueryPaymentPolicy {
+    fn default() -> Self {
+        Self {
+            cost_ttl: Duration::from_secs(60),
+            headroom_percent: 10,
+            refresh_on_insufficient_fee: true,
+        }
+    }
+}
+
+impl QueryPaymentPolicy {
+    /// Create a new query payment policy with the default values.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns how long a cost quote is reused for.
+    #[must_use]
+    pub fn get_cost_ttl(&self) -> Duration {
+        self.cost_ttl
+    }
+
+    /// Sets how long a cost quote is reused for.
+    ///
+    /// A zero duration disables caching. Defaults to 60 seconds.
+    pub fn cost_ttl(&mut self, ttl: Duration) -> &mut Self {
+        self.cost_ttl = ttl;
+        self
+    }
+
+    /// Returns the percentage added on top of the quoted cost of a query.
+    #[must_use]
+    pub fn get_headroom_percent(&self) -> u32 {
+        self.headroom_percent
+    }
+
+    /// Sets the percentage added on top of the quoted cost of a query.
+    ///
+    /// The payment never exceeds the query's maximum payment amount.
+    /// Defaults to 10 percent.
+    pub fn headroom_percent(&mut self, percent: u32) -> &mut Self {
+        self.headroom_percent = percent;
+        self
+    }
+
+    /// Returns whether a query that fails with `INSUFFICIENT_TX_FEE` is retried with a fresh quote.
+    #[must_use]
+    pub fn get_refresh_on_insufficient_fee(&self) -> bool {
+        self.refresh_on_insufficient_fee
+    }
+
+    /// Sets whether a query that fails with `INSUFFICIENT_TX_FEE` is retried (once) with a fresh quote.
+    ///
+    /// Defaults to `true`.
+    pub fn refresh_on_insufficient_fee(&mut self, refresh: bool) -> &mut Self {
+        self.refresh_on_insufficient_fee = refresh;
+        self
+    }
+
+    /// Returns `cost` plus this policy's headroom, rounded up to the nearest tinybar.
+    pub(crate) fn apply_headroom(&self, cost: Hbar) -> Hbar {
+        let tinybars = i128::from(cost.to_tinybars());
+        let percent = 100 + i128::from(self.headroom_percent);
+
+        let tinybars = (tinybars * percent + 99) / 100;
+
+        Hbar::from_tinybars(i64::try_from(tinybars).unwrap_or(i64::MAX))
+    }
+}
+
+/// The type of a query, as far as its cost is concerned.
+pub(crate) type QueryKind = Discriminant<services::query::Query>;
+
+pub(crate) fn query_kind<D: QueryExecute>(data: &D) -> Option<QueryKind> {
+    let header = services::QueryHeader { response_type: 0, payment: None };
+
+    data.to_query_protobuf(header).query.as_ref().map(std::mem::discriminant)
+}
+
+/// Cost quotes, keyed by query type and the node that gave them.
+#[derive(Default)]
+pub(crate) struct QueryCostCache(Mutex<HashMap<(QueryKind, AccountId), (Hbar, Instant)>>);
+
+impl QueryCostCache {
+    /// Returns the highest quote for `kind` from the given nodes (or any node) that is younger than `ttl`.
+    pub(crate) fn get(
+        &self,
+        kind: QueryKind,
+        node_account_ids: Option<&[AccountId]>,
+        ttl: Duration,
+        now: Instant,
+    ) -> Option<Hbar> {
+        let quotes = self.0.lock();
+
+        quotes
+            .iter()
+            .filter(|((it, node_account_id), _)| {
+                *it == kind && node_account_ids.is_none_or(|ids| ids.contains(node_account_id))
+            })
+            .filter(|(_, (_, quoted_at))| now.saturating_duration_since(*quoted_at) < ttl)
+            .map(|(_, (cost, _))| *cost)
+            .max()
+    }
+
+    pub(crate) fn insert(
+        &self,
+        kind: QueryKind,
+        node_account_id: AccountId,
+        cost: Hbar,
+        now: Instant,
+    ) {
+        self.0.lock().insert((kind, node_account_id), (cost, now));
+    }
+
+    /// Forgets every quote for `kind`.
+    pub(crate) fn invalidate(&self, kind: QueryKind) {
+        self.0.lock().retain(|(it, _), _| *it != kind);
+    }
+
+    pub(crate) fn clear(&self) {
+        self.0.lock().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{
+        Duration,
+        Instant,
+    };
+
+    use super::{
+        query_kind,
+        QueryCostCache,
+        QueryPaymentPolicy,
+    };
+    use crate::{
+        AccountBalanceQuery,
+        AccountId,
+        AccountInfoQuery,
+        Hbar,
+        TopicInfoQuery,
+    };
+
+    #[test]
+    fn apply_headroom() {
+        let mut policy = QueryPaymentPolicy::new();
+
+        assert_eq!(policy.apply_headroom(Hbar::from_tinybars(1000)), Hbar::from_tinybars(1100));
+        assert_eq!(policy.apply_headroom(Hbar::from_tinybars(1)), Hbar::from_tinybars(2));
+
+        policy.headroom_percent(0);
+
+        assert_eq!(policy.apply_headroom(Hbar::from_tinybars(1000)), Hbar::from_tinybars(1000));
+    }
+
+    #[test]
+    fn query_kinds() {
+        let info = query_kind(&AccountInfoQuery::new().data).unwrap();
+        let info2 = query_kind(&AccountInfoQuery::new().account_id(AccountId::from(5)).data);
+        let topic = query_kind(&TopicInfoQuery::new().data).unwrap();
+        let balance = query_kind(&AccountBalanceQuery::new().data).unwrap();
+
+        assert_eq!(Some(info), info2);
+        assert_ne!(info, topic);
+        assert_ne!(info, balance);
+    }
+
+    #[test]
+    fn cache_get_insert() {
+        let cache = QueryCostCache::default();
+        let kind = query_kind(&AccountInfoQuery::new().data).unwrap();
+        let other_kind = query_kind(&TopicInfoQuery::new().data).unwrap();
+        let ttl = Duration::from_secs(60);
+        let now = Instant::now();
+
+        cache.insert(kind, AccountId::from(3), Hbar::from_tinybars(10), now);
+        cache.insert(kind, AccountId::from(4), Hbar::from_tinybars(20), now);
+
+        assert_eq!(cache.get(kind, None, ttl, now), Some(Hbar::from_tinybars(20)));
+        assert_eq!(
+            cache.get(kind, Some(&[AccountId::from(3)]), ttl, now),
+            Some(Hbar::from_tinybars(10))
+        );
+        assert_eq!(cache.get(kind, Some(&[AccountId::from(5)]), ttl, now), None);
+        assert_eq!(cache.get(other_kind, None, ttl, now), None);
+
+        // expired
+        assert_eq!(cache.get(kind, None, ttl, now + ttl), None);
+        assert_eq!(cache.get(kind, None, Duration::ZERO, now), None);
+
+        cache.invalidate(kind);
+
+        assert_eq!(cache.get(kind, None, ttl, now), None);
+    }
+}
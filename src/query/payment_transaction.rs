@@ -56,6 +56,11 @@ impl PaymentTransaction {
         self
     }
 
+    pub(super) fn clear_amount(&mut self) -> &mut Self {
+        self.data_mut().amount = None;
+        self
+    }
+
     pub(super) fn get_max_amount(&self) -> Option<Hbar> {
         self.data().max_amount
     }
@@ -28,6 +28,11 @@ mod data {
         FileDeleteTransactionData as FileDelete,
         FileUpdateTransactionData as FileUpdate,
     };
+    pub(super) use crate::node::{
+        NodeCreateTransactionData as NodeCreate,
+        NodeDeleteTransactionData as NodeDelete,
+        NodeUpdateTransactionData as NodeUpdate,
+    };
     pub(super) use crate::prng_transaction::PrngTransactionData as Prng;
     pub(super) use crate::schedule::ScheduleDeleteTransactionData as ScheduleDelete;
     pub(super) use crate::system::{
@@ -36,8 +41,11 @@ mod data {
         SystemUndeleteTransactionData as SystemUndelete,
     };
     pub(super) use crate::token::{
+        TokenAirdropTransactionData as TokenAirdrop,
         TokenAssociateTransactionData as TokenAssociate,
         TokenBurnTransactionData as TokenBurn,
+        TokenCancelAirdropTransactionData as TokenCancelAirdrop,
+        TokenClaimAirdropTransactionData as TokenClaimAirdrop,
         TokenCreateTransactionData as TokenCreate,
         TokenDeleteTransactionData as TokenDelete,
         TokenDissociateTransactionData as TokenDissociate,
@@ -46,6 +54,7 @@ mod data {
         TokenGrantKycTransactionData as TokenGrantKyc,
         TokenMintTransactionData as TokenMint,
         TokenPauseTransactionData as TokenPause,
+        TokenRejectTransactionData as TokenReject,
         TokenRevokeKycTransactionData as TokenRevokeKyc,
         TokenUnfreezeTransactionData as TokenUnfreeze,
         TokenUnpauseTransactionData as TokenUnpause,
@@ -83,6 +92,13 @@ impl FromProtobuf<services::SchedulableTransactionBody> for SchedulableTransacti
     }
 }
 
+// The transaction data types don't implement `PartialEq`, so compare what would be sent instead.
+impl PartialEq for SchedulableTransactionBody {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_scheduled_body_protobuf() == other.to_scheduled_body_protobuf()
+    }
+}
+
 impl SchedulableTransactionBody {
     pub(super) fn to_scheduled_body_protobuf(&self) -> services::SchedulableTransactionBody {
         services::SchedulableTransactionBody {
@@ -138,6 +154,13 @@ pub(super) enum AnySchedulableTransactionData {
     Freeze(data::Freeze),
     ScheduleDelete(data::ScheduleDelete),
     TokenUpdateNfts(data::TokenUpdateNfts),
+    TokenAirdrop(data::TokenAirdrop),
+    TokenClaimAirdrop(data::TokenClaimAirdrop),
+    TokenCancelAirdrop(data::TokenCancelAirdrop),
+    TokenReject(data::TokenReject),
+    NodeCreate(data::NodeCreate),
+    NodeUpdate(data::NodeUpdate),
+    NodeDelete(data::NodeDelete),
 }
 
 impl AnySchedulableTransactionData {
@@ -179,6 +202,17 @@ impl AnySchedulableTransactionData {
             AnySchedulableTransactionData::TokenGrantKyc(it) => it.default_max_transaction_fee(),
             AnySchedulableTransactionData::TokenMint(it) => it.default_max_transaction_fee(),
             AnySchedulableTransactionData::TokenUpdateNfts(it) => it.default_max_transaction_fee(),
+            AnySchedulableTransactionData::TokenAirdrop(it) => it.default_max_transaction_fee(),
+            AnySchedulableTransactionData::TokenClaimAirdrop(it) => {
+                it.default_max_transaction_fee()
+            }
+            AnySchedulableTransactionData::TokenCancelAirdrop(it) => {
+                it.default_max_transaction_fee()
+            }
+            AnySchedulableTransactionData::TokenReject(it) => it.default_max_transaction_fee(),
+            AnySchedulableTransactionData::NodeCreate(it) => it.default_max_transaction_fee(),
+            AnySchedulableTransactionData::NodeUpdate(it) => it.default_max_transaction_fee(),
+            AnySchedulableTransactionData::NodeDelete(it) => it.default_max_transaction_fee(),
             AnySchedulableTransactionData::TokenPause(it) => it.default_max_transaction_fee(),
             AnySchedulableTransactionData::TokenRevokeKyc(it) => it.default_max_transaction_fee(),
             AnySchedulableTransactionData::TokenUnfreeze(it) => it.default_max_transaction_fee(),
@@ -287,6 +321,19 @@ impl FromProtobuf<services::schedulable_transaction_body::Data> for AnySchedulab
             Data::TokenUpdateNfts(it) => {
                 Ok(Self::TokenUpdateNfts(data::TokenUpdateNfts::from_protobuf(it)?))
             }
+            Data::TokenAirdrop(it) => {
+                Ok(Self::TokenAirdrop(data::TokenAirdrop::from_protobuf(it)?))
+            }
+            Data::TokenClaimAirdrop(it) => {
+                Ok(Self::TokenClaimAirdrop(data::TokenClaimAirdrop::from_protobuf(it)?))
+            }
+            Data::TokenCancelAirdrop(it) => {
+                Ok(Self::TokenCancelAirdrop(data::TokenCancelAirdrop::from_protobuf(it)?))
+            }
+            Data::TokenReject(it) => Ok(Self::TokenReject(data::TokenReject::from_protobuf(it)?)),
+            Data::NodeCreate(it) => Ok(Self::NodeCreate(data::NodeCreate::from_protobuf(it)?)),
+            Data::NodeUpdate(it) => Ok(Self::NodeUpdate(data::NodeUpdate::from_protobuf(it)?)),
+            Data::NodeDelete(it) => Ok(Self::NodeDelete(data::NodeDelete::from_protobuf(it)?)),
         }
     }
 }
@@ -414,6 +461,27 @@ impl ToSchedulableTransactionDataProtobuf for AnySchedulableTransactionData {
             AnySchedulableTransactionData::TokenUpdateNfts(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            AnySchedulableTransactionData::TokenAirdrop(it) => {
+                it.to_schedulable_transaction_data_protobuf()
+            }
+            AnySchedulableTransactionData::TokenClaimAirdrop(it) => {
+                it.to_schedulable_transaction_data_protobuf()
+            }
+            AnySchedulableTransactionData::TokenCancelAirdrop(it) => {
+                it.to_schedulable_transaction_data_protobuf()
+            }
+            AnySchedulableTransactionData::TokenReject(it) => {
+                it.to_schedulable_transaction_data_protobuf()
+            }
+            AnySchedulableTransactionData::NodeCreate(it) => {
+                it.to_schedulable_transaction_data_protobuf()
+            }
+            AnySchedulableTransactionData::NodeUpdate(it) => {
+                it.to_schedulable_transaction_data_protobuf()
+            }
+            AnySchedulableTransactionData::NodeDelete(it) => {
+                it.to_schedulable_transaction_data_protobuf()
+            }
         }
     }
 }
@@ -464,6 +532,13 @@ impl TryFrom<AnyTransactionData> for AnySchedulableTransactionData {
             AnyTransactionData::ScheduleDelete(it) => Ok(Self::ScheduleDelete(it)),
             AnyTransactionData::Prng(it) => Ok(Self::Prng(it)),
             AnyTransactionData::TokenUpdateNfts(it) => Ok(Self::TokenUpdateNfts(it)),
+            AnyTransactionData::TokenAirdrop(it) => Ok(Self::TokenAirdrop(it)),
+            AnyTransactionData::TokenClaimAirdrop(it) => Ok(Self::TokenClaimAirdrop(it)),
+            AnyTransactionData::TokenCancelAirdrop(it) => Ok(Self::TokenCancelAirdrop(it)),
+            AnyTransactionData::TokenReject(it) => Ok(Self::TokenReject(it)),
+            AnyTransactionData::NodeCreate(it) => Ok(Self::NodeCreate(it)),
+            AnyTransactionData::NodeUpdate(it) => Ok(Self::NodeUpdate(it)),
+            AnyTransactionData::NodeDelete(it) => Ok(Self::NodeDelete(it)),
 
             // fixme: basic-parse isn't suitable for this.
             AnyTransactionData::ScheduleCreate(_) => {
@@ -475,6 +550,15 @@ impl TryFrom<AnyTransactionData> for AnySchedulableTransactionData {
             AnyTransactionData::Ethereum(_) => {
                 Err(crate::Error::basic_parse("Cannot schedule `EthereumTransaction`"))
             }
+            AnyTransactionData::LiveHashAdd(_) => {
+                Err(crate::Error::basic_parse("Cannot schedule `LiveHashAddTransaction`"))
+            }
+            AnyTransactionData::LiveHashDelete(_) => {
+                Err(crate::Error::basic_parse("Cannot schedule `LiveHashDeleteTransaction`"))
+            }
+            AnyTransactionData::Batch(_) => {
+                Err(crate::Error::basic_parse("Cannot schedule `BatchTransaction`"))
+            }
         }
     }
 }
@@ -527,6 +611,13 @@ impl From<AnySchedulableTransactionData> for AnyTransactionData {
             AnySchedulableTransactionData::ScheduleDelete(it) => Self::ScheduleDelete(it),
             AnySchedulableTransactionData::Prng(it) => Self::Prng(it),
             AnySchedulableTransactionData::TokenUpdateNfts(it) => Self::TokenUpdateNfts(it),
+            AnySchedulableTransactionData::TokenAirdrop(it) => Self::TokenAirdrop(it),
+            AnySchedulableTransactionData::TokenClaimAirdrop(it) => Self::TokenClaimAirdrop(it),
+            AnySchedulableTransactionData::TokenCancelAirdrop(it) => Self::TokenCancelAirdrop(it),
+            AnySchedulableTransactionData::TokenReject(it) => Self::TokenReject(it),
+            AnySchedulableTransactionData::NodeCreate(it) => Self::NodeCreate(it),
+            AnySchedulableTransactionData::NodeUpdate(it) => Self::NodeUpdate(it),
+            AnySchedulableTransactionData::NodeDelete(it) => Self::NodeDelete(it),
         }
     }
 }
@@ -33,11 +33,13 @@ use crate::transaction::{
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
+    TransactionBody,
     TransactionData,
     TransactionExecute,
 };
 use crate::{
     AccountId,
+    AnyTransaction,
     BoxGrpcFuture,
     Error,
     Key,
@@ -71,10 +73,34 @@ pub struct ScheduleCreateTransactionData {
 }
 
 impl ScheduleCreateTransaction {
-    // note(sr): not sure what the right way to go about this is?
-    // pub fn get_scheduled_transaction(&self) -> Option<&SchedulableTransactionBody> {
-    //     self.data().scheduled_transaction.as_ref()
-    // }
+    /// Returns the scheduled transaction.
+    ///
+    /// The returned transaction is not frozen and has no transaction ID or node account IDs,
+    /// only the fields that are actually part of the schedule (the data, memo, and max transaction fee) are set.
+    ///
+    /// This is *not* guaranteed to be a constant time operation.
+    #[must_use]
+    pub fn get_scheduled_transaction(&self) -> Option<AnyTransaction> {
+        let scheduled = self.data().scheduled_transaction.as_ref()?;
+
+        Some(Transaction::from_parts(
+            TransactionBody {
+                data: (*scheduled.data).clone().into(),
+                node_account_ids: None,
+                transaction_valid_duration: None,
+                max_transaction_fee: scheduled.max_transaction_fee,
+                transaction_memo: scheduled.transaction_memo.clone(),
+                transaction_id: None,
+                batch_key: None,
+                custom_fee_limits: Vec::new(),
+                operator: None,
+                is_frozen: false,
+                regenerate_transaction_id: None,
+                idempotent_resubmission: false,
+            },
+            Vec::new(),
+        ))
+    }
 
     /// Sets the scheduled transaction.
     ///
@@ -436,6 +462,39 @@ mod tests {
         assert_eq!(tx, tx2);
     }
 
+    #[test]
+    fn get_scheduled_transaction() {
+        let tx = make_transaction();
+
+        let scheduled = tx.get_scheduled_transaction().unwrap();
+        let scheduled: TransferTransaction = scheduled.downcast().unwrap();
+
+        assert_eq!(scheduled.get_hbar_transfers(), scheduled_transaction().get_hbar_transfers());
+        assert_eq!(scheduled.get_transaction_memo(), "");
+        assert_eq!(scheduled.get_transaction_id(), None);
+    }
+
+    #[test]
+    fn get_scheduled_transaction_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+        let tx2: ScheduleCreateTransaction = tx2.downcast().unwrap();
+
+        let scheduled = tx2.get_scheduled_transaction().unwrap();
+        let scheduled: TransferTransaction = scheduled.downcast().unwrap();
+
+        assert_eq!(scheduled.get_hbar_transfers(), scheduled_transaction().get_hbar_transfers());
+        assert_eq!(scheduled.get_max_transaction_fee(), Some(Hbar::new(2)));
+    }
+
+    #[test]
+    fn get_scheduled_transaction_unset() {
+        let tx = ScheduleCreateTransaction::new();
+
+        assert!(tx.get_scheduled_transaction().is_none());
+    }
+
     #[test]
     fn from_proto_body() {
         let tx = services::ScheduleCreateTransactionBody {
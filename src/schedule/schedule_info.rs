@@ -38,7 +38,7 @@ use crate::{
 
 // TODO: scheduled_transaction
 /// Response from [`ScheduleInfoQuery`][crate::ScheduleInfoQuery].
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct ScheduleInfo {
     /// The ID of the schedule for which information is requested.
     pub schedule_id: ScheduleId,
@@ -105,9 +105,12 @@ impl ScheduleInfo {
                 max_transaction_fee: None,
                 transaction_memo: self.scheduled_transaction.transaction_memo.clone(),
                 transaction_id: Some(self.scheduled_transaction_id),
+                batch_key: None,
+                custom_fee_limits: Vec::new(),
                 operator: None,
                 is_frozen: true,
                 regenerate_transaction_id: Some(false),
+                idempotent_resubmission: false,
             },
             Vec::new(),
         ))
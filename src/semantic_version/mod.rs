@@ -34,7 +34,7 @@ mod tests;
 
 /// Hedera follows [semantic versioning](https://semver.org) for both the HAPI protobufs and
 /// the Services software.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SemanticVersion {
     /// Increases with incompatible API changes
     pub major: u32,
@@ -0,0 +1,196 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+use std::net::SocketAddrV4;
+
+use hedera_proto::services;
+
+use crate::protobuf::ToProtobuf;
+use crate::{
+    Error,
+    FromProtobuf,
+};
+
+/// An address at which a node provides a service, either as an IPv4 socket address or as a
+/// fully qualified domain name and port.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ServiceEndpoint {
+    /// An IPv4 address and port.
+    Ip(SocketAddrV4),
+
+    /// A fully qualified domain name and port.
+    DomainName {
+        /// The fully qualified domain name, up to 253 characters.
+        domain_name: String,
+
+        /// The port number.
+        port: u16,
+    },
+}
+
+impl ServiceEndpoint {
+    /// Create a new endpoint from a fully qualified domain name and port.
+    #[must_use]
+    pub fn domain_name(domain_name: impl Into<String>, port: u16) -> Self {
+        Self::DomainName { domain_name: domain_name.into(), port }
+    }
+
+    /// Returns the port of this endpoint.
+    #[must_use]
+    pub fn port(&self) -> u16 {
+        match self {
+            Self::Ip(addr) => addr.port(),
+            Self::DomainName { port, .. } => *port,
+        }
+    }
+}
+
+impl From<SocketAddrV4> for ServiceEndpoint {
+    fn from(value: SocketAddrV4) -> Self {
+        Self::Ip(value)
+    }
+}
+
+impl fmt::Display for ServiceEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Ip(addr) => addr.fmt(f),
+            Self::DomainName { domain_name, port } => write!(f, "{domain_name}:{port}"),
+        }
+    }
+}
+
+pub(crate) fn parse_socket_addr_v4(ip: Vec<u8>, port: i32) -> crate::Result<SocketAddrV4> {
+    let octets: Result<[u8; 4], _> = ip.try_into();
+    let octets = octets.map_err(|v| {
+        Error::from_protobuf(format!("expected 4 byte ip address, got `{}` bytes", v.len()))
+    })?;
+
+    Ok(SocketAddrV4::new(octets.into(), parse_port(port)?))
+}
+
+fn parse_port(port: i32) -> crate::Result<u16> {
+    u16::try_from(port).map_err(|_| {
+        Error::from_protobuf(format!(
+            "expected 16 bit non-negative port number, but the port was actually `{port}`",
+        ))
+    })
+}
+
+impl FromProtobuf<services::ServiceEndpoint> for ServiceEndpoint {
+    fn from_protobuf(pb: services::ServiceEndpoint) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        if !pb.ip_address_v4.is_empty() {
+            return Ok(Self::Ip(parse_socket_addr_v4(pb.ip_address_v4, pb.port)?));
+        }
+
+        if pb.domain_name.is_empty() {
+            return Err(Error::from_protobuf(
+                "expected either an ip address or a domain name in `ServiceEndpoint`",
+            ));
+        }
+
+        Ok(Self::DomainName { domain_name: pb.domain_name, port: parse_port(pb.port)? })
+    }
+}
+
+impl ToProtobuf for ServiceEndpoint {
+    type Protobuf = services::ServiceEndpoint;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        match self {
+            Self::Ip(addr) => services::ServiceEndpoint {
+                ip_address_v4: addr.ip().octets().to_vec(),
+                port: i32::from(addr.port()),
+                domain_name: String::new(),
+            },
+            Self::DomainName { domain_name, port } => services::ServiceEndpoint {
+                ip_address_v4: Vec::new(),
+                port: i32::from(*port),
+                domain_name: domain_name.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{
+        Ipv4Addr,
+        SocketAddrV4,
+    };
+
+    use hedera_proto::services;
+
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::ServiceEndpoint;
+
+    #[test]
+    fn ip_to_from_protobuf() {
+        let endpoint = ServiceEndpoint::from(SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 50211));
+
+        let pb = endpoint.to_protobuf();
+
+        assert_eq!(pb.ip_address_v4, vec![127, 0, 0, 1]);
+        assert_eq!(pb.domain_name, "");
+        assert_eq!(ServiceEndpoint::from_protobuf(pb).unwrap(), endpoint);
+    }
+
+    #[test]
+    fn domain_name_to_from_protobuf() {
+        let endpoint = ServiceEndpoint::domain_name("unit.test.com", 50212);
+
+        let pb = endpoint.to_protobuf();
+
+        assert!(pb.ip_address_v4.is_empty());
+        assert_eq!(pb.domain_name, "unit.test.com");
+        assert_eq!(ServiceEndpoint::from_protobuf(pb).unwrap(), endpoint);
+    }
+
+    #[test]
+    fn empty_fails() {
+        let pb = services::ServiceEndpoint {
+            ip_address_v4: Vec::new(),
+            port: 50211,
+            domain_name: String::new(),
+        };
+
+        assert!(ServiceEndpoint::from_protobuf(pb).is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ServiceEndpoint::domain_name("unit.test.com", 50211).to_string(),
+            "unit.test.com:50211"
+        );
+        assert_eq!(
+            ServiceEndpoint::from(SocketAddrV4::new(Ipv4Addr::new(127, 0, 0, 1), 50211))
+                .to_string(),
+            "127.0.0.1:50211"
+        );
+    }
+}
@@ -10,7 +10,7 @@ use crate::{
 
 // todo(sr): is this right?
 /// Info related to account/contract staking settings.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct StakingInfo {
     /// If `true`, the contract declines receiving a staking reward. The default value is `false`.
     pub decline_staking_reward: bool,
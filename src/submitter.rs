@@ -0,0 +1,484 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+
+use futures_core::stream::BoxStream;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+use triomphe::Arc;
+
+use crate::{
+    AccountId,
+    AnyTransaction,
+    Client,
+    Error,
+    TransactionReceipt,
+    TransactionResponse,
+};
+
+/// The result of submitting one transaction with a [`Submitter`]:
+/// its response, and its receipt if [`wait_for_receipts`](Submitter::wait_for_receipts) is enabled.
+pub type SubmitResult = (TransactionResponse, Option<TransactionReceipt>);
+
+/// Submits many transactions concurrently while limiting how much load is put on each node.
+///
+/// Each transaction is frozen and signed with the client's operator, assigned to the least busy node
+/// that still has room, and executed. One transaction failing doesn't stop the others.
+///
+/// Results are yielded as `(index, result)` in *completion order*, where `index` is the position
+/// of the transaction in the input stream. A successful result is the transaction's response and,
+/// when [`wait_for_receipts`](Self::wait_for_receipts) is enabled, its receipt.
+///
+/// # Examples
+/// ```no_run
+/// # async fn f(client: hedera::Client, transactions: Vec<hedera::AnyTransaction>) {
+/// use futures_util::StreamExt;
+///
+/// let mut results = hedera::Submitter::new(client)
+///     .wait_for_receipts(true)
+///     .submit(futures_util::stream::iter(transactions));
+///
+/// while let Some((index, result)) = results.next().await {
+///     match result {
+///         Ok((response, receipt)) => println!("transaction {index}: {response:?} {receipt:?}"),
+///         Err(e) => println!("transaction {index} failed: {e}"),
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Submitter {
+    client: Client,
+    max_concurrency: usize,
+    max_concurrency_per_node: usize,
+    wait_for_receipts: bool,
+}
+
+impl Submitter {
+    /// Create a new `Submitter` that submits transactions with `client`.
+    #[must_use]
+    pub fn new(client: Client) -> Self {
+        Self { client, max_concurrency: 32, max_concurrency_per_node: 4, wait_for_receipts: false }
+    }
+
+    /// Returns the maximum number of transactions in flight at once.
+    #[must_use]
+    pub fn get_max_concurrency(&self) -> usize {
+        self.max_concurrency
+    }
+
+    /// Sets the maximum number of transactions in flight at once.
+    ///
+    /// Defaults to `32`.
+    ///
+    /// # Panics
+    /// If `max_concurrency` == 0
+    pub fn max_concurrency(&mut self, max_concurrency: usize) -> &mut Self {
+        assert!(max_concurrency > 0, "Cannot set max concurrency to zero");
+
+        self.max_concurrency = max_concurrency;
+        self
+    }
+
+    /// Returns the maximum number of transactions in flight to any one node at once.
+    #[must_use]
+    pub fn get_max_concurrency_per_node(&self) -> usize {
+        self.max_concurrency_per_node
+    }
+
+    /// Sets the maximum number of transactions in flight to any one node at once.
+    ///
+    /// Defaults to `4`.
+    ///
+    /// # Panics
+    /// If `max_concurrency` == 0
+    pub fn max_concurrency_per_node(&mut self, max_concurrency: usize) -> &mut Self {
+        assert!(max_concurrency > 0, "Cannot set max concurrency per node to zero");
+
+        self.max_concurrency_per_node = max_concurrency;
+        self
+    }
+
+    /// Returns whether each transaction's receipt is fetched before its result is yielded.
+    #[must_use]
+    pub fn get_wait_for_receipts(&self) -> bool {
+        self.wait_for_receipts
+    }
+
+    /// Sets whether each transaction's receipt is fetched before its result is yielded.
+    ///
+    /// When enabled, the receipt is yielded alongside the response, and a failing receipt is reported as that transaction's error.
+    /// The in-flight slot is held while waiting, so this also limits how many transactions await consensus at once.
+    ///
+    /// Defaults to `false`.
+    pub fn wait_for_receipts(&mut self, wait_for_receipts: bool) -> &mut Self {
+        self.wait_for_receipts = wait_for_receipts;
+        self
+    }
+
+    /// Submit every transaction in `transactions`.
+    ///
+    /// Transactions without explicit node account IDs are sent to the node chosen for them first,
+    /// and only fall back to the other healthy nodes if that node can't be reached.
+    /// Transactions with explicit node account IDs only count against the first of those nodes
+    /// that has room.
+    ///
+    /// A transaction fails with [`Error::NetworkEmpty`] if there are no nodes to send it to.
+    pub fn submit<S>(
+        &self,
+        transactions: S,
+    ) -> BoxStream<'static, (usize, crate::Result<SubmitResult>)>
+    where
+        S: Stream<Item = AnyTransaction> + Send + 'static,
+    {
+        let client = self.client.clone();
+        let slots = Arc::new(NodeSlots::new(self.max_concurrency_per_node));
+        let wait_for_receipts = self.wait_for_receipts;
+
+        transactions
+            .enumerate()
+            .map(move |(index, transaction)| {
+                let client = client.clone();
+                let slots = Arc::clone(&slots);
+
+                async move {
+                    let result = submit_one(&client, &slots, transaction, wait_for_receipts).await;
+
+                    (index, result)
+                }
+            })
+            .buffer_unordered(self.max_concurrency)
+            .boxed()
+    }
+}
+
+impl Client {
+    /// Submit every transaction in `transactions` with the default [`Submitter`] settings.
+    ///
+    /// See [`Submitter::submit`].
+    pub fn submit_stream<S>(
+        &self,
+        transactions: S,
+    ) -> BoxStream<'static, (usize, crate::Result<SubmitResult>)>
+    where
+        S: Stream<Item = AnyTransaction> + Send + 'static,
+    {
+        Submitter::new(self.clone()).submit(transactions)
+    }
+}
+
+async fn submit_one(
+    client: &Client,
+    slots: &NodeSlots,
+    mut transaction: AnyTransaction,
+    wait_for_receipts: bool,
+) -> crate::Result<SubmitResult> {
+    let explicit_node_ids = transaction.get_node_account_ids().map(<[AccountId]>::to_vec);
+
+    let candidates = match &explicit_node_ids {
+        Some(ids) => ids.clone(),
+        None => {
+            let network = client.net().0.load();
+            let mut ids: Vec<_> = network.healthy_node_ids().collect();
+
+            if ids.is_empty() {
+                ids = network.node_ids().to_vec();
+            }
+
+            ids
+        }
+    };
+
+    let slot = slots.acquire(&candidates).await.ok_or(Error::NetworkEmpty)?;
+
+    let response = match explicit_node_ids {
+        Some(_) => transaction.freeze_with(client)?.execute(client).await?,
+        None => {
+            Box::pin(execute_with_fallbacks(client, transaction, slot.node_account_id, &candidates))
+                .await?
+        }
+    };
+
+    let receipt = match wait_for_receipts {
+        true => Some(response.get_receipt(client).await?),
+        false => None,
+    };
+
+    drop(slot);
+
+    Ok((response, receipt))
+}
+
+/// Execute `transaction` on `node_account_id`, falling back to the rest of `candidates` if that node can't be reached.
+async fn execute_with_fallbacks(
+    client: &Client,
+    mut transaction: AnyTransaction,
+    node_account_id: AccountId,
+    candidates: &[AccountId],
+) -> crate::Result<TransactionResponse> {
+    // both attempts use the same transaction ID, so that if the first one reached the network anyway
+    // the second is recognized as a duplicate instead of being executed twice.
+    if transaction.get_transaction_id().is_none() {
+        let payer = client.get_operator_account_id().ok_or(Error::NoPayerAccountOrTransactionId)?;
+        transaction.transaction_id(client.generate_transaction_id(payer));
+    }
+
+    let mut fallback = transaction.clone();
+
+    let error = match transaction
+        .node_account_ids([node_account_id])
+        .freeze_with(client)?
+        .execute(client)
+        .await
+    {
+        Ok(response) => return Ok(response),
+        Err(error @ (Error::GrpcStatus(_) | Error::TimedOut(_))) => error,
+        Err(error) => return Err(error),
+    };
+
+    let fallbacks: Vec<_> =
+        candidates.iter().copied().filter(|&id| id != node_account_id).collect();
+
+    if fallbacks.is_empty() {
+        return Err(error);
+    }
+
+    log::debug!("node `{node_account_id}` failed with {error:?}, falling back to the other nodes");
+
+    fallback
+        .node_account_ids(fallbacks)
+        .idempotent_resubmission(true)
+        .freeze_with(client)?
+        .execute(client)
+        .await
+}
+
+/// Tracks how many transactions are in flight per node.
+struct NodeSlots {
+    max_per_node: usize,
+    in_flight: Mutex<HashMap<AccountId, usize>>,
+    released: Notify,
+}
+
+impl NodeSlots {
+    fn new(max_per_node: usize) -> Self {
+        Self { max_per_node, in_flight: Mutex::new(HashMap::new()), released: Notify::new() }
+    }
+
+    /// Wait for any of `candidates` to have room, preferring the least busy node.
+    ///
+    /// Returns `None` if there are no `candidates`, since there would be nothing to wait for.
+    async fn acquire(&self, candidates: &[AccountId]) -> Option<NodeSlot<'_>> {
+        if candidates.is_empty() {
+            return None;
+        }
+
+        loop {
+            let released = self.released.notified();
+            tokio::pin!(released);
+
+            // register for notifications *before* checking, so that a release between the check and the wait isn't lost.
+            released.as_mut().enable();
+
+            if let Some(node_account_id) = self.try_acquire(candidates) {
+                return Some(NodeSlot { slots: self, node_account_id });
+            }
+
+            released.await;
+        }
+    }
+
+    fn try_acquire(&self, candidates: &[AccountId]) -> Option<AccountId> {
+        let mut in_flight = self.in_flight.lock();
+
+        let node_account_id = candidates
+            .iter()
+            .copied()
+            .map(|id| (id, in_flight.get(&id).copied().unwrap_or_default()))
+            .filter(|(_, count)| *count < self.max_per_node)
+            .min_by_key(|(_, count)| *count)?
+            .0;
+
+        *in_flight.entry(node_account_id).or_default() += 1;
+
+        Some(node_account_id)
+    }
+
+    fn release(&self, node_account_id: AccountId) {
+        {
+            let mut in_flight = self.in_flight.lock();
+            if let Some(count) = in_flight.get_mut(&node_account_id) {
+                *count -= 1;
+            }
+        }
+
+        // waiters may be waiting on different nodes, so everyone gets to re-check.
+        self.released.notify_waiters();
+    }
+}
+
+struct NodeSlot<'a> {
+    slots: &'a NodeSlots,
+    node_account_id: AccountId,
+}
+
+impl Drop for NodeSlot<'_> {
+    fn drop(&mut self) {
+        self.slots.release(self.node_account_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::Duration;
+
+    use futures_util::StreamExt;
+    use hedera_proto::services::ResponseCodeEnum;
+
+    use super::NodeSlots;
+    use crate::client::mock_node;
+    use crate::{
+        AccountId,
+        AnyTransaction,
+        Client,
+        Error,
+        Hbar,
+        Status,
+        Submitter,
+        TransferTransaction,
+    };
+
+    const NODE_A: AccountId = AccountId::new(0, 0, 3);
+    const NODE_B: AccountId = AccountId::new(0, 0, 4);
+
+    #[tokio::test]
+    async fn prefers_least_busy_node() {
+        let slots = NodeSlots::new(2);
+
+        let first = slots.acquire(&[NODE_A, NODE_B]).await.unwrap();
+        let second = slots.acquire(&[NODE_A, NODE_B]).await.unwrap();
+
+        assert_ne!(first.node_account_id, second.node_account_id);
+    }
+
+    #[tokio::test]
+    async fn waits_for_room() {
+        let slots = NodeSlots::new(1);
+
+        let held = slots.acquire(&[NODE_A]).await.unwrap();
+
+        assert!(tokio::time::timeout(Duration::from_millis(50), slots.acquire(&[NODE_A]))
+            .await
+            .is_err());
+
+        drop(held);
+
+        let slot = tokio::time::timeout(Duration::from_millis(50), slots.acquire(&[NODE_A]))
+            .await
+            .expect("slot should be free after release")
+            .unwrap();
+
+        assert_eq!(slot.node_account_id, NODE_A);
+    }
+
+    #[tokio::test]
+    async fn no_candidates() {
+        assert!(NodeSlots::new(1).acquire(&[]).await.is_none());
+    }
+
+    fn transfer() -> AnyTransaction {
+        let mut transaction = TransferTransaction::new();
+        transaction
+            .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(1));
+
+        transaction.into_any()
+    }
+
+    async fn healthy_node() -> String {
+        mock_node::spawn(|method, _| match method {
+            "getTransactionReceipts" => Ok(mock_node::receipt_response(ResponseCodeEnum::Success)),
+            _ => Ok(mock_node::transaction_response(ResponseCodeEnum::Ok)),
+        })
+        .await
+    }
+
+    #[tokio::test]
+    async fn submit_yields_receipts() {
+        let client = mock_node::client(&[healthy_node().await]);
+
+        let mut results: Vec<_> = Submitter::new(client)
+            .wait_for_receipts(true)
+            .submit(futures_util::stream::iter((0..3).map(|_| transfer())))
+            .collect()
+            .await;
+
+        results.sort_by_key(|(index, _)| *index);
+
+        assert_eq!(results.iter().map(|(index, _)| *index).collect::<Vec<_>>(), [0, 1, 2]);
+
+        for (_, result) in results {
+            let (response, receipt) = result.unwrap();
+
+            assert_eq!(response.node_account_id, NODE_A);
+            assert_eq!(receipt.map(|it| it.status), Some(Status::Success));
+        }
+    }
+
+    #[tokio::test]
+    async fn submit_without_receipts() {
+        let client = mock_node::client(&[healthy_node().await]);
+
+        let (_, result) =
+            client.submit_stream(futures_util::stream::iter([transfer()])).next().await.unwrap();
+
+        assert!(result.unwrap().1.is_none());
+    }
+
+    #[tokio::test]
+    async fn submit_with_empty_network() {
+        let client = Client::for_network(HashMap::new()).unwrap();
+
+        let (_, result) =
+            client.submit_stream(futures_util::stream::iter([transfer()])).next().await.unwrap();
+
+        assert!(matches!(result, Err(Error::NetworkEmpty)), "{result:?}");
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_other_nodes() {
+        let unreachable = mock_node::spawn(|_, _| Err(tonic::Status::unavailable("down"))).await;
+
+        let client = mock_node::client(&[unreachable, healthy_node().await]);
+        client.set_max_attempts(2);
+        client.set_max_backoff(Duration::from_millis(10));
+
+        let response =
+            super::execute_with_fallbacks(&client, transfer(), NODE_A, &[NODE_A, NODE_B])
+                .await
+                .unwrap();
+
+        assert_eq!(response.node_account_id, NODE_B);
+    }
+}
@@ -151,6 +151,31 @@ impl From<RoyaltyFee> for AnyCustomFee {
     }
 }
 
+// Topic custom fees are always fixed, and `FixedCustomFee` has no field for exempt collectors,
+// so `all_collectors_are_exempt` can't be carried over the wire and is always `false` once parsed.
+impl FromProtobuf<services::FixedCustomFee> for FixedFee {
+    fn from_protobuf(pb: services::FixedCustomFee) -> crate::Result<Self> {
+        let fee = pb_getf!(pb, fixed_fee)?;
+
+        Ok(Self {
+            fee: FixedFeeData::from_protobuf(fee)?,
+            fee_collector_account_id: Option::from_protobuf(pb.fee_collector_account_id)?,
+            all_collectors_are_exempt: false,
+        })
+    }
+}
+
+impl ToProtobuf for FixedFee {
+    type Protobuf = services::FixedCustomFee;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::FixedCustomFee {
+            fixed_fee: Some(self.fee.to_protobuf()),
+            fee_collector_account_id: self.fee_collector_account_id.to_protobuf(),
+        }
+    }
+}
+
 /// Represents the possible fee types.
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub enum Fee {
@@ -5,6 +5,7 @@ use crate::token::custom_fees::{
     AnyCustomFee,
     CustomFee,
     Fee,
+    FixedFee,
     FixedFeeData,
     FractionalFeeData,
     RoyaltyFeeData,
@@ -120,6 +121,77 @@ fn fixed_fee_can_be_created_from_protobuf() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn fixed_custom_fee_can_convert_to_protobuf() -> anyhow::Result<()> {
+    let custom_fee = FixedFee {
+        fee: FixedFeeData { amount: 1000, denominating_token_id: Some(TokenId::from(2)) },
+        fee_collector_account_id: Some(AccountId::from(1)),
+        all_collectors_are_exempt: false,
+    };
+
+    let custom_fee_proto = custom_fee.to_protobuf();
+
+    assert_eq!(Some(custom_fee.fee.to_protobuf()), custom_fee_proto.fixed_fee);
+    assert_eq!(
+        custom_fee.fee_collector_account_id.to_protobuf(),
+        custom_fee_proto.fee_collector_account_id
+    );
+
+    Ok(())
+}
+
+#[test]
+fn fixed_custom_fee_can_be_created_from_protobuf() -> anyhow::Result<()> {
+    let custom_fee_proto = services::FixedCustomFee {
+        fixed_fee: Some(services::FixedFee {
+            denominating_token_id: Some(TokenId::from(2).to_protobuf()),
+            amount: 1000,
+        }),
+        fee_collector_account_id: Some(AccountId::from(1).to_protobuf()),
+    };
+
+    let custom_fee = FixedFee::from_protobuf(custom_fee_proto.clone())?;
+
+    assert_eq!(Some(custom_fee.fee.to_protobuf()), custom_fee_proto.fixed_fee);
+    assert_eq!(
+        custom_fee.fee_collector_account_id.to_protobuf(),
+        custom_fee_proto.fee_collector_account_id
+    );
+    assert!(!custom_fee.all_collectors_are_exempt);
+
+    Ok(())
+}
+
+#[test]
+fn fixed_custom_fee_round_trips_through_protobuf() -> anyhow::Result<()> {
+    let custom_fee = FixedFee {
+        fee: FixedFeeData { amount: 1000, denominating_token_id: Some(TokenId::from(2)) },
+        fee_collector_account_id: Some(AccountId::from(1)),
+        all_collectors_are_exempt: false,
+    };
+
+    assert_eq!(FixedFee::from_protobuf(custom_fee.to_protobuf())?, custom_fee);
+
+    Ok(())
+}
+
+#[test]
+fn fixed_custom_fee_does_not_carry_exempt_collectors() -> anyhow::Result<()> {
+    let custom_fee = FixedFee {
+        fee: FixedFeeData { amount: 1000, denominating_token_id: None },
+        fee_collector_account_id: Some(AccountId::from(1)),
+        all_collectors_are_exempt: true,
+    };
+
+    let round_tripped = FixedFee::from_protobuf(custom_fee.to_protobuf())?;
+
+    assert_eq!(round_tripped.fee, custom_fee.fee);
+    assert_eq!(round_tripped.fee_collector_account_id, custom_fee.fee_collector_account_id);
+    assert!(!round_tripped.all_collectors_are_exempt);
+
+    Ok(())
+}
+
 #[test]
 fn fractional_fee_can_convert_to_protobuf() -> anyhow::Result<()> {
     let minimum_amount = 500;
@@ -0,0 +1,106 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+
+use crate::protobuf::ToProtobuf;
+use crate::{
+    FromProtobuf,
+    PendingAirdropId,
+};
+
+/// A record of a new pending airdrop, as reported in a [`TransactionRecord`](crate::TransactionRecord).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingAirdropRecord {
+    /// The unique identifier of the pending airdrop.
+    pub pending_airdrop_id: PendingAirdropId,
+
+    /// The amount of fungible tokens pending, in the lowest denomination of the token.
+    ///
+    /// This is always `0` for NFT airdrops.
+    pub amount: u64,
+}
+
+impl PendingAirdropRecord {
+    /// Create a new `PendingAirdropRecord` from protobuf-encoded `bytes`.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the bytes fails to produce a valid protobuf.
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if decoding the protobuf fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        FromProtobuf::from_bytes(bytes)
+    }
+
+    /// Convert `self` to a protobuf-encoded [`Vec<u8>`].
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        ToProtobuf::to_bytes(self)
+    }
+}
+
+impl FromProtobuf<services::PendingAirdropRecord> for PendingAirdropRecord {
+    fn from_protobuf(pb: services::PendingAirdropRecord) -> crate::Result<Self>
+    where
+        Self: Sized,
+    {
+        let pending_airdrop_id = pb_getf!(pb, pending_airdrop_id)?;
+
+        Ok(Self {
+            pending_airdrop_id: PendingAirdropId::from_protobuf(pending_airdrop_id)?,
+            amount: pb.pending_airdrop_value.map_or(0, |it| it.amount),
+        })
+    }
+}
+
+impl ToProtobuf for PendingAirdropRecord {
+    type Protobuf = services::PendingAirdropRecord;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::PendingAirdropRecord {
+            pending_airdrop_id: Some(self.pending_airdrop_id.to_protobuf()),
+            pending_airdrop_value: (self.amount != 0)
+                .then_some(services::PendingAirdropValue { amount: self.amount }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AccountId,
+        PendingAirdropId,
+        PendingAirdropRecord,
+        TokenId,
+    };
+
+    #[test]
+    fn to_from_bytes() {
+        let record = PendingAirdropRecord {
+            pending_airdrop_id: PendingAirdropId::new_token_id(
+                AccountId::new(0, 0, 5005),
+                AccountId::new(0, 0, 5006),
+                TokenId::new(0, 0, 5007),
+            ),
+            amount: 100,
+        };
+
+        assert_eq!(PendingAirdropRecord::from_bytes(&record.to_bytes()).unwrap(), record);
+    }
+}
@@ -0,0 +1,402 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+
+use hedera_proto::services;
+use hedera_proto::services::token_service_client::TokenServiceClient;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToSchedulableTransactionDataProtobuf,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::transfer_transaction::TokenTransfer;
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Error,
+    NftId,
+    TokenId,
+    TokenNftTransfer,
+    Transaction,
+    ValidateChecksums,
+};
+
+/// Airdrop one or more tokens to one or more accounts.
+///
+/// Each fungible transfer list works the same way as in a [`TransferTransaction`](crate::TransferTransaction):
+/// every negative amount is withdrawn from a sender, every positive amount is credited to a receiver
+/// and the amounts for each token must sum to zero.
+///
+/// If a receiver is already associated with the token (or has a free automatic association slot)
+/// the tokens are transferred immediately. Otherwise a pending airdrop is created, which the receiver
+/// can later accept with a [`TokenClaimAirdropTransaction`](crate::TokenClaimAirdropTransaction),
+/// or the sender can withdraw with a [`TokenCancelAirdropTransaction`](crate::TokenCancelAirdropTransaction).
+/// Any pending airdrops created are reported in
+/// [`TransactionRecord::new_pending_airdrops`](crate::TransactionRecord::new_pending_airdrops).
+///
+/// Hbar transfers are not supported.
+pub type TokenAirdropTransaction = Transaction<TokenAirdropTransactionData>;
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(test, derive(Eq, PartialEq))]
+pub struct TokenAirdropTransactionData {
+    token_transfers: Vec<TokenTransfer>,
+}
+
+impl TokenAirdropTransaction {
+    fn _token_transfer(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: i64,
+        approved: bool,
+        expected_decimals: Option<u32>,
+    ) -> &mut Self {
+        TokenTransfer::push_transfer(
+            &mut self.data_mut().token_transfers,
+            token_id,
+            account_id,
+            amount,
+            approved,
+            expected_decimals,
+        );
+
+        self
+    }
+
+    /// Add a non-approved token transfer to the airdrop.
+    ///
+    /// `amount` is in the lowest denomination for the token (if the token has `2` decimals this would be `0.01` tokens).
+    pub fn token_transfer(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: i64,
+    ) -> &mut Self {
+        self._token_transfer(token_id, account_id, amount, false, None)
+    }
+
+    /// Add an approved token transfer to the airdrop.
+    ///
+    /// `amount` is in the lowest denomination for the token (if the token has `2` decimals this would be `0.01` tokens).
+    pub fn approved_token_transfer(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: i64,
+    ) -> &mut Self {
+        self._token_transfer(token_id, account_id, amount, true, None)
+    }
+
+    /// Add a non-approved token transfer to the airdrop, ensuring that the token has `expected_decimals` decimals.
+    ///
+    /// `amount` is _still_ in the lowest denomination, however,
+    /// you will get an error if the token has a different amount of decimals than `expected_decimals`.
+    pub fn token_transfer_with_decimals(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: i64,
+        expected_decimals: u32,
+    ) -> &mut Self {
+        self._token_transfer(token_id, account_id, amount, false, Some(expected_decimals))
+    }
+
+    /// Add an approved token transfer to the airdrop, ensuring that the token has `expected_decimals` decimals.
+    ///
+    /// `amount` is _still_ in the lowest denomination, however,
+    /// you will get an error if the token has a different amount of decimals than `expected_decimals`.
+    pub fn approved_token_transfer_with_decimals(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: i64,
+        expected_decimals: u32,
+    ) -> &mut Self {
+        self._token_transfer(token_id, account_id, amount, true, Some(expected_decimals))
+    }
+
+    /// Returns all the token transfers associated with this airdrop.
+    pub fn get_token_transfers(&self) -> HashMap<TokenId, HashMap<AccountId, i64>> {
+        TokenTransfer::amounts(&self.data().token_transfers)
+    }
+
+    /// Returns the decimals associated with each token.
+    pub fn get_token_decimals(&self) -> HashMap<TokenId, u32> {
+        TokenTransfer::decimals(&self.data().token_transfers)
+    }
+
+    fn _nft_transfer(
+        &mut self,
+        nft_id: NftId,
+        sender_account_id: AccountId,
+        receiver_account_id: AccountId,
+        approved: bool,
+    ) -> &mut Self {
+        TokenTransfer::push_nft_transfer(
+            &mut self.data_mut().token_transfers,
+            nft_id,
+            sender_account_id,
+            receiver_account_id,
+            approved,
+        );
+
+        self
+    }
+
+    /// Add an approved nft transfer to the airdrop.
+    pub fn approved_nft_transfer(
+        &mut self,
+        nft_id: impl Into<NftId>,
+        sender_account_id: AccountId,
+        receiver_account_id: AccountId,
+    ) -> &mut Self {
+        self._nft_transfer(nft_id.into(), sender_account_id, receiver_account_id, true)
+    }
+
+    /// Add a non-approved nft transfer to the airdrop.
+    pub fn nft_transfer(
+        &mut self,
+        nft_id: impl Into<NftId>,
+        sender_account_id: AccountId,
+        receiver_account_id: AccountId,
+    ) -> &mut Self {
+        self._nft_transfer(nft_id.into(), sender_account_id, receiver_account_id, false)
+    }
+
+    /// Returns all the NFT transfers associated with this airdrop.
+    pub fn get_nft_transfers(&self) -> HashMap<TokenId, Vec<TokenNftTransfer>> {
+        TokenTransfer::nft_transfers(&self.data().token_transfers)
+    }
+}
+
+impl TransactionData for TokenAirdropTransactionData {}
+
+impl TransactionExecute for TokenAirdropTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { TokenServiceClient::new(channel).airdrop_tokens(request).await })
+    }
+}
+
+impl ValidateChecksums for TokenAirdropTransactionData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        for token_transfer in &self.token_transfers {
+            token_transfer.validate_checksums(ledger_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl ToTransactionDataProtobuf for TokenAirdropTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::TokenAirdrop(self.to_protobuf())
+    }
+}
+
+impl ToSchedulableTransactionDataProtobuf for TokenAirdropTransactionData {
+    fn to_schedulable_transaction_data_protobuf(
+        &self,
+    ) -> services::schedulable_transaction_body::Data {
+        services::schedulable_transaction_body::Data::TokenAirdrop(self.to_protobuf())
+    }
+}
+
+impl From<TokenAirdropTransactionData> for AnyTransactionData {
+    fn from(transaction: TokenAirdropTransactionData) -> Self {
+        Self::TokenAirdrop(transaction)
+    }
+}
+
+impl FromProtobuf<services::TokenAirdropTransactionBody> for TokenAirdropTransactionData {
+    fn from_protobuf(pb: services::TokenAirdropTransactionBody) -> crate::Result<Self> {
+        Ok(Self { token_transfers: Vec::from_protobuf(pb.token_transfers)? })
+    }
+}
+
+impl ToProtobuf for TokenAirdropTransactionData {
+    type Protobuf = services::TokenAirdropTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::TokenAirdropTransactionBody {
+            token_transfers: self.token_transfers.to_protobuf(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use expect_test::expect_file;
+    use hedera_proto::services;
+
+    use super::TokenAirdropTransactionData;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::transaction::test_helpers::{
+        check_body,
+        transaction_body,
+    };
+    use crate::transfer_transaction::TokenTransfer;
+    use crate::{
+        AccountId,
+        AnyTransaction,
+        TokenAirdropTransaction,
+        TokenId,
+    };
+
+    const TEST_TOKEN_ID: TokenId = TokenId::new(0, 0, 5);
+    const TEST_NFT_TOKEN_ID: TokenId = TokenId::new(0, 0, 3);
+    const SENDER: AccountId = AccountId::new(0, 0, 5006);
+    const RECEIVER: AccountId = AccountId::new(0, 0, 5008);
+
+    fn make_transaction() -> TokenAirdropTransaction {
+        let mut tx = TokenAirdropTransaction::new_for_tests();
+
+        tx.token_transfer(TEST_TOKEN_ID, RECEIVER, 400)
+            .token_transfer_with_decimals(TEST_TOKEN_ID, SENDER, -400, 3)
+            .approved_token_transfer(TokenId::new(0, 0, 4), RECEIVER, 1)
+            .approved_token_transfer(TokenId::new(0, 0, 4), SENDER, -1)
+            .nft_transfer(TEST_NFT_TOKEN_ID.nft(1), SENDER, RECEIVER)
+            .approved_nft_transfer(TEST_NFT_TOKEN_ID.nft(2), SENDER, RECEIVER)
+            .freeze()
+            .unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn serialize() {
+        let tx = make_transaction();
+
+        let tx = transaction_body(tx);
+
+        let tx = check_body(tx);
+
+        expect_file!["./snapshots/token_airdrop_transaction/serialize.txt"].assert_debug_eq(&tx);
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_body(tx);
+        let tx2 = transaction_body(tx2);
+
+        assert_eq!(tx, tx2);
+    }
+
+    #[test]
+    fn from_proto_body() {
+        let tx = services::TokenAirdropTransactionBody {
+            token_transfers: vec![services::TokenTransferList {
+                token: Some(TEST_TOKEN_ID.to_protobuf()),
+                transfers: vec![
+                    services::AccountAmount {
+                        account_id: Some(SENDER.to_protobuf()),
+                        amount: -10,
+                        is_approval: false,
+                    },
+                    services::AccountAmount {
+                        account_id: Some(RECEIVER.to_protobuf()),
+                        amount: 10,
+                        is_approval: false,
+                    },
+                ],
+                nft_transfers: Vec::new(),
+                expected_decimals: Some(2),
+            }],
+        };
+
+        let tx = TokenAirdropTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(
+            TokenTransfer::amounts(&tx.token_transfers),
+            HashMap::from([(TEST_TOKEN_ID, HashMap::from([(SENDER, -10), (RECEIVER, 10)]))])
+        );
+        assert_eq!(
+            TokenTransfer::decimals(&tx.token_transfers),
+            HashMap::from([(TEST_TOKEN_ID, 2)])
+        );
+    }
+
+    #[test]
+    fn get_set_token_transfers() {
+        let mut tx = TokenAirdropTransaction::new();
+        tx.token_transfer_with_decimals(TEST_TOKEN_ID, SENDER, -10, 2).token_transfer(
+            TEST_TOKEN_ID,
+            RECEIVER,
+            10,
+        );
+
+        assert_eq!(
+            tx.get_token_transfers(),
+            HashMap::from([(TEST_TOKEN_ID, HashMap::from([(SENDER, -10), (RECEIVER, 10)]))])
+        );
+        // the last call to `token_transfer` didn't specify decimals.
+        assert_eq!(tx.get_token_decimals(), HashMap::new());
+    }
+
+    #[test]
+    fn get_set_nft_transfers() {
+        let mut tx = TokenAirdropTransaction::new();
+        tx.nft_transfer(TEST_NFT_TOKEN_ID.nft(1), SENDER, RECEIVER);
+
+        let transfers = tx.get_nft_transfers();
+        let transfers = &transfers[&TEST_NFT_TOKEN_ID];
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].serial, 1);
+        assert_eq!(transfers[0].sender, SENDER);
+        assert_eq!(transfers[0].receiver, RECEIVER);
+        assert!(!transfers[0].is_approved);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_token_transfers_frozen_panic() {
+        let mut tx = make_transaction();
+        tx.token_transfer(TEST_TOKEN_ID, RECEIVER, 1);
+    }
+}
@@ -28,7 +28,7 @@ use crate::{
 };
 
 /// A token <-> account association.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TokenAssociation {
     /// The token involved in the association.
     pub token_id: TokenId,
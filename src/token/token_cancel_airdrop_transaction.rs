@@ -0,0 +1,249 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use hedera_proto::services::token_service_client::TokenServiceClient;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToSchedulableTransactionDataProtobuf,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::{
+    BoxGrpcFuture,
+    Error,
+    PendingAirdropId,
+    Transaction,
+    ValidateChecksums,
+};
+
+/// Cancel one or more pending airdrops.
+///
+/// Cancelling a pending airdrop removes it from the network state; no tokens are transferred.
+///
+/// --- Signing Requirements ---
+/// 1. The sender of every listed pending airdrop must sign the transaction.
+pub type TokenCancelAirdropTransaction = Transaction<TokenCancelAirdropTransactionData>;
+
+#[derive(Debug, Clone, Default)]
+pub struct TokenCancelAirdropTransactionData {
+    /// The pending airdrops to cancel.
+    pending_airdrop_ids: Vec<PendingAirdropId>,
+}
+
+impl TokenCancelAirdropTransaction {
+    /// Returns the pending airdrops to cancel.
+    #[must_use]
+    pub fn get_pending_airdrop_ids(&self) -> Vec<PendingAirdropId> {
+        self.data().pending_airdrop_ids.clone()
+    }
+
+    /// Sets the pending airdrops to cancel.
+    pub fn pending_airdrop_ids(
+        &mut self,
+        pending_airdrop_ids: impl IntoIterator<Item = PendingAirdropId>,
+    ) -> &mut Self {
+        self.data_mut().pending_airdrop_ids = pending_airdrop_ids.into_iter().collect();
+        self
+    }
+
+    /// Adds a pending airdrop to cancel.
+    pub fn add_pending_airdrop_id(&mut self, pending_airdrop_id: PendingAirdropId) -> &mut Self {
+        self.data_mut().pending_airdrop_ids.push(pending_airdrop_id);
+        self
+    }
+}
+
+impl TransactionData for TokenCancelAirdropTransactionData {}
+
+impl TransactionExecute for TokenCancelAirdropTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { TokenServiceClient::new(channel).cancel_airdrop(request).await })
+    }
+}
+
+impl ValidateChecksums for TokenCancelAirdropTransactionData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        for pending_airdrop_id in &self.pending_airdrop_ids {
+            pending_airdrop_id.validate_checksums(ledger_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl ToTransactionDataProtobuf for TokenCancelAirdropTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::TokenCancelAirdrop(self.to_protobuf())
+    }
+}
+
+impl ToSchedulableTransactionDataProtobuf for TokenCancelAirdropTransactionData {
+    fn to_schedulable_transaction_data_protobuf(
+        &self,
+    ) -> services::schedulable_transaction_body::Data {
+        services::schedulable_transaction_body::Data::TokenCancelAirdrop(self.to_protobuf())
+    }
+}
+
+impl From<TokenCancelAirdropTransactionData> for AnyTransactionData {
+    fn from(transaction: TokenCancelAirdropTransactionData) -> Self {
+        Self::TokenCancelAirdrop(transaction)
+    }
+}
+
+impl FromProtobuf<services::TokenCancelAirdropTransactionBody>
+    for TokenCancelAirdropTransactionData
+{
+    fn from_protobuf(pb: services::TokenCancelAirdropTransactionBody) -> crate::Result<Self> {
+        Ok(Self { pending_airdrop_ids: Vec::from_protobuf(pb.pending_airdrops)? })
+    }
+}
+
+impl ToProtobuf for TokenCancelAirdropTransactionData {
+    type Protobuf = services::TokenCancelAirdropTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::TokenCancelAirdropTransactionBody {
+            pending_airdrops: self.pending_airdrop_ids.to_protobuf(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect_file;
+    use hedera_proto::services;
+
+    use super::TokenCancelAirdropTransactionData;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::transaction::test_helpers::{
+        check_body,
+        transaction_body,
+    };
+    use crate::{
+        AccountId,
+        AnyTransaction,
+        PendingAirdropId,
+        TokenCancelAirdropTransaction,
+        TokenId,
+    };
+
+    fn test_pending_airdrop_ids() -> Vec<PendingAirdropId> {
+        vec![
+            PendingAirdropId::new_token_id(
+                AccountId::new(0, 0, 5006),
+                AccountId::new(0, 0, 5008),
+                TokenId::new(0, 0, 5),
+            ),
+            PendingAirdropId::new_nft_id(
+                AccountId::new(0, 0, 5006),
+                AccountId::new(0, 0, 5008),
+                TokenId::new(0, 0, 3).nft(1),
+            ),
+        ]
+    }
+
+    fn make_transaction() -> TokenCancelAirdropTransaction {
+        let mut tx = TokenCancelAirdropTransaction::new_for_tests();
+
+        tx.pending_airdrop_ids(test_pending_airdrop_ids()).freeze().unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn serialize() {
+        let tx = make_transaction();
+
+        let tx = transaction_body(tx);
+
+        let tx = check_body(tx);
+
+        expect_file!["./snapshots/token_cancel_airdrop_transaction/serialize.txt"]
+            .assert_debug_eq(&tx);
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_body(tx);
+        let tx2 = transaction_body(tx2);
+
+        assert_eq!(tx, tx2);
+    }
+
+    #[test]
+    fn from_proto_body() {
+        let tx = services::TokenCancelAirdropTransactionBody {
+            pending_airdrops: test_pending_airdrop_ids().to_protobuf(),
+        };
+
+        let tx = TokenCancelAirdropTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(tx.pending_airdrop_ids, test_pending_airdrop_ids());
+    }
+
+    #[test]
+    fn get_set_pending_airdrop_ids() {
+        let mut tx = TokenCancelAirdropTransaction::new();
+        tx.pending_airdrop_ids(test_pending_airdrop_ids());
+        assert_eq!(tx.get_pending_airdrop_ids(), test_pending_airdrop_ids());
+    }
+
+    #[test]
+    fn add_pending_airdrop_id() {
+        let ids = test_pending_airdrop_ids();
+
+        let mut tx = TokenCancelAirdropTransaction::new();
+        tx.add_pending_airdrop_id(ids[0]).add_pending_airdrop_id(ids[1]);
+        assert_eq!(tx.get_pending_airdrop_ids(), ids);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_pending_airdrop_ids_frozen_panic() {
+        let mut tx = make_transaction();
+        tx.pending_airdrop_ids(test_pending_airdrop_ids());
+    }
+}
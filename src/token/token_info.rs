@@ -42,7 +42,7 @@ use crate::{
 };
 
 /// Response from [`TokenInfoQuery`][crate::TokenInfoQuery].
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TokenInfo {
     /// The ID of the token for which information is requested.
     pub token_id: TokenId,
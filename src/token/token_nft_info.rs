@@ -32,7 +32,7 @@ use crate::{
 
 /// Response from [`TokenNftInfoQuery`][crate::TokenNftInfoQuery].
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TokenNftInfo {
     /// The ID of the NFT.
     pub nft_id: NftId,
@@ -0,0 +1,352 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use hedera_proto::services::token_service_client::TokenServiceClient;
+use tonic::transport::Channel;
+
+use crate::ledger_id::RefLedgerId;
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToSchedulableTransactionDataProtobuf,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::{
+    AccountId,
+    BoxGrpcFuture,
+    Error,
+    NftId,
+    TokenId,
+    Transaction,
+    ValidateChecksums,
+};
+
+/// Reject one or more tokens, returning them to the treasury.
+///
+/// Rejecting a fungible token returns the owner's entire balance of that token;
+/// rejecting an NFT returns only the given serial.
+/// Custom fees and royalties are not charged.
+///
+/// --- Signing Requirements ---
+/// 1. The owner must sign the transaction (the transaction fee payer is the owner if no owner is set).
+pub type TokenRejectTransaction = Transaction<TokenRejectTransactionData>;
+
+#[derive(Debug, Clone, Default)]
+pub struct TokenRejectTransactionData {
+    /// The account holding the tokens to be rejected.
+    owner: Option<AccountId>,
+
+    /// The fungible tokens to reject.
+    token_ids: Vec<TokenId>,
+
+    /// The NFTs to reject.
+    nft_ids: Vec<NftId>,
+}
+
+impl TokenRejectTransaction {
+    /// Returns the account holding the tokens to be rejected.
+    #[must_use]
+    pub fn get_owner(&self) -> Option<AccountId> {
+        self.data().owner
+    }
+
+    /// Sets the account holding the tokens to be rejected.
+    ///
+    /// Defaults to the transaction fee payer.
+    pub fn owner(&mut self, owner: AccountId) -> &mut Self {
+        self.data_mut().owner = Some(owner);
+        self
+    }
+
+    /// Returns the fungible tokens to reject.
+    #[must_use]
+    pub fn get_token_ids(&self) -> Vec<TokenId> {
+        self.data().token_ids.clone()
+    }
+
+    /// Sets the fungible tokens to reject.
+    pub fn token_ids(&mut self, token_ids: impl IntoIterator<Item = TokenId>) -> &mut Self {
+        self.data_mut().token_ids = token_ids.into_iter().collect();
+        self
+    }
+
+    /// Adds a fungible token to reject.
+    pub fn add_token_id(&mut self, token_id: TokenId) -> &mut Self {
+        self.data_mut().token_ids.push(token_id);
+        self
+    }
+
+    /// Returns the NFTs to reject.
+    #[must_use]
+    pub fn get_nft_ids(&self) -> Vec<NftId> {
+        self.data().nft_ids.clone()
+    }
+
+    /// Sets the NFTs to reject.
+    pub fn nft_ids(&mut self, nft_ids: impl IntoIterator<Item = NftId>) -> &mut Self {
+        self.data_mut().nft_ids = nft_ids.into_iter().collect();
+        self
+    }
+
+    /// Adds an NFT to reject.
+    pub fn add_nft_id(&mut self, nft_id: NftId) -> &mut Self {
+        self.data_mut().nft_ids.push(nft_id);
+        self
+    }
+}
+
+impl TransactionData for TokenRejectTransactionData {}
+
+impl TransactionExecute for TokenRejectTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { TokenServiceClient::new(channel).reject_token(request).await })
+    }
+}
+
+impl ValidateChecksums for TokenRejectTransactionData {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.owner.validate_checksums(ledger_id)?;
+        for token_id in &self.token_ids {
+            token_id.validate_checksums(ledger_id)?;
+        }
+        for nft_id in &self.nft_ids {
+            nft_id.validate_checksums(ledger_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl ToTransactionDataProtobuf for TokenRejectTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::TokenReject(self.to_protobuf())
+    }
+}
+
+impl ToSchedulableTransactionDataProtobuf for TokenRejectTransactionData {
+    fn to_schedulable_transaction_data_protobuf(
+        &self,
+    ) -> services::schedulable_transaction_body::Data {
+        services::schedulable_transaction_body::Data::TokenReject(self.to_protobuf())
+    }
+}
+
+impl From<TokenRejectTransactionData> for AnyTransactionData {
+    fn from(transaction: TokenRejectTransactionData) -> Self {
+        Self::TokenReject(transaction)
+    }
+}
+
+impl FromProtobuf<services::TokenRejectTransactionBody> for TokenRejectTransactionData {
+    fn from_protobuf(pb: services::TokenRejectTransactionBody) -> crate::Result<Self> {
+        use services::token_reference::TokenIdentifier;
+
+        let mut token_ids = Vec::new();
+        let mut nft_ids = Vec::new();
+
+        for rejection in pb.rejections {
+            match pb_getf!(rejection, token_identifier)? {
+                TokenIdentifier::FungibleToken(it) => token_ids.push(TokenId::from_protobuf(it)?),
+                TokenIdentifier::Nft(it) => nft_ids.push(NftId::from_protobuf(it)?),
+            }
+        }
+
+        Ok(Self { owner: Option::from_protobuf(pb.owner)?, token_ids, nft_ids })
+    }
+}
+
+impl ToProtobuf for TokenRejectTransactionData {
+    type Protobuf = services::TokenRejectTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        use services::token_reference::TokenIdentifier;
+
+        let fungible =
+            self.token_ids.iter().map(|it| TokenIdentifier::FungibleToken(it.to_protobuf()));
+        let nfts = self.nft_ids.iter().map(|it| TokenIdentifier::Nft(it.to_protobuf()));
+
+        let rejections = fungible
+            .chain(nfts)
+            .map(|it| services::TokenReference { token_identifier: Some(it) })
+            .collect();
+
+        services::TokenRejectTransactionBody { owner: self.owner.to_protobuf(), rejections }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect_file;
+    use hedera_proto::services;
+
+    use super::TokenRejectTransactionData;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::transaction::test_helpers::{
+        check_body,
+        transaction_body,
+    };
+    use crate::{
+        AccountId,
+        AnyTransaction,
+        NftId,
+        TokenId,
+        TokenRejectTransaction,
+    };
+
+    const TEST_OWNER: AccountId = AccountId::new(0, 0, 5008);
+
+    fn test_token_ids() -> Vec<TokenId> {
+        vec![TokenId::new(0, 0, 5), TokenId::new(0, 0, 6)]
+    }
+
+    fn test_nft_ids() -> Vec<NftId> {
+        vec![TokenId::new(0, 0, 3).nft(1), TokenId::new(0, 0, 3).nft(2)]
+    }
+
+    fn make_transaction() -> TokenRejectTransaction {
+        let mut tx = TokenRejectTransaction::new_for_tests();
+
+        tx.owner(TEST_OWNER).token_ids(test_token_ids()).nft_ids(test_nft_ids()).freeze().unwrap();
+
+        tx
+    }
+
+    #[test]
+    fn serialize() {
+        let tx = make_transaction();
+
+        let tx = transaction_body(tx);
+
+        let tx = check_body(tx);
+
+        expect_file!["./snapshots/token_reject_transaction/serialize.txt"].assert_debug_eq(&tx);
+    }
+
+    #[test]
+    fn to_from_bytes() {
+        let tx = make_transaction();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_body(tx);
+        let tx2 = transaction_body(tx2);
+
+        assert_eq!(tx, tx2);
+    }
+
+    #[test]
+    fn from_proto_body() {
+        use services::token_reference::TokenIdentifier;
+
+        let tx = services::TokenRejectTransactionBody {
+            owner: Some(TEST_OWNER.to_protobuf()),
+            rejections: vec![
+                services::TokenReference {
+                    token_identifier: Some(TokenIdentifier::Nft(test_nft_ids()[0].to_protobuf())),
+                },
+                services::TokenReference {
+                    token_identifier: Some(TokenIdentifier::FungibleToken(
+                        test_token_ids()[0].to_protobuf(),
+                    )),
+                },
+            ],
+        };
+
+        let tx = TokenRejectTransactionData::from_protobuf(tx).unwrap();
+
+        assert_eq!(tx.owner, Some(TEST_OWNER));
+        assert_eq!(tx.token_ids, vec![test_token_ids()[0]]);
+        assert_eq!(tx.nft_ids, vec![test_nft_ids()[0]]);
+    }
+
+    #[test]
+    fn get_set_owner() {
+        let mut tx = TokenRejectTransaction::new();
+        tx.owner(TEST_OWNER);
+        assert_eq!(tx.get_owner(), Some(TEST_OWNER));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_owner_frozen_panic() {
+        let mut tx = make_transaction();
+        tx.owner(TEST_OWNER);
+    }
+
+    #[test]
+    fn get_set_token_ids() {
+        let mut tx = TokenRejectTransaction::new();
+        tx.token_ids(test_token_ids());
+        assert_eq!(tx.get_token_ids(), test_token_ids());
+    }
+
+    #[test]
+    fn add_token_id() {
+        let mut tx = TokenRejectTransaction::new();
+        tx.add_token_id(test_token_ids()[0]).add_token_id(test_token_ids()[1]);
+        assert_eq!(tx.get_token_ids(), test_token_ids());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_token_ids_frozen_panic() {
+        let mut tx = make_transaction();
+        tx.token_ids(test_token_ids());
+    }
+
+    #[test]
+    fn get_set_nft_ids() {
+        let mut tx = TokenRejectTransaction::new();
+        tx.nft_ids(test_nft_ids());
+        assert_eq!(tx.get_nft_ids(), test_nft_ids());
+    }
+
+    #[test]
+    fn add_nft_id() {
+        let mut tx = TokenRejectTransaction::new();
+        tx.add_nft_id(test_nft_ids()[0]).add_nft_id(test_nft_ids()[1]);
+        assert_eq!(tx.get_nft_ids(), test_nft_ids());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_nft_ids_frozen_panic() {
+        let mut tx = make_transaction();
+        tx.nft_ids(test_nft_ids());
+    }
+}
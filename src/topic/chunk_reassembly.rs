@@ -0,0 +1,433 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+use std::time::Duration;
+
+use tokio::time::Instant;
+
+use super::topic_message::{
+    PbTopicMessageChunk,
+    PbTopicMessageHeader,
+};
+use crate::{
+    IncompleteTopicMessage,
+    IncompleteTopicMessageReason,
+    TopicMessage,
+    TransactionId,
+};
+
+/// Limits on how [`TopicMessageQuery`](crate::TopicMessageQuery) buffers the chunks of chunked messages
+/// while it waits for the rest of them.
+///
+/// A message that exceeds these limits is dropped and reported as a
+/// [`TopicMessageItem::Incomplete`](crate::TopicMessageItem::Incomplete), any of its chunks that arrive
+/// later are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkReassemblyPolicy {
+    max_pending_messages: usize,
+    max_pending_bytes: usize,
+    timeout: Duration,
+}
+
+impl Default for ChunkReassemblyPolicy {
+    fn default() -> Self {
+        Self {
+            max_pending_messages: Self::DEFAULT_MAX_PENDING_MESSAGES,
+            max_pending_bytes: Self::DEFAULT_MAX_PENDING_BYTES,
+            timeout: Self::DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+impl ChunkReassemblyPolicy {
+    const DEFAULT_MAX_PENDING_MESSAGES: usize = 1000;
+    const DEFAULT_MAX_PENDING_BYTES: usize = 10 * 1024 * 1024;
+    const DEFAULT_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
+    /// Create a policy with the default limits.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the maximum number of partially received messages buffered at once.
+    #[must_use]
+    pub fn get_max_pending_messages(&self) -> usize {
+        self.max_pending_messages
+    }
+
+    /// Sets the maximum number of partially received messages buffered at once,
+    /// past which the oldest one is dropped.
+    ///
+    /// Defaults to 1000.
+    pub fn max_pending_messages(&mut self, max_pending_messages: usize) -> &mut Self {
+        self.max_pending_messages = max_pending_messages;
+        self
+    }
+
+    /// Returns the maximum combined size of the chunks buffered at once.
+    #[must_use]
+    pub fn get_max_pending_bytes(&self) -> usize {
+        self.max_pending_bytes
+    }
+
+    /// Sets the maximum combined size of the chunks buffered at once,
+    /// past which the oldest partially received message is dropped.
+    ///
+    /// Defaults to 10 MiB.
+    pub fn max_pending_bytes(&mut self, max_pending_bytes: usize) -> &mut Self {
+        self.max_pending_bytes = max_pending_bytes;
+        self
+    }
+
+    /// Returns how long to wait for the rest of a message's chunks after receiving its first one.
+    #[must_use]
+    pub fn get_timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Sets how long to wait for the rest of a message's chunks after receiving its first one.
+    ///
+    /// Defaults to 15 minutes.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+struct PendingMessage {
+    total: i32,
+    // sorted by `number`, without duplicates.
+    chunks: Vec<PbTopicMessageChunk>,
+    bytes: usize,
+    deadline: Instant,
+    // when the message was first seen, relative to the others, for evicting the oldest.
+    order: u64,
+}
+
+/// Reassembles chunked messages within the limits of a [`ChunkReassemblyPolicy`].
+pub(crate) struct ChunkReassembler {
+    policy: ChunkReassemblyPolicy,
+    pending: HashMap<TransactionId, PendingMessage>,
+    pending_bytes: usize,
+    next_order: u64,
+    // messages that were completed or given up on, and until when to ignore their chunks.
+    finished: HashMap<TransactionId, Instant>,
+    incomplete: VecDeque<IncompleteTopicMessage>,
+}
+
+impl ChunkReassembler {
+    pub(crate) fn new(policy: ChunkReassemblyPolicy) -> Self {
+        Self {
+            policy,
+            pending: HashMap::new(),
+            pending_bytes: 0,
+            next_order: 0,
+            finished: HashMap::new(),
+            incomplete: VecDeque::new(),
+        }
+    }
+
+    /// Adds `chunk`, returning the message it completes, if any.
+    ///
+    /// Chunks with a number outside of `1..=total`, a `total` that disagrees with the message's first chunk,
+    /// or a number that was already received are ignored.
+    pub(crate) fn push(
+        &mut self,
+        chunk: PbTopicMessageChunk,
+        now: Instant,
+    ) -> Option<TopicMessage> {
+        let id = chunk.initial_transaction_id;
+
+        if self.finished.contains_key(&id) {
+            return None;
+        }
+
+        // validate before inserting, so that a malformed first chunk doesn't leave an empty message behind.
+        let total = self.pending.get(&id).map_or(chunk.total, |it| it.total);
+
+        if chunk.total != total || !(1..=total).contains(&chunk.number) {
+            log::warn!(
+                "ignoring malformed chunk {}/{} of message `{id}`",
+                chunk.number,
+                chunk.total
+            );
+            return None;
+        }
+
+        let pending = self.pending.entry(id).or_insert_with(|| {
+            let order = self.next_order;
+            self.next_order += 1;
+
+            PendingMessage {
+                total: chunk.total,
+                chunks: Vec::new(),
+                bytes: 0,
+                deadline: now + self.policy.timeout,
+                order,
+            }
+        });
+
+        if let Err(index) = pending.chunks.binary_search_by_key(&chunk.number, |it| it.number) {
+            pending.bytes += chunk.header.message.len();
+            self.pending_bytes += chunk.header.message.len();
+            pending.chunks.insert(index, chunk);
+        }
+
+        if pending.chunks.len() == pending.total.unsigned_abs() as usize {
+            let pending = self.remove(&id, now);
+            return Some(TopicMessage::from_chunks(pending.chunks));
+        }
+
+        self.enforce_limits(now);
+
+        None
+    }
+
+    /// Gives up on every message whose timeout has passed, and forgets finished messages that are long gone.
+    pub(crate) fn expire(&mut self, now: Instant) {
+        self.finished.retain(|_, until| *until > now);
+
+        let mut expired: Vec<_> = self
+            .pending
+            .iter()
+            .filter(|(_, it)| it.deadline <= now)
+            .map(|(id, it)| (it.order, *id))
+            .collect();
+
+        expired.sort_unstable_by_key(|(order, _)| *order);
+
+        for (_, id) in expired {
+            self.give_up(&id, IncompleteTopicMessageReason::TimedOut, now);
+        }
+    }
+
+    /// Returns the chunk with the lowest sequence number out of every pending message.
+    pub(crate) fn earliest_pending_chunk(&self) -> Option<&PbTopicMessageHeader> {
+        self.pending
+            .values()
+            .flat_map(|it| &it.chunks)
+            .map(|it| &it.header)
+            .min_by_key(|it| it.sequence_number)
+    }
+
+    /// Returns the earliest time a pending message times out.
+    pub(crate) fn next_deadline(&self) -> Option<Instant> {
+        self.pending.values().map(|it| it.deadline).min()
+    }
+
+    /// Takes the next message that was given up on.
+    pub(crate) fn take_incomplete(&mut self) -> Option<IncompleteTopicMessage> {
+        self.incomplete.pop_front()
+    }
+
+    fn enforce_limits(&mut self, now: Instant) {
+        while self.pending.len() > self.policy.max_pending_messages
+            || self.pending_bytes > self.policy.max_pending_bytes
+        {
+            let Some(oldest) =
+                self.pending.iter().min_by_key(|(_, it)| it.order).map(|(id, _)| *id)
+            else {
+                break;
+            };
+
+            self.give_up(&oldest, IncompleteTopicMessageReason::PendingLimitExceeded, now);
+        }
+    }
+
+    fn give_up(&mut self, id: &TransactionId, reason: IncompleteTopicMessageReason, now: Instant) {
+        let pending = self.remove(id, now);
+
+        self.incomplete.push_back(IncompleteTopicMessage {
+            transaction: *id,
+            total: pending.total.unsigned_abs(),
+            chunks: pending.chunks.into_iter().map(Into::into).collect(),
+            reason,
+        });
+    }
+
+    fn remove(&mut self, id: &TransactionId, now: Instant) -> PendingMessage {
+        let pending = self.pending.remove(id).expect("message should be pending");
+
+        self.pending_bytes -= pending.bytes;
+
+        // late and duplicate chunks can trail behind by about as long as a message can take to arrive.
+        self.finished.insert(*id, now + self.policy.timeout);
+
+        pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use expect_test::expect;
+    use time::OffsetDateTime;
+    use tokio::time::Instant;
+
+    use super::{
+        ChunkReassembler,
+        ChunkReassemblyPolicy,
+    };
+    use crate::topic::topic_message::{
+        PbTopicMessageChunk,
+        PbTopicMessageHeader,
+    };
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::{
+        IncompleteTopicMessageReason,
+        TransactionId,
+    };
+
+    fn chunk(id: TransactionId, number: i32, total: i32) -> PbTopicMessageChunk {
+        PbTopicMessageChunk {
+            header: PbTopicMessageHeader {
+                consensus_timestamp: OffsetDateTime::from_unix_timestamp(1_554_158_542).unwrap(),
+                sequence_number: number as u64,
+                running_hash: Vec::new(),
+                running_hash_version: 3,
+                message: format!("{number}:").into_bytes(),
+            },
+            initial_transaction_id: id,
+            number,
+            total,
+        }
+    }
+
+    fn other_id(nanos: i64) -> TransactionId {
+        TransactionId {
+            valid_start: TEST_TX_ID.valid_start + time::Duration::nanoseconds(nanos),
+            ..TEST_TX_ID
+        }
+    }
+
+    #[test]
+    fn out_of_order_and_duplicate_chunks() {
+        let mut reassembler = ChunkReassembler::new(ChunkReassemblyPolicy::new());
+        let now = Instant::now();
+
+        assert!(reassembler.push(chunk(TEST_TX_ID, 3, 3), now).is_none());
+        assert!(reassembler.push(chunk(TEST_TX_ID, 1, 3), now).is_none());
+        assert!(reassembler.push(chunk(TEST_TX_ID, 1, 3), now).is_none());
+        // a chunk that disagrees about the total is ignored.
+        assert!(reassembler.push(chunk(TEST_TX_ID, 2, 4), now).is_none());
+
+        let message = reassembler.push(chunk(TEST_TX_ID, 2, 3), now).unwrap();
+
+        expect!["1:2:3:"].assert_eq(&String::from_utf8(message.contents).unwrap());
+
+        // a late duplicate of a finished message doesn't start a new one.
+        assert!(reassembler.push(chunk(TEST_TX_ID, 1, 3), now).is_none());
+        assert_eq!(reassembler.next_deadline(), None);
+    }
+
+    #[test]
+    fn malformed_first_chunk_is_not_pending() {
+        let mut reassembler = ChunkReassembler::new(ChunkReassemblyPolicy::new());
+        let now = Instant::now();
+
+        assert!(reassembler.push(chunk(TEST_TX_ID, 4, 3), now).is_none());
+        assert!(reassembler.push(chunk(other_id(1), 0, 3), now).is_none());
+
+        assert_eq!(reassembler.next_deadline(), None);
+
+        reassembler.expire(now + ChunkReassemblyPolicy::DEFAULT_TIMEOUT);
+        assert!(reassembler.take_incomplete().is_none());
+    }
+
+    #[test]
+    fn timeout() {
+        let mut policy = ChunkReassemblyPolicy::new();
+        policy.timeout(Duration::from_secs(10));
+
+        let mut reassembler = ChunkReassembler::new(policy);
+        let now = Instant::now();
+
+        reassembler.push(chunk(TEST_TX_ID, 2, 3), now);
+
+        assert_eq!(reassembler.next_deadline(), Some(now + Duration::from_secs(10)));
+
+        reassembler.expire(now + Duration::from_secs(9));
+        assert!(reassembler.take_incomplete().is_none());
+
+        reassembler.expire(now + Duration::from_secs(10));
+
+        let incomplete = reassembler.take_incomplete().unwrap();
+
+        assert_eq!(incomplete.transaction, TEST_TX_ID);
+        assert_eq!(incomplete.total, 3);
+        assert_eq!(incomplete.reason, IncompleteTopicMessageReason::TimedOut);
+        assert_eq!(incomplete.chunks.len(), 1);
+        assert_eq!(incomplete.chunks[0].number, 2);
+        assert_eq!(incomplete.chunks[0].contents, b"2:");
+
+        // the rest of the message arriving late doesn't resurrect it.
+        assert!(reassembler.push(chunk(TEST_TX_ID, 1, 3), now).is_none());
+        assert_eq!(reassembler.next_deadline(), None);
+    }
+
+    #[test]
+    fn max_pending_messages() {
+        let mut policy = ChunkReassemblyPolicy::new();
+        policy.max_pending_messages(2);
+
+        let mut reassembler = ChunkReassembler::new(policy);
+        let now = Instant::now();
+
+        reassembler.push(chunk(other_id(1), 1, 2), now);
+        reassembler.push(chunk(other_id(2), 1, 2), now);
+        reassembler.push(chunk(other_id(3), 1, 2), now);
+
+        let incomplete = reassembler.take_incomplete().unwrap();
+
+        assert_eq!(incomplete.transaction, other_id(1));
+        assert_eq!(incomplete.reason, IncompleteTopicMessageReason::PendingLimitExceeded);
+        assert!(reassembler.take_incomplete().is_none());
+
+        assert!(reassembler.push(chunk(other_id(3), 2, 2), now).is_some());
+    }
+
+    #[test]
+    fn max_pending_bytes() {
+        let mut policy = ChunkReassemblyPolicy::new();
+        policy.max_pending_bytes(4);
+
+        let mut reassembler = ChunkReassembler::new(policy);
+        let now = Instant::now();
+
+        reassembler.push(chunk(other_id(1), 1, 3), now);
+        reassembler.push(chunk(other_id(1), 2, 3), now);
+        assert!(reassembler.take_incomplete().is_none());
+
+        // 6 bytes pending, the oldest message goes.
+        reassembler.push(chunk(other_id(2), 1, 2), now);
+
+        let incomplete = reassembler.take_incomplete().unwrap();
+
+        assert_eq!(incomplete.transaction, other_id(1));
+        assert_eq!(incomplete.chunks.len(), 2);
+        assert!(reassembler.push(chunk(other_id(2), 2, 2), now).is_some());
+    }
+}
@@ -18,6 +18,10 @@
  * ‍
  */
 
+mod chunk_reassembly;
+mod multi_topic_subscription;
+mod running_hash;
+mod subscription_checkpoint;
 mod topic_create_transaction;
 mod topic_delete_transaction;
 mod topic_id;
@@ -28,6 +32,13 @@ mod topic_message_query;
 mod topic_message_submit_transaction;
 mod topic_update_transaction;
 
+pub use chunk_reassembly::ChunkReassemblyPolicy;
+pub use multi_topic_subscription::MultiTopicSubscription;
+pub use subscription_checkpoint::{
+    FileSubscriptionCheckpoint,
+    SubscriptionCheckpoint,
+    TopicCheckpoint,
+};
 pub use topic_create_transaction::TopicCreateTransaction;
 pub(crate) use topic_create_transaction::TopicCreateTransactionData;
 pub use topic_delete_transaction::TopicDeleteTransaction;
@@ -36,7 +47,13 @@ pub use topic_id::TopicId;
 pub use topic_info::TopicInfo;
 pub use topic_info_query::TopicInfoQuery;
 pub(crate) use topic_info_query::TopicInfoQueryData;
-pub use topic_message::TopicMessage;
+pub use topic_message::{
+    IncompleteTopicMessage,
+    IncompleteTopicMessageChunk,
+    IncompleteTopicMessageReason,
+    TopicMessage,
+    TopicMessageItem,
+};
 pub use topic_message_query::TopicMessageQuery;
 pub(crate) use topic_message_query::TopicMessageQueryData;
 pub use topic_message_submit_transaction::TopicMessageSubmitTransaction;
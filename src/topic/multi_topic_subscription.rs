@@ -0,0 +1,253 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+use std::pin::Pin;
+use std::task::{
+    Context,
+    Poll,
+    Waker,
+};
+
+use futures_core::stream::BoxStream;
+use futures_core::Stream;
+use futures_util::StreamExt;
+use time::OffsetDateTime;
+
+use crate::{
+    Client,
+    SubscriptionHandle,
+    TopicId,
+    TopicMessageItem,
+    TopicMessageQuery,
+};
+
+/// A subscription to the messages of several topics, merged into a single stream.
+///
+/// Created by [`TopicMessageQuery::subscribe_topics`]. Every topic is subscribed with the settings
+/// of the query it was created from, and all of them share the client's mirror network channel.
+///
+/// Each item is tagged with the topic it belongs to. Messages of the same topic are yielded in order,
+/// while busy topics are interleaved with the others so none of them are starved.
+///
+/// The stream stays open while it has no topics, so that topics can be added later.
+pub struct MultiTopicSubscription<'a> {
+    client: &'a Client,
+    query: TopicMessageQuery,
+    topics: Vec<TopicStream<'a>>,
+
+    // the topic to poll first, rotated after every item.
+    next: usize,
+    waker: Option<Waker>,
+}
+
+struct TopicStream<'a> {
+    topic_id: TopicId,
+    stream: BoxStream<'a, crate::Result<TopicMessageItem>>,
+    handle: SubscriptionHandle,
+    finished: bool,
+}
+
+impl fmt::Debug for MultiTopicSubscription<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MultiTopicSubscription")
+            .field("query", &self.query)
+            .field("topics", &self.get_topics())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> MultiTopicSubscription<'a> {
+    pub(super) fn new(client: &'a Client, query: TopicMessageQuery) -> Self {
+        Self { client, query, topics: Vec::new(), next: 0, waker: None }
+    }
+
+    /// Returns the topics that are currently subscribed to.
+    #[must_use]
+    pub fn get_topics(&self) -> Vec<TopicId> {
+        self.topics.iter().filter(|it| !it.finished).map(|it| it.topic_id).collect()
+    }
+
+    /// Subscribe to `topic_id`, starting at `start_time`, or the query's start time if `None`.
+    ///
+    /// If the topic is already subscribed to, its subscription is restarted.
+    /// The returned handle controls only this topic's subscription.
+    pub fn add_topic(
+        &mut self,
+        topic_id: TopicId,
+        start_time: Option<OffsetDateTime>,
+    ) -> SubscriptionHandle {
+        let mut query = self.query.clone();
+        query.topic_id(topic_id);
+
+        if let Some(start_time) = start_time {
+            query.start_time(start_time);
+        }
+
+        let (stream, handle) = query.subscribe_with_handle(self.client);
+
+        self.insert(topic_id, stream, handle.clone());
+
+        handle
+    }
+
+    /// Stop receiving messages for `topic_id`.
+    ///
+    /// Returns `false` if the topic wasn't subscribed to.
+    pub fn remove_topic(&mut self, topic_id: TopicId) -> bool {
+        let Some(index) = self.topics.iter().position(|it| it.topic_id == topic_id) else {
+            return false;
+        };
+
+        let topic = self.topics.remove(index);
+        topic.handle.cancel("topic removed from subscription");
+
+        !topic.finished
+    }
+
+    fn insert(
+        &mut self,
+        topic_id: TopicId,
+        stream: BoxStream<'a, crate::Result<TopicMessageItem>>,
+        handle: SubscriptionHandle,
+    ) {
+        self.remove_topic(topic_id);
+
+        self.topics.push(TopicStream { topic_id, stream, handle, finished: false });
+
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+// nothing is ever pinned in place, the streams are boxed and the query is only cloned.
+impl Unpin for MultiTopicSubscription<'_> {}
+
+impl Stream for MultiTopicSubscription<'_> {
+    type Item = (TopicId, crate::Result<TopicMessageItem>);
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        this.topics.retain(|it| !it.finished);
+
+        let len = this.topics.len();
+
+        for offset in 0..len {
+            let index = (this.next + offset) % len;
+            let topic = &mut this.topics[index];
+
+            match topic.stream.poll_next_unpin(cx) {
+                Poll::Ready(Some(item)) => {
+                    this.next = (index + 1) % len;
+                    return Poll::Ready(Some((topic.topic_id, item)));
+                }
+
+                // removed on the next poll, so that the indices stay valid here.
+                Poll::Ready(None) => topic.finished = true,
+
+                Poll::Pending => {}
+            }
+        }
+
+        this.waker = Some(cx.waker().clone());
+
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::{
+        FutureExt,
+        StreamExt,
+    };
+
+    use super::MultiTopicSubscription;
+    use crate::{
+        Client,
+        SubscriptionHandle,
+        TopicId,
+        TopicMessage,
+        TopicMessageItem,
+        TopicMessageQuery,
+    };
+
+    fn message(sequence_number: u64) -> crate::Result<TopicMessageItem> {
+        Ok(TopicMessageItem::Message(TopicMessage {
+            consensus_timestamp: time::OffsetDateTime::UNIX_EPOCH,
+            contents: Vec::new(),
+            running_hash: Vec::new(),
+            running_hash_version: 3,
+            sequence_number,
+            chunks: None,
+            transaction: None,
+        }))
+    }
+
+    fn insert(subscription: &mut MultiTopicSubscription<'_>, topic: u64, messages: Vec<u64>) {
+        let stream = futures_util::stream::iter(messages.into_iter().map(message));
+
+        subscription.insert(TopicId::from(topic), stream.boxed(), SubscriptionHandle::new().0);
+    }
+
+    fn sequence_numbers(items: Vec<(TopicId, crate::Result<TopicMessageItem>)>) -> Vec<(u64, u64)> {
+        items
+            .into_iter()
+            .map(|(topic, it)| (topic.num, it.unwrap().into_message().unwrap().sequence_number))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn merges_topics_in_order() {
+        let client = Client::for_testnet();
+        let mut subscription = MultiTopicSubscription::new(&client, TopicMessageQuery::new());
+
+        insert(&mut subscription, 1, vec![1, 2, 3]);
+        insert(&mut subscription, 2, vec![1, 2]);
+
+        let items = subscription.by_ref().take(5).collect::<Vec<_>>().await;
+
+        assert_eq!(sequence_numbers(items), [(1, 1), (2, 1), (1, 2), (2, 2), (1, 3)]);
+    }
+
+    #[tokio::test]
+    async fn add_and_remove_topics_while_running() {
+        let client = Client::for_testnet();
+        let mut subscription = MultiTopicSubscription::new(&client, TopicMessageQuery::new());
+
+        insert(&mut subscription, 1, vec![1, 2]);
+
+        let first = subscription.next().await.unwrap();
+        assert_eq!(sequence_numbers(vec![first]), [(1, 1)]);
+
+        insert(&mut subscription, 2, vec![1]);
+        assert!(subscription.remove_topic(TopicId::from(1)));
+        assert!(!subscription.remove_topic(TopicId::from(3)));
+
+        let second = subscription.next().await.unwrap();
+        assert_eq!(sequence_numbers(vec![second]), [(2, 1)]);
+
+        // finished topics are dropped, but the subscription stays open for new ones.
+        assert!(subscription.next().now_or_never().is_none());
+        assert!(subscription.get_topics().is_empty());
+    }
+}
@@ -0,0 +1,334 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::mirror;
+use sha2::{
+    Digest,
+    Sha384,
+};
+
+use crate::{
+    AccountId,
+    Error,
+    FromProtobuf,
+    TopicId,
+    TransactionId,
+};
+
+/// The running hash version this module knows how to compute.
+pub(crate) const RUNNING_HASH_VERSION: u64 = 3;
+
+/// Computes the version 3 running hash of a topic after a message.
+///
+/// The consensus node serializes the inputs with Java's `ObjectOutputStream`, so they're hashed here
+/// in the same framing: the stream header, a single block data record, and then
+/// `previous || version || payer || topic || seconds || nanos || sequence number || SHA-384(message)`,
+/// with each entity ID written as its shard, realm and number.
+pub(crate) fn running_hash_v3(
+    previous: &[u8; 48],
+    payer: AccountId,
+    topic_id: TopicId,
+    consensus_seconds: i64,
+    consensus_nanos: i32,
+    sequence_number: u64,
+    message: &[u8],
+) -> [u8; 48] {
+    // `STREAM_MAGIC`, `STREAM_VERSION`
+    const STREAM_HEADER: [u8; 4] = [0xac, 0xed, 0x00, 0x05];
+    // `TC_BLOCKDATA`, for blocks of at most 255 bytes.
+    const TC_BLOCKDATA: u8 = 0x77;
+    // the inputs are fixed size, so they always fit in one block.
+    const LEN: u8 = 48 + 8 + 6 * 8 + 8 + 4 + 8 + 48;
+
+    let mut data = Vec::with_capacity(LEN.into());
+
+    data.extend_from_slice(previous);
+    data.extend_from_slice(&RUNNING_HASH_VERSION.to_be_bytes());
+
+    for it in [payer.shard, payer.realm, payer.num, topic_id.shard, topic_id.realm, topic_id.num] {
+        data.extend_from_slice(&it.to_be_bytes());
+    }
+
+    data.extend_from_slice(&consensus_seconds.to_be_bytes());
+    data.extend_from_slice(&consensus_nanos.to_be_bytes());
+    data.extend_from_slice(&sequence_number.to_be_bytes());
+    data.extend_from_slice(&Sha384::digest(message));
+
+    debug_assert_eq!(data.len(), usize::from(LEN));
+
+    let mut hasher = Sha384::new();
+
+    hasher.update(STREAM_HEADER);
+    hasher.update([TC_BLOCKDATA, LEN]);
+    hasher.update(&data);
+
+    hasher.finalize().into()
+}
+
+/// Checks that each message received for a topic continues the running hash chain of the one before it.
+///
+/// A message can only be checked when the previous message's hash is known (or it's the topic's first message),
+/// it uses version 3 hashes, and its payer is known, which the mirror node only reports for chunked messages.
+/// Messages that can't be checked are trusted and the chain continues from their hash, unless `strict` is set,
+/// in which case they're reported with [`Error::TopicRunningHashUnverifiable`].
+///
+/// Every running hash must be 48 bytes (SHA-384), anything else is a mismatch.
+#[derive(Debug)]
+pub(crate) struct RunningHashVerifier {
+    topic_id: TopicId,
+    strict: bool,
+    previous: Option<(u64, [u8; 48])>,
+}
+
+impl RunningHashVerifier {
+    pub(crate) fn new(topic_id: TopicId, strict: bool) -> Self {
+        Self { topic_id, strict, previous: None }
+    }
+
+    pub(crate) fn verify(&mut self, item: &mirror::ConsensusTopicResponse) -> crate::Result<()> {
+        let Ok(running_hash) = <[u8; 48]>::try_from(&item.running_hash[..]) else {
+            return Err(Error::TopicRunningHashMismatch {
+                topic_id: self.topic_id,
+                sequence_number: item.sequence_number,
+            });
+        };
+
+        let previous = match &self.previous {
+            // the chain starts with an all zero hash.
+            None if item.sequence_number == 1 => Some([0; 48]),
+            None => None,
+            Some((sequence_number, _)) if *sequence_number + 1 != item.sequence_number => {
+                return Err(Error::TopicSequenceNumberGap {
+                    topic_id: self.topic_id,
+                    expected: *sequence_number + 1,
+                    actual: item.sequence_number,
+                });
+            }
+            Some((_, hash)) => Some(*hash),
+        };
+
+        let payer = item
+            .chunk_info
+            .as_ref()
+            .and_then(|it| it.initial_transaction_id.clone())
+            .map(TransactionId::from_protobuf)
+            .transpose()?
+            .map(|it| it.account_id);
+
+        if let (Some(previous), Some(payer), Some(timestamp), RUNNING_HASH_VERSION) =
+            (previous, payer, item.consensus_timestamp.as_ref(), item.running_hash_version)
+        {
+            let expected = running_hash_v3(
+                &previous,
+                payer,
+                self.topic_id,
+                timestamp.seconds,
+                timestamp.nanos,
+                item.sequence_number,
+                &item.message,
+            );
+
+            if expected != running_hash {
+                return Err(Error::TopicRunningHashMismatch {
+                    topic_id: self.topic_id,
+                    sequence_number: item.sequence_number,
+                });
+            }
+        } else if self.strict {
+            return Err(Error::TopicRunningHashUnverifiable {
+                topic_id: self.topic_id,
+                sequence_number: item.sequence_number,
+            });
+        }
+
+        self.previous = Some((item.sequence_number, running_hash));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use hedera_proto::{
+        mirror,
+        services,
+    };
+
+    use super::{
+        running_hash_v3,
+        RunningHashVerifier,
+    };
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::{
+        ToProtobuf,
+        TopicId,
+    };
+
+    const TOPIC_ID: TopicId = TopicId::new(0, 0, 1001);
+
+    fn message(sequence_number: u64, previous: &[u8; 48]) -> mirror::ConsensusTopicResponse {
+        let message = format!("message {sequence_number}").into_bytes();
+        let seconds = 1_554_158_542 + sequence_number as i64;
+
+        mirror::ConsensusTopicResponse {
+            consensus_timestamp: Some(services::Timestamp { seconds, nanos: 7 }),
+            running_hash: running_hash_v3(
+                previous,
+                TEST_TX_ID.account_id,
+                TOPIC_ID,
+                seconds,
+                7,
+                sequence_number,
+                &message,
+            )
+            .to_vec(),
+            message,
+            sequence_number,
+            running_hash_version: 3,
+            chunk_info: Some(services::ConsensusMessageChunkInfo {
+                initial_transaction_id: Some(TEST_TX_ID.to_protobuf()),
+                total: 1,
+                number: 1,
+            }),
+        }
+    }
+
+    fn chain(len: u64) -> Vec<mirror::ConsensusTopicResponse> {
+        let mut previous = [0; 48];
+
+        (1..=len)
+            .map(|sequence_number| {
+                let item = message(sequence_number, &previous);
+                previous.copy_from_slice(&item.running_hash);
+                item
+            })
+            .collect()
+    }
+
+    // note: this vector was computed by this implementation, not taken from the network,
+    // so it only guards against regressions (`valid_chain` and friends can't catch framing mistakes).
+    #[test]
+    fn running_hash() {
+        let hash = running_hash_v3(
+            &[0; 48],
+            TEST_TX_ID.account_id,
+            TOPIC_ID,
+            1_554_158_543,
+            7,
+            1,
+            b"hello",
+        );
+
+        expect!["e8dbc7a15511012d0816833de3834865212fff16f65649412428ab722411b18f461e133fa64d5049763ec7d0addd21d6"].assert_eq(&hex::encode(hash));
+    }
+
+    #[test]
+    fn valid_chain() {
+        let mut verifier = RunningHashVerifier::new(TOPIC_ID, false);
+
+        for item in chain(3) {
+            verifier.verify(&item).unwrap();
+        }
+    }
+
+    #[test]
+    fn resumed_chain_trusts_first_message() {
+        let mut verifier = RunningHashVerifier::new(TOPIC_ID, false);
+
+        let mut items = chain(3);
+        items[1].running_hash = vec![1; 48];
+
+        // there's no way to check the second message without the first, but the third is checked against it.
+        verifier.verify(&items[1]).unwrap();
+
+        assert!(matches!(
+            verifier.verify(&items[2]),
+            Err(crate::Error::TopicRunningHashMismatch { sequence_number: 3, .. })
+        ));
+    }
+
+    #[test]
+    fn tampered_message() {
+        let mut verifier = RunningHashVerifier::new(TOPIC_ID, false);
+
+        let mut items = chain(2);
+        items[1].message = b"tampered".to_vec();
+
+        verifier.verify(&items[0]).unwrap();
+
+        expect!["running hash of message 2 on topic `0.0.1001` doesn't follow from the previous message"]
+            .assert_eq(&verifier.verify(&items[1]).unwrap_err().to_string());
+    }
+
+    #[test]
+    fn sequence_number_gap() {
+        let mut verifier = RunningHashVerifier::new(TOPIC_ID, false);
+
+        let items = chain(3);
+
+        verifier.verify(&items[0]).unwrap();
+
+        expect!["expected message 2 on topic `0.0.1001` but received message 3"]
+            .assert_eq(&verifier.verify(&items[2]).unwrap_err().to_string());
+    }
+
+    #[test]
+    fn unknown_payer_is_trusted() {
+        let mut verifier = RunningHashVerifier::new(TOPIC_ID, false);
+
+        let mut items = chain(2);
+        items[1].chunk_info = None;
+        items[1].message = b"unverifiable".to_vec();
+
+        verifier.verify(&items[0]).unwrap();
+        verifier.verify(&items[1]).unwrap();
+    }
+
+    #[test]
+    fn unknown_payer_is_reported_when_strict() {
+        let mut verifier = RunningHashVerifier::new(TOPIC_ID, true);
+
+        let mut items = chain(2);
+        items[1].chunk_info = None;
+
+        verifier.verify(&items[0]).unwrap();
+
+        expect!["running hash of message 2 on topic `0.0.1001` can't be verified"]
+            .assert_eq(&verifier.verify(&items[1]).unwrap_err().to_string());
+    }
+
+    #[test]
+    fn wrong_length_is_a_mismatch() {
+        let mut items = chain(2);
+        items[0].running_hash.push(0);
+        items[1].running_hash.truncate(47);
+
+        for mut item in items {
+            // even when the message can't otherwise be checked.
+            item.sequence_number += 10;
+
+            assert!(matches!(
+                RunningHashVerifier::new(TOPIC_ID, false).verify(&item),
+                Err(crate::Error::TopicRunningHashMismatch { .. })
+            ));
+        }
+    }
+}
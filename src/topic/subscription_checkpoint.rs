@@ -0,0 +1,281 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt::Debug;
+use std::io::{
+    self,
+    Write,
+};
+use std::path::{
+    Path,
+    PathBuf,
+};
+
+use parking_lot::Mutex;
+use time::OffsetDateTime;
+
+use crate::{
+    Error,
+    TopicId,
+};
+
+/// The position of a [`TopicMessageQuery`](crate::TopicMessageQuery) subscription in its topic,
+/// as of the last message it delivered.
+///
+/// While a chunked message is partially received, this is instead the position right before its first chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TopicCheckpoint {
+    /// The consensus timestamp of the last delivered message.
+    pub consensus_timestamp: OffsetDateTime,
+
+    /// The sequence number of the last delivered message.
+    pub sequence_number: u64,
+}
+
+/// Durable storage for the progress of [`TopicMessageQuery`](crate::TopicMessageQuery) subscriptions.
+///
+/// A query configured with a checkpoint saves each message as it's delivered, and on (re)start resumes
+/// right after the saved message, ignoring any message with a sequence number that was already delivered.
+pub trait SubscriptionCheckpoint: Debug + Send + Sync {
+    /// Load the checkpoint for `topic_id`, `None` if there isn't one yet.
+    ///
+    /// # Errors
+    /// - [`Error::SubscriptionCheckpoint`] if the checkpoint can't be read.
+    fn load(&self, topic_id: TopicId) -> crate::Result<Option<TopicCheckpoint>>;
+
+    /// Save `checkpoint` for `topic_id`, replacing any previous checkpoint.
+    ///
+    /// # Errors
+    /// - [`Error::SubscriptionCheckpoint`] if the checkpoint can't be written.
+    fn save(&self, topic_id: TopicId, checkpoint: TopicCheckpoint) -> crate::Result<()>;
+}
+
+// lint note: this is for sharing a checkpoint between queries, which is up to the caller.
+#[allow(clippy::disallowed_types)]
+impl<T: SubscriptionCheckpoint + ?Sized> SubscriptionCheckpoint for std::sync::Arc<T> {
+    fn load(&self, topic_id: TopicId) -> crate::Result<Option<TopicCheckpoint>> {
+        T::load(self, topic_id)
+    }
+
+    fn save(&self, topic_id: TopicId, checkpoint: TopicCheckpoint) -> crate::Result<()> {
+        T::save(self, topic_id, checkpoint)
+    }
+}
+
+/// A [`SubscriptionCheckpoint`] that keeps the checkpoints of every topic in a single file.
+///
+/// Each line of the file is `<topic id> <seconds>.<nanos> <sequence number>`,
+/// the file is replaced atomically (and synced to disk) on every save.
+///
+/// Saves block on file IO, [`TopicMessageQuery`](crate::TopicMessageQuery) runs them on tokio's
+/// blocking thread pool.
+#[derive(Debug)]
+pub struct FileSubscriptionCheckpoint {
+    path: PathBuf,
+    // serializes read-modify-write cycles between subscriptions sharing this checkpoint.
+    lock: Mutex<()>,
+}
+
+impl FileSubscriptionCheckpoint {
+    /// Create a checkpoint stored at `path`, the file is created on the first save.
+    #[must_use]
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), lock: Mutex::new(()) }
+    }
+
+    /// Returns the path of the checkpoint file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn read(&self) -> crate::Result<Vec<(TopicId, TopicCheckpoint)>> {
+        let contents = match std::fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(Error::subscription_checkpoint(e)),
+        };
+
+        contents.lines().filter(|it| !it.trim().is_empty()).map(parse_line).collect()
+    }
+}
+
+impl SubscriptionCheckpoint for FileSubscriptionCheckpoint {
+    fn load(&self, topic_id: TopicId) -> crate::Result<Option<TopicCheckpoint>> {
+        let _guard = self.lock.lock();
+
+        Ok(self.read()?.into_iter().find(|(id, _)| *id == topic_id).map(|(_, it)| it))
+    }
+
+    fn save(&self, topic_id: TopicId, checkpoint: TopicCheckpoint) -> crate::Result<()> {
+        let _guard = self.lock.lock();
+
+        let mut entries = self.read()?;
+
+        match entries.iter_mut().find(|(id, _)| *id == topic_id) {
+            Some((_, entry)) => *entry = checkpoint,
+            None => entries.push((topic_id, checkpoint)),
+        }
+
+        let contents: String = entries
+            .iter()
+            .map(|(topic_id, it)| {
+                format!(
+                    "{topic_id} {}.{:09} {}\n",
+                    it.consensus_timestamp.unix_timestamp(),
+                    it.consensus_timestamp.nanosecond(),
+                    it.sequence_number
+                )
+            })
+            .collect();
+
+        // write to a sibling and rename over the original so a crash never leaves a torn file behind.
+        let mut tmp = self.path.clone().into_os_string();
+        tmp.push(".tmp");
+
+        write_durably(tmp.as_ref(), contents.as_bytes(), &self.path)
+            .map_err(Error::subscription_checkpoint)
+    }
+}
+
+/// Writes `contents` to `tmp` and renames it over `path`, syncing both the file and (where supported)
+/// the directory so that the new contents survive a crash once this returns.
+fn write_durably(tmp: &Path, contents: &[u8], path: &Path) -> io::Result<()> {
+    let mut file = std::fs::File::create(tmp)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+
+    std::fs::rename(tmp, path)?;
+
+    // directories can't be opened (let alone synced) as files on windows.
+    #[cfg(unix)]
+    if let Some(dir) = path.parent() {
+        let dir = if dir.as_os_str().is_empty() { Path::new(".") } else { dir };
+        std::fs::File::open(dir)?.sync_all()?;
+    }
+
+    Ok(())
+}
+
+fn parse_line(line: &str) -> crate::Result<(TopicId, TopicCheckpoint)> {
+    let malformed =
+        || Error::subscription_checkpoint(format!("malformed checkpoint line `{line}`"));
+
+    let mut parts = line.split_whitespace();
+
+    let (Some(topic_id), Some(timestamp), Some(sequence_number), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(malformed());
+    };
+
+    let (seconds, nanos) = timestamp.split_once('.').ok_or_else(malformed)?;
+
+    let seconds: i64 = seconds.parse().map_err(|_| malformed())?;
+    let nanos: u32 = nanos.parse().map_err(|_| malformed())?;
+
+    let consensus_timestamp = OffsetDateTime::from_unix_timestamp(seconds)
+        .ok()
+        .and_then(|it| it.replace_nanosecond(nanos).ok())
+        .ok_or_else(malformed)?;
+
+    Ok((
+        topic_id.parse().map_err(|_| malformed())?,
+        TopicCheckpoint {
+            consensus_timestamp,
+            sequence_number: sequence_number.parse().map_err(|_| malformed())?,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use time::OffsetDateTime;
+
+    use super::{
+        FileSubscriptionCheckpoint,
+        SubscriptionCheckpoint,
+        TopicCheckpoint,
+    };
+    use crate::TopicId;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("hedera-checkpoint-{name}-{}", std::process::id()));
+
+        let _ = std::fs::remove_file(&path);
+
+        path
+    }
+
+    #[test]
+    fn load_missing_file() {
+        let checkpoint = FileSubscriptionCheckpoint::new(temp_path("missing"));
+
+        assert_eq!(checkpoint.load(TopicId::new(0, 0, 1001)).unwrap(), None);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = temp_path("save");
+        let checkpoint = FileSubscriptionCheckpoint::new(&path);
+
+        let first = TopicCheckpoint {
+            consensus_timestamp: OffsetDateTime::from_unix_timestamp_nanos(
+                1_700_000_000_000_000_001,
+            )
+            .unwrap(),
+            sequence_number: 7,
+        };
+
+        let second = TopicCheckpoint { sequence_number: 8, ..first };
+
+        checkpoint.save(TopicId::new(0, 0, 1001), first).unwrap();
+        checkpoint.save(TopicId::new(0, 0, 1002), first).unwrap();
+        checkpoint.save(TopicId::new(0, 0, 1001), second).unwrap();
+
+        // a fresh instance sees what the previous one wrote.
+        let checkpoint = FileSubscriptionCheckpoint::new(&path);
+
+        assert_eq!(checkpoint.load(TopicId::new(0, 0, 1001)).unwrap(), Some(second));
+        assert_eq!(checkpoint.load(TopicId::new(0, 0, 1002)).unwrap(), Some(first));
+        assert_eq!(checkpoint.load(TopicId::new(0, 0, 1003)).unwrap(), None);
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "0.0.1001 1700000000.000000001 8\n0.0.1002 1700000000.000000001 7\n"
+        );
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn malformed_file() {
+        let path = temp_path("malformed");
+        std::fs::write(&path, "0.0.1001 nonsense\n").unwrap();
+
+        let error = FileSubscriptionCheckpoint::new(&path).load(TopicId::new(0, 0, 1001));
+
+        assert!(matches!(error, Err(crate::Error::SubscriptionCheckpoint(_))));
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -40,6 +40,7 @@ use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
+    FixedFee,
     Key,
     Transaction,
     ValidateChecksums,
@@ -73,6 +74,15 @@ pub struct TopicCreateTransactionData {
 
     /// Account to be used at the topic's expiration time to extend the life of the topic.
     auto_renew_account_id: Option<AccountId>,
+
+    /// Access control for updating the topic's custom fees.
+    fee_schedule_key: Option<Key>,
+
+    /// Keys whose signatures exempt a `TopicMessageSubmitTransaction` from the topic's custom fees.
+    fee_exempt_keys: Vec<Key>,
+
+    /// The fixed fees to assess when a message is submitted to the topic.
+    custom_fees: Vec<FixedFee>,
 }
 
 impl Default for TopicCreateTransactionData {
@@ -83,6 +93,9 @@ impl Default for TopicCreateTransactionData {
             submit_key: None,
             auto_renew_period: Some(Duration::days(90)),
             auto_renew_account_id: None,
+            fee_schedule_key: None,
+            fee_exempt_keys: Vec::new(),
+            custom_fees: Vec::new(),
         }
     }
 }
@@ -153,6 +166,58 @@ impl TopicCreateTransaction {
         self.data_mut().auto_renew_account_id = Some(id);
         self
     }
+
+    /// Returns the access control for updating the topic's custom fees.
+    #[must_use]
+    pub fn get_fee_schedule_key(&self) -> Option<&Key> {
+        self.data().fee_schedule_key.as_ref()
+    }
+
+    /// Sets the access control for updating the topic's custom fees.
+    pub fn fee_schedule_key(&mut self, key: impl Into<Key>) -> &mut Self {
+        self.data_mut().fee_schedule_key = Some(key.into());
+        self
+    }
+
+    /// Returns the keys that are exempt from paying the topic's custom fees.
+    #[must_use]
+    pub fn get_fee_exempt_keys(&self) -> &[Key] {
+        &self.data().fee_exempt_keys
+    }
+
+    /// Sets the keys that are exempt from paying the topic's custom fees.
+    ///
+    /// A message submitted with a signature from any of these keys is not charged custom fees.
+    pub fn fee_exempt_keys(&mut self, keys: impl IntoIterator<Item = Key>) -> &mut Self {
+        self.data_mut().fee_exempt_keys = keys.into_iter().collect();
+        self
+    }
+
+    /// Adds a key to the keys that are exempt from paying the topic's custom fees.
+    pub fn add_fee_exempt_key(&mut self, key: impl Into<Key>) -> &mut Self {
+        self.data_mut().fee_exempt_keys.push(key.into());
+        self
+    }
+
+    /// Returns the fixed fees to assess when a message is submitted to the topic.
+    #[must_use]
+    pub fn get_custom_fees(&self) -> &[FixedFee] {
+        &self.data().custom_fees
+    }
+
+    /// Sets the fixed fees to assess when a message is submitted to the topic.
+    ///
+    /// Topic fees have no exempt collectors, `all_collectors_are_exempt` isn't sent to the network.
+    pub fn custom_fees(&mut self, fees: impl IntoIterator<Item = FixedFee>) -> &mut Self {
+        self.data_mut().custom_fees = fees.into_iter().collect();
+        self
+    }
+
+    /// Adds a fixed fee to assess when a message is submitted to the topic.
+    pub fn add_custom_fee(&mut self, fee: FixedFee) -> &mut Self {
+        self.data_mut().custom_fees.push(fee);
+        self
+    }
 }
 
 impl TransactionData for TopicCreateTransactionData {}
@@ -206,6 +271,9 @@ impl FromProtobuf<services::ConsensusCreateTopicTransactionBody> for TopicCreate
             submit_key: Option::from_protobuf(pb.submit_key)?,
             auto_renew_period: pb.auto_renew_period.map(Into::into),
             auto_renew_account_id: Option::from_protobuf(pb.auto_renew_account)?,
+            fee_schedule_key: Option::from_protobuf(pb.fee_schedule_key)?,
+            fee_exempt_keys: Vec::from_protobuf(pb.fee_exempt_key_list)?,
+            custom_fees: Vec::from_protobuf(pb.custom_fees)?,
         })
     }
 }
@@ -220,6 +288,9 @@ impl ToProtobuf for TopicCreateTransactionData {
             admin_key: self.admin_key.to_protobuf(),
             submit_key: self.submit_key.to_protobuf(),
             auto_renew_period: self.auto_renew_period.to_protobuf(),
+            fee_schedule_key: self.fee_schedule_key.to_protobuf(),
+            fee_exempt_key_list: self.fee_exempt_keys.to_protobuf(),
+            custom_fees: self.custom_fees.to_protobuf(),
         }
     }
 }
@@ -243,7 +314,11 @@ mod tests {
     use crate::{
         AccountId,
         AnyTransaction,
+        FixedFee,
+        FixedFeeData,
+        Key,
         PublicKey,
+        TokenId,
         TopicCreateTransaction,
     };
 
@@ -254,6 +329,14 @@ mod tests {
     const AUTO_RENEW_ACCOUNT_ID: AccountId = AccountId::new(0, 0, 5007);
     const AUTO_RENEW_PERIOD: Duration = Duration::days(1);
 
+    fn custom_fees() -> Vec<FixedFee> {
+        vec![FixedFee {
+            fee: FixedFeeData { amount: 1, denominating_token_id: Some(TokenId::new(0, 0, 5009)) },
+            fee_collector_account_id: Some(AccountId::new(0, 0, 5008)),
+            all_collectors_are_exempt: false,
+        }]
+    }
+
     fn make_transaction() -> TopicCreateTransaction {
         let mut tx = TopicCreateTransaction::new_for_tests();
 
@@ -261,6 +344,9 @@ mod tests {
             .admin_key(key())
             .auto_renew_account_id(AUTO_RENEW_ACCOUNT_ID)
             .auto_renew_period(AUTO_RENEW_PERIOD)
+            .fee_schedule_key(key())
+            .add_fee_exempt_key(key())
+            .custom_fees(custom_fees())
             .freeze()
             .unwrap();
 
@@ -379,6 +465,117 @@ mod tests {
                             ),
                         },
                     ),
+                    fee_schedule_key: Some(
+                        Key {
+                            key: Some(
+                                Ed25519(
+                                    [
+                                        224,
+                                        200,
+                                        236,
+                                        39,
+                                        88,
+                                        165,
+                                        135,
+                                        159,
+                                        250,
+                                        194,
+                                        38,
+                                        161,
+                                        60,
+                                        12,
+                                        81,
+                                        107,
+                                        121,
+                                        158,
+                                        114,
+                                        227,
+                                        81,
+                                        65,
+                                        160,
+                                        221,
+                                        130,
+                                        143,
+                                        148,
+                                        211,
+                                        121,
+                                        136,
+                                        164,
+                                        183,
+                                    ],
+                                ),
+                            ),
+                        },
+                    ),
+                    fee_exempt_key_list: [
+                        Key {
+                            key: Some(
+                                Ed25519(
+                                    [
+                                        224,
+                                        200,
+                                        236,
+                                        39,
+                                        88,
+                                        165,
+                                        135,
+                                        159,
+                                        250,
+                                        194,
+                                        38,
+                                        161,
+                                        60,
+                                        12,
+                                        81,
+                                        107,
+                                        121,
+                                        158,
+                                        114,
+                                        227,
+                                        81,
+                                        65,
+                                        160,
+                                        221,
+                                        130,
+                                        143,
+                                        148,
+                                        211,
+                                        121,
+                                        136,
+                                        164,
+                                        183,
+                                    ],
+                                ),
+                            ),
+                        },
+                    ],
+                    custom_fees: [
+                        FixedCustomFee {
+                            fixed_fee: Some(
+                                FixedFee {
+                                    amount: 1,
+                                    denominating_token_id: Some(
+                                        TokenId {
+                                            shard_num: 0,
+                                            realm_num: 0,
+                                            token_num: 5009,
+                                        },
+                                    ),
+                                },
+                            ),
+                            fee_collector_account_id: Some(
+                                AccountId {
+                                    shard_num: 0,
+                                    realm_num: 0,
+                                    account: Some(
+                                        AccountNum(
+                                            5008,
+                                        ),
+                                    ),
+                                },
+                            ),
+                        },
+                    ],
                 },
             )
         "#]]
@@ -406,6 +603,9 @@ mod tests {
             submit_key: Some(key().to_protobuf()),
             auto_renew_period: Some(AUTO_RENEW_PERIOD.to_protobuf()),
             auto_renew_account: Some(AUTO_RENEW_ACCOUNT_ID.to_protobuf()),
+            fee_schedule_key: Some(key().to_protobuf()),
+            fee_exempt_key_list: vec![key().to_protobuf()],
+            custom_fees: custom_fees().to_protobuf(),
         };
 
         let tx = TopicCreateTransactionData::from_protobuf(tx).unwrap();
@@ -414,6 +614,9 @@ mod tests {
         assert_eq!(tx.submit_key, Some(key().into()));
         assert_eq!(tx.auto_renew_period, Some(AUTO_RENEW_PERIOD));
         assert_eq!(tx.auto_renew_account_id, Some(AUTO_RENEW_ACCOUNT_ID));
+        assert_eq!(tx.fee_schedule_key, Some(key().into()));
+        assert_eq!(tx.fee_exempt_keys, vec![Key::from(key())]);
+        assert_eq!(tx.custom_fees, custom_fees());
     }
 
     #[test]
@@ -471,4 +674,46 @@ mod tests {
     fn get_set_auto_renew_account_id_frozen_panics() {
         make_transaction().auto_renew_account_id(AUTO_RENEW_ACCOUNT_ID);
     }
+
+    #[test]
+    fn get_set_fee_schedule_key() {
+        let mut tx = TopicCreateTransaction::new();
+        tx.fee_schedule_key(key());
+
+        assert_eq!(tx.get_fee_schedule_key(), Some(&key().into()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_fee_schedule_key_frozen_panics() {
+        make_transaction().fee_schedule_key(key());
+    }
+
+    #[test]
+    fn get_set_fee_exempt_keys() {
+        let mut tx = TopicCreateTransaction::new();
+        tx.fee_exempt_keys([Key::from(key())]).add_fee_exempt_key(key());
+
+        assert_eq!(tx.get_fee_exempt_keys(), &[Key::from(key()), Key::from(key())]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_fee_exempt_keys_frozen_panics() {
+        make_transaction().add_fee_exempt_key(key());
+    }
+
+    #[test]
+    fn get_set_custom_fees() {
+        let mut tx = TopicCreateTransaction::new();
+        tx.custom_fees(custom_fees());
+
+        assert_eq!(tx.get_custom_fees(), custom_fees());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_custom_fees_frozen_panics() {
+        make_transaction().custom_fees(custom_fees());
+    }
 }
@@ -27,6 +27,7 @@ use time::{
 use crate::protobuf::ToProtobuf;
 use crate::{
     AccountId,
+    FixedFee,
     FromProtobuf,
     Key,
     LedgerId,
@@ -35,7 +36,7 @@ use crate::{
 
 /// Response from [`TopicInfoQuery`][crate::TopicInfoQuery].
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TopicInfo {
     /// The ID of the topic for which information is requested.
     pub topic_id: TopicId,
@@ -65,6 +66,15 @@ pub struct TopicInfo {
     /// The interval at which the auto-renew account will be charged to extend the topic's expiry.
     pub auto_renew_period: Option<Duration>,
 
+    /// Access control for updating the topic's custom fees.
+    pub fee_schedule_key: Option<Key>,
+
+    /// Keys whose signatures exempt a submitted message from the topic's custom fees.
+    pub fee_exempt_keys: Vec<Key>,
+
+    /// The fixed fees assessed when a message is submitted to the topic.
+    pub custom_fees: Vec<FixedFee>,
+
     /// The ledger ID the response was returned from
     pub ledger_id: LedgerId,
 }
@@ -108,6 +118,9 @@ impl FromProtobuf<services::ConsensusGetTopicInfoResponse> for TopicInfo {
         let expiration_time = info.expiration_time.map(Into::into);
         let auto_renew_period = info.auto_renew_period.map(Into::into);
         let auto_renew_account_id = Option::from_protobuf(info.auto_renew_account)?;
+        let fee_schedule_key = Option::from_protobuf(info.fee_schedule_key)?;
+        let fee_exempt_keys = Vec::from_protobuf(info.fee_exempt_key_list)?;
+        let custom_fees = Vec::from_protobuf(info.custom_fees)?;
         let ledger_id = LedgerId::from_bytes(info.ledger_id);
 
         Ok(Self {
@@ -120,6 +133,9 @@ impl FromProtobuf<services::ConsensusGetTopicInfoResponse> for TopicInfo {
             sequence_number: info.sequence_number,
             expiration_time,
             topic_memo: info.memo,
+            fee_schedule_key,
+            fee_exempt_keys,
+            custom_fees,
             ledger_id,
         })
     }
@@ -141,6 +157,9 @@ impl ToProtobuf for TopicInfo {
                 auto_renew_period: self.auto_renew_period.to_protobuf(),
                 auto_renew_account: self.auto_renew_account_id.to_protobuf(),
                 ledger_id: self.ledger_id.to_bytes(),
+                fee_schedule_key: self.fee_schedule_key.to_protobuf(),
+                fee_exempt_key_list: self.fee_exempt_keys.to_protobuf(),
+                custom_fees: self.custom_fees.to_protobuf(),
             }),
             header: None,
         }
@@ -181,6 +200,16 @@ mod tests {
                     account: Some(services::account_id::Account::AccountNum(4)),
                 }),
                 ledger_id: LedgerId::testnet().to_bytes(),
+                fee_schedule_key: None,
+                fee_exempt_key_list: Vec::new(),
+                custom_fees: Vec::from([services::FixedCustomFee {
+                    fixed_fee: Some(services::FixedFee { amount: 6, denominating_token_id: None }),
+                    fee_collector_account_id: Some(services::AccountId {
+                        shard_num: 0,
+                        realm_num: 0,
+                        account: Some(services::account_id::Account::AccountNum(7)),
+                    }),
+                }]),
             }),
         }
     }
@@ -217,6 +246,20 @@ mod tests {
                         nanoseconds: 0,
                     },
                 ),
+                fee_schedule_key: None,
+                fee_exempt_keys: [],
+                custom_fees: [
+                    CustomFee {
+                        fee: FixedFeeData {
+                            amount: 6,
+                            denominating_token_id: None,
+                        },
+                        fee_collector_account_id: Some(
+                            "0.0.7",
+                        ),
+                        all_collectors_are_exempt: false,
+                    },
+                ],
                 ledger_id: "testnet",
             }
         "#]]
@@ -351,6 +394,29 @@ mod tests {
                         ledger_id: [
                             1,
                         ],
+                        fee_schedule_key: None,
+                        fee_exempt_key_list: [],
+                        custom_fees: [
+                            FixedCustomFee {
+                                fixed_fee: Some(
+                                    FixedFee {
+                                        amount: 6,
+                                        denominating_token_id: None,
+                                    },
+                                ),
+                                fee_collector_account_id: Some(
+                                    AccountId {
+                                        shard_num: 0,
+                                        realm_num: 0,
+                                        account: Some(
+                                            AccountNum(
+                                                7,
+                                            ),
+                                        ),
+                                    },
+                                ),
+                            },
+                        ],
                     },
                 ),
             }
@@ -390,6 +456,20 @@ mod tests {
                         nanoseconds: 0,
                     },
                 ),
+                fee_schedule_key: None,
+                fee_exempt_keys: [],
+                custom_fees: [
+                    CustomFee {
+                        fee: FixedFeeData {
+                            amount: 6,
+                            denominating_token_id: None,
+                        },
+                        fee_collector_account_id: Some(
+                            "0.0.7",
+                        ),
+                        all_collectors_are_exempt: false,
+                    },
+                ],
                 ledger_id: "testnet",
             }
         "#]]
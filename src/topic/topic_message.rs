@@ -152,6 +152,101 @@ impl TopicMessage {
     }
 }
 
+/// An item yielded by a [`TopicMessageQuery`](crate::TopicMessageQuery) subscription.
+#[derive(Clone, Debug)]
+pub enum TopicMessageItem {
+    /// A message, with all of its chunks.
+    Message(TopicMessage),
+
+    /// A chunked message that was given up on before all of its chunks arrived.
+    ///
+    /// This doesn't end the subscription.
+    Incomplete(IncompleteTopicMessage),
+}
+
+impl TopicMessageItem {
+    /// Returns the message, or `None` if it's incomplete.
+    #[must_use]
+    pub fn into_message(self) -> Option<TopicMessage> {
+        match self {
+            Self::Message(it) => Some(it),
+            Self::Incomplete(_) => None,
+        }
+    }
+}
+
+/// A chunked topic message that was given up on before all of its chunks arrived.
+///
+/// Reported by [`TopicMessageQuery`](crate::TopicMessageQuery) subscriptions as
+/// [`TopicMessageItem::Incomplete`].
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct IncompleteTopicMessage {
+    /// The [`TransactionId`] of the message's first chunk.
+    pub transaction: TransactionId,
+
+    /// The number of chunks the message was split into.
+    pub total: u32,
+
+    /// The chunks that were received, ordered by their number.
+    pub chunks: Vec<IncompleteTopicMessageChunk>,
+
+    /// Why the message was given up on.
+    pub reason: IncompleteTopicMessageReason,
+}
+
+/// A chunk received for an [`IncompleteTopicMessage`].
+#[non_exhaustive]
+#[derive(Clone, Debug)]
+pub struct IncompleteTopicMessageChunk {
+    /// The chunk's position in the message, starting at 1.
+    pub number: u32,
+
+    /// The consensus timestamp for this chunk.
+    pub consensus_timestamp: OffsetDateTime,
+
+    /// Sequence number for this chunk.
+    pub sequence_number: u64,
+
+    /// The new running hash of the topic that received the chunk.
+    pub running_hash: Vec<u8>,
+
+    /// The content of the chunk.
+    pub contents: Vec<u8>,
+}
+
+/// Why an [`IncompleteTopicMessage`] was given up on.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IncompleteTopicMessageReason {
+    /// The rest of the chunks didn't arrive within the configured timeout.
+    TimedOut,
+
+    /// Too many messages (or bytes) were pending, so the oldest pending message was dropped.
+    PendingLimitExceeded,
+}
+
+impl std::fmt::Display for IncompleteTopicMessageReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TimedOut => f.write_str("timed out"),
+            Self::PendingLimitExceeded => f.write_str("pending limit exceeded"),
+        }
+    }
+}
+
+impl From<PbTopicMessageChunk> for IncompleteTopicMessageChunk {
+    fn from(pb: PbTopicMessageChunk) -> Self {
+        Self {
+            number: pb.number.unsigned_abs(),
+            consensus_timestamp: pb.header.consensus_timestamp,
+            sequence_number: pb.header.sequence_number,
+            running_hash: pb.header.running_hash,
+            contents: pb.header.message,
+        }
+    }
+}
+
 pub(crate) struct PbTopicMessageHeader {
     pub(crate) consensus_timestamp: OffsetDateTime,
     pub(crate) sequence_number: u64,
@@ -18,16 +18,17 @@
  * ‍
  */
 
-use std::collections::HashMap;
-use std::{
-    mem,
-    task,
-};
+use std::future::Future;
+use std::pin::Pin;
+use std::task;
 
 use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 use futures_core::Stream;
-use futures_util::TryStreamExt;
+use futures_util::{
+    future,
+    TryStreamExt,
+};
 use hedera_proto::mirror;
 use hedera_proto::mirror::consensus_service_client::ConsensusServiceClient;
 use hedera_proto::mirror::ConsensusTopicQuery;
@@ -37,7 +38,15 @@ use time::{
 };
 use tonic::transport::Channel;
 use tonic::Response;
+use triomphe::Arc;
+use unsize::{
+    CoerceUnsize,
+    Coercion,
+};
 
+use super::chunk_reassembly::ChunkReassembler;
+use super::multi_topic_subscription::MultiTopicSubscription;
+use super::running_hash::RunningHashVerifier;
 use super::topic_message::{
     PbTopicMessageChunk,
     PbTopicMessageHeader,
@@ -50,10 +59,16 @@ use crate::mirror_query::{
 use crate::protobuf::FromProtobuf;
 use crate::{
     AnyMirrorQueryResponse,
+    ChunkReassemblyPolicy,
+    Client,
+    Error,
     MirrorQuery,
+    SubscriptionCheckpoint,
     ToProtobuf,
+    TopicCheckpoint,
     TopicId,
     TopicMessage,
+    TopicMessageItem,
     TransactionId,
 };
 
@@ -65,6 +80,9 @@ use crate::{
 #[derive(Default)]
 pub struct TopicMessageQueryContext {
     start_time: Option<OffsetDateTime>,
+
+    /// The sequence number of the last message received, anything at or before it is a duplicate.
+    last_sequence_number: Option<u64>,
 }
 
 /// Query a stream of Hedera Consensus Service (HCS)
@@ -85,14 +103,43 @@ pub struct TopicMessageQueryData {
 
     /// The maximum number of messages to receive before stopping.
     limit: u64,
+
+    /// Where to persist the subscription's progress, if anywhere.
+    checkpoint: Option<Arc<dyn SubscriptionCheckpoint>>,
+
+    /// Whether to check each message's running hash against the previous message's.
+    verify_running_hash: bool,
+
+    /// Whether messages whose running hash can't be checked end the subscription.
+    strict_running_hash: bool,
+
+    /// Limits on buffering the chunks of chunked messages.
+    chunk_reassembly: ChunkReassemblyPolicy,
 }
 
 impl TopicMessageQueryData {
-    fn map_stream<'a, S>(stream: S) -> impl Stream<Item = crate::Result<TopicMessage>>
+    fn map_stream<'a, S>(&self, stream: S) -> impl Stream<Item = crate::Result<TopicMessageItem>>
     where
         S: Stream<Item = crate::Result<mirror::ConsensusTopicResponse>> + Send + 'a,
     {
-        MessagesMapStream { inner: stream, incomplete_messages: HashMap::new() }
+        let checkpoint = self.topic_id.zip(self.checkpoint.clone());
+
+        let running_hash = match (self.verify_running_hash, self.topic_id) {
+            (true, Some(topic_id)) => {
+                Some(RunningHashVerifier::new(topic_id, self.strict_running_hash))
+            }
+            _ => None,
+        };
+
+        MessagesMapStream {
+            inner: stream,
+            reassembler: ChunkReassembler::new(self.chunk_reassembly),
+            reassembly_timer: None,
+            checkpoint,
+            pending_save: None,
+            running_hash,
+            failed: false,
+        }
     }
 }
 
@@ -146,6 +193,102 @@ impl TopicMessageQuery {
         self.data.limit = limit;
         self
     }
+
+    /// Returns whether each message's running hash is checked against the previous message's.
+    #[must_use]
+    pub fn get_verify_running_hash(&self) -> bool {
+        self.data.verify_running_hash
+    }
+
+    /// Sets whether each message's running hash is checked against the previous message's.
+    ///
+    /// When the chain breaks, because a message was altered or skipped, the subscription yields
+    /// [`Error::TopicRunningHashMismatch`] or [`Error::TopicSequenceNumberGap`] and ends.
+    ///
+    /// Only version 3 running hashes are checked. Since the running hash covers the payer of the message,
+    /// and the mirror node only reports it for chunked messages, other messages are trusted
+    /// (as is the first message received, unless it's the topic's first message).
+    /// Use [`strict_running_hash`](Self::strict_running_hash) to reject them instead.
+    ///
+    /// Defaults to `false`.
+    pub fn verify_running_hash(&mut self, verify: bool) -> &mut Self {
+        self.data.verify_running_hash = verify;
+        self
+    }
+
+    /// Returns whether messages whose running hash can't be checked end the subscription.
+    #[must_use]
+    pub fn get_strict_running_hash(&self) -> bool {
+        self.data.strict_running_hash
+    }
+
+    /// Sets whether messages whose running hash can't be checked end the subscription.
+    ///
+    /// When set (along with [`verify_running_hash`](Self::verify_running_hash)), instead of trusting
+    /// such a message the subscription yields [`Error::TopicRunningHashUnverifiable`] and ends.
+    ///
+    /// Defaults to `false`.
+    pub fn strict_running_hash(&mut self, strict: bool) -> &mut Self {
+        self.data.strict_running_hash = strict;
+        self
+    }
+
+    /// Returns the limits on buffering the chunks of chunked messages.
+    #[must_use]
+    pub fn get_chunk_reassembly(&self) -> ChunkReassemblyPolicy {
+        self.data.chunk_reassembly
+    }
+
+    /// Sets the limits on buffering the chunks of chunked messages.
+    ///
+    /// Messages that exceed them are reported with [`TopicMessageItem::Incomplete`],
+    /// after which the subscription continues. [`execute`](MirrorQuery::execute) skips them.
+    pub fn chunk_reassembly(&mut self, policy: ChunkReassemblyPolicy) -> &mut Self {
+        self.data.chunk_reassembly = policy;
+        self
+    }
+
+    /// Returns the checkpoint the subscription's progress is persisted to.
+    #[must_use]
+    pub fn get_checkpoint(&self) -> Option<&dyn SubscriptionCheckpoint> {
+        self.data.checkpoint.as_deref()
+    }
+
+    /// Sets the checkpoint to persist the subscription's progress to.
+    ///
+    /// Every message is saved to the checkpoint as it's delivered, when a checkpoint already exists
+    /// for the topic the subscription resumes right after it (taking precedence over [`start_time`](Self::start_time)),
+    /// and messages with a sequence number at or before it are never delivered again.
+    ///
+    /// While a chunked message is partially received the checkpoint stays right before its first chunk,
+    /// so messages delivered in the meantime may be delivered again after a restart.
+    ///
+    /// If saving fails the message is still delivered, followed by the error.
+    ///
+    /// To share a checkpoint between queries, pass an [`Arc`](std::sync::Arc) of it.
+    pub fn checkpoint<C: SubscriptionCheckpoint + 'static>(&mut self, checkpoint: C) -> &mut Self {
+        self.data.checkpoint =
+            Some(Arc::new(checkpoint).unsize(Coercion!(to dyn SubscriptionCheckpoint)));
+        self
+    }
+
+    /// Subscribe to several topics at once, each with this query's settings.
+    ///
+    /// Each topic starts at its own start time, or this query's start time if `None`.
+    /// This query's topic ID is ignored.
+    pub fn subscribe_topics<'a>(
+        &self,
+        client: &'a Client,
+        topics: impl IntoIterator<Item = (TopicId, Option<OffsetDateTime>)>,
+    ) -> MultiTopicSubscription<'a> {
+        let mut subscription = MultiTopicSubscription::new(client, self.clone());
+
+        for (topic_id, start_time) in topics {
+            subscription.add_topic(topic_id, start_time);
+        }
+
+        subscription
+    }
 }
 
 impl From<TopicMessageQueryData> for AnyMirrorQueryData {
@@ -161,11 +304,11 @@ impl MirrorRequest for TopicMessageQueryData {
 
     type Context = TopicMessageQueryContext;
 
-    type Item = TopicMessage;
+    type Item = TopicMessageItem;
 
     type Response = Vec<TopicMessage>;
 
-    type ItemStream<'a> = BoxStream<'a, crate::Result<TopicMessage>>;
+    type ItemStream<'a> = BoxStream<'a, crate::Result<TopicMessageItem>>;
 
     fn connect(
         &self,
@@ -199,29 +342,51 @@ impl MirrorRequest for TopicMessageQueryData {
         })
     }
 
-    fn make_item_stream<'a, S>(stream: S) -> Self::ItemStream<'a>
+    fn make_item_stream<'a, S>(&self, stream: S) -> Self::ItemStream<'a>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a,
     {
-        Box::pin(Self::map_stream(stream))
+        Box::pin(self.map_stream(stream))
     }
 
-    fn try_collect<'a, S>(stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
+    fn try_collect<'a, S>(&self, stream: S) -> BoxFuture<'a, crate::Result<Self::Response>>
     where
         S: Stream<Item = crate::Result<Self::GrpcItem>> + Send + 'a,
     {
         // this doesn't reuse the work in `make_item_stream`
-        Box::pin(Self::map_stream(stream).try_collect())
+        Box::pin(
+            self.map_stream(stream)
+                .try_filter_map(|it| future::ready(Ok(it.into_message())))
+                .try_collect(),
+        )
+    }
+
+    fn initial_context(&self) -> crate::Result<Self::Context> {
+        let (Some(topic_id), Some(checkpoint)) = (self.topic_id, &self.checkpoint) else {
+            return Ok(Self::Context::default());
+        };
+
+        let checkpoint = checkpoint.load(topic_id)?;
+
+        Ok(TopicMessageQueryContext {
+            start_time: checkpoint.map(|it| it.consensus_timestamp),
+            last_sequence_number: checkpoint.map(|it| it.sequence_number),
+        })
+    }
+
+    fn should_yield(context: &Self::Context, item: &Self::GrpcItem) -> bool {
+        context.last_sequence_number.is_none_or(|last| item.sequence_number > last)
     }
 
     fn update_context(context: &mut Self::Context, item: &Self::GrpcItem) {
         context.start_time =
             item.consensus_timestamp.map(OffsetDateTime::from).or(context.start_time);
+        context.last_sequence_number = Some(item.sequence_number);
     }
 }
 
-impl From<TopicMessage> for AnyMirrorQueryMessage {
-    fn from(value: TopicMessage) -> Self {
+impl From<TopicMessageItem> for AnyMirrorQueryMessage {
+    fn from(value: TopicMessageItem) -> Self {
         Self::TopicMessage(value)
     }
 }
@@ -232,30 +397,20 @@ impl From<Vec<TopicMessage>> for AnyMirrorQueryResponse {
     }
 }
 
-enum IncompleteMessage {
-    Partial(OffsetDateTime, Vec<PbTopicMessageChunk>),
-    Expired,
-    Complete,
-}
-
-impl IncompleteMessage {
-    fn handle_expiry(&mut self) -> &mut Self {
-        match self {
-            IncompleteMessage::Partial(expiry, _) if *expiry < OffsetDateTime::now_utc() => {
-                *self = Self::Expired;
-            }
-            _ => {}
-        }
-
-        self
-    }
-}
-
 pin_project_lite::pin_project! {
     struct MessagesMapStream<S> {
         #[pin]
         inner: S,
-        incomplete_messages: HashMap<TransactionId, IncompleteMessage>,
+        reassembler: ChunkReassembler,
+        // wakes the stream when the next pending message times out.
+        reassembly_timer: Option<Pin<Box<tokio::time::Sleep>>>,
+        checkpoint: Option<(TopicId, Arc<dyn SubscriptionCheckpoint>)>,
+        // the checkpoint save of the previous message, which runs off the executor and is
+        // finished (yielding its error, if any) before anything else is yielded.
+        pending_save: Option<tokio::task::JoinHandle<crate::Result<()>>>,
+        running_hash: Option<RunningHashVerifier>,
+        // set once the running hash chain breaks, nothing after that can be trusted.
+        failed: bool,
     }
 }
 
@@ -263,7 +418,7 @@ impl<S> Stream for MessagesMapStream<S>
 where
     S: Stream<Item = crate::Result<mirror::ConsensusTopicResponse>> + Send,
 {
-    type Item = crate::Result<TopicMessage>;
+    type Item = crate::Result<TopicMessageItem>;
 
     fn poll_next(
         self: std::pin::Pin<&mut Self>,
@@ -273,15 +428,87 @@ where
 
         let mut this = self.project();
 
+        if let Some(save) = this.pending_save.as_mut() {
+            let result = task::ready!(Pin::new(save).poll(cx));
+            *this.pending_save = None;
+
+            if let Err(e) = result.map_err(Error::subscription_checkpoint).and_then(|it| it) {
+                return Poll::Ready(Some(Err(e)));
+            }
+        }
+
+        if *this.failed {
+            return Poll::Ready(None);
+        }
+
         loop {
-            let item = match task::ready!(this.inner.as_mut().poll_next(cx)) {
-                Some(Ok(item)) => item,
-                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
-                None => return Poll::Ready(None),
+            this.reassembler.expire(tokio::time::Instant::now());
+
+            if let Some(incomplete) = this.reassembler.take_incomplete() {
+                return Poll::Ready(Some(Ok(TopicMessageItem::Incomplete(incomplete))));
+            }
+
+            let item = match this.inner.as_mut().poll_next(cx) {
+                Poll::Ready(Some(Ok(item))) => item,
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => {
+                    let Some(deadline) = this.reassembler.next_deadline() else {
+                        return Poll::Pending;
+                    };
+
+                    let timer = match this.reassembly_timer {
+                        Some(timer) => {
+                            timer.as_mut().reset(deadline);
+                            timer
+                        }
+                        None => this
+                            .reassembly_timer
+                            .insert(Box::pin(tokio::time::sleep_until(deadline))),
+                    };
+
+                    task::ready!(timer.as_mut().poll(cx));
+
+                    // a pending message timed out.
+                    continue;
+                }
             };
 
-            match filter_map(item, this.incomplete_messages) {
-                Ok(Some(item)) => return Poll::Ready(Some(Ok(item))),
+            if let Some(verifier) = this.running_hash {
+                if let Err(e) = verifier.verify(&item) {
+                    *this.failed = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+
+            match filter_map(item, this.reassembler) {
+                Ok(Some(item)) => {
+                    if let Some((topic_id, checkpoint)) = this.checkpoint {
+                        let mut position = TopicCheckpoint {
+                            consensus_timestamp: item.consensus_timestamp,
+                            sequence_number: item.sequence_number,
+                        };
+
+                        // chunks of messages that are still being reassembled have to be received again
+                        // after a restart, so resume right before the earliest of them.
+                        if let Some(chunk) = this.reassembler.earliest_pending_chunk() {
+                            if chunk.sequence_number <= position.sequence_number {
+                                position = TopicCheckpoint {
+                                    consensus_timestamp: chunk.consensus_timestamp,
+                                    sequence_number: chunk.sequence_number.saturating_sub(1),
+                                };
+                            }
+                        }
+
+                        let (topic_id, checkpoint) = (*topic_id, checkpoint.clone());
+
+                        *this.pending_save = Some(tokio::task::spawn_blocking(move || {
+                            checkpoint.save(topic_id, position)
+                        }));
+                    }
+
+                    return Poll::Ready(Some(Ok(TopicMessageItem::Message(item))));
+                }
                 Ok(None) => {}
                 Err(e) => return Poll::Ready(Some(Err(e))),
             }
@@ -291,7 +518,7 @@ where
 
 fn filter_map(
     mut item: mirror::ConsensusTopicResponse,
-    incomplete_messages: &mut HashMap<TransactionId, IncompleteMessage>,
+    reassembler: &mut ChunkReassembler,
 ) -> crate::Result<Option<TopicMessage>> {
     let header = PbTopicMessageHeader {
         consensus_timestamp: pb_getf!(item, consensus_timestamp)?.into(),
@@ -314,50 +541,57 @@ fn filter_map(
         _ => return Ok(Some(TopicMessage::from_single(header))),
     };
 
-    let tx_id = item.initial_transaction_id;
-
-    let entry = incomplete_messages.entry(tx_id).or_insert_with(|| {
-        IncompleteMessage::Partial(
-            // todo: configurable?
-            OffsetDateTime::now_utc() + time::Duration::minutes(15),
-            Vec::new(),
-        )
-    });
-
-    let IncompleteMessage::Partial(_, messages) = entry.handle_expiry() else { return Ok(None) };
-
-    match messages.binary_search_by_key(&item.number, |it| it.number) {
-        // We have a duplicate `number`, so, we'll just ignore it (this is unspecified behavior)
-        Ok(_) => {}
-        Err(index) => messages.insert(index, item),
-    };
-
-    // find the smallest `total` so that we aren't susceptable to stuff like total changing (and getting bigger)
-    // later on there's a check that ensures that they all have the same total.
-    let total = messages.iter().map(|it| it.total).min().unwrap();
-
-    // note: because of the way we handle `total`, `total` can get *smaller*.
-
-    match messages.len() >= total as usize {
-        true => {
-            let messages = mem::take(messages);
-            *entry = IncompleteMessage::Complete;
-            Ok(Some(TopicMessage::from_chunks(messages)))
-        }
-
-        false => Ok(None),
-    }
+    Ok(reassembler.push(item, tokio::time::Instant::now()))
 }
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::disallowed_types)]
+
+    use std::sync::Arc;
+
+    use futures_util::TryStreamExt;
+    use hedera_proto::mirror;
+    use parking_lot::Mutex;
     use time::OffsetDateTime;
 
+    use crate::mirror_query::MirrorRequest;
     use crate::{
+        SubscriptionCheckpoint,
+        TopicCheckpoint,
         TopicId,
         TopicMessageQuery,
     };
 
+    #[derive(Debug, Default)]
+    struct MemoryCheckpoint(Mutex<Option<TopicCheckpoint>>);
+
+    impl SubscriptionCheckpoint for MemoryCheckpoint {
+        fn load(&self, _topic_id: TopicId) -> crate::Result<Option<TopicCheckpoint>> {
+            Ok(*self.0.lock())
+        }
+
+        fn save(&self, _topic_id: TopicId, checkpoint: TopicCheckpoint) -> crate::Result<()> {
+            *self.0.lock() = Some(checkpoint);
+            Ok(())
+        }
+    }
+
+    fn response(sequence_number: u64) -> mirror::ConsensusTopicResponse {
+        mirror::ConsensusTopicResponse {
+            consensus_timestamp: Some(
+                OffsetDateTime::from_unix_timestamp(1_700_000_000 + sequence_number as i64)
+                    .unwrap()
+                    .into(),
+            ),
+            message: vec![sequence_number as u8],
+            running_hash: Vec::new(),
+            sequence_number,
+            running_hash_version: 3,
+            chunk_info: None,
+        }
+    }
+
     #[test]
     fn get_set_topic_id() {
         let mut query = TopicMessageQuery::new();
@@ -390,4 +624,271 @@ mod tests {
 
         assert_eq!(query.get_limit(), 1415);
     }
+
+    #[test]
+    fn initial_context_resumes_from_checkpoint() {
+        let checkpoint = Arc::new(MemoryCheckpoint::default());
+        checkpoint
+            .save(
+                TopicId::new(0, 0, 1001),
+                TopicCheckpoint {
+                    consensus_timestamp: OffsetDateTime::from_unix_timestamp(1_700_000_002)
+                        .unwrap(),
+                    sequence_number: 2,
+                },
+            )
+            .unwrap();
+
+        let mut query = TopicMessageQuery::new();
+        query
+            .topic_id(TopicId::new(0, 0, 1001))
+            .start_time(OffsetDateTime::UNIX_EPOCH)
+            .checkpoint(checkpoint);
+
+        let context = query.data.initial_context().unwrap();
+
+        assert_eq!(
+            context.start_time,
+            Some(OffsetDateTime::from_unix_timestamp(1_700_000_002).unwrap())
+        );
+        assert_eq!(context.last_sequence_number, Some(2));
+
+        // anything at or before the checkpoint was already delivered.
+        assert!(!super::TopicMessageQueryData::should_yield(&context, &response(1)));
+        assert!(!super::TopicMessageQueryData::should_yield(&context, &response(2)));
+        assert!(super::TopicMessageQueryData::should_yield(&context, &response(3)));
+    }
+
+    #[test]
+    fn initial_context_without_checkpoint() {
+        let mut query = TopicMessageQuery::new();
+        query.topic_id(TopicId::new(0, 0, 1001));
+
+        let context = query.data.initial_context().unwrap();
+
+        assert_eq!(context.start_time, None);
+        assert!(super::TopicMessageQueryData::should_yield(&context, &response(1)));
+    }
+
+    #[tokio::test]
+    async fn delivered_messages_are_checkpointed() {
+        let checkpoint = Arc::new(MemoryCheckpoint::default());
+
+        let mut query = TopicMessageQuery::new();
+        query.topic_id(TopicId::new(0, 0, 1001)).checkpoint(checkpoint.clone());
+
+        let messages: Vec<_> = query
+            .data
+            .make_item_stream(futures_util::stream::iter([Ok(response(1)), Ok(response(2))]))
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(
+            checkpoint.load(TopicId::new(0, 0, 1001)).unwrap(),
+            Some(TopicCheckpoint {
+                consensus_timestamp: OffsetDateTime::from_unix_timestamp(1_700_000_002).unwrap(),
+                sequence_number: 2,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_checkpoint_save_is_yielded_after_message() {
+        #[derive(Debug)]
+        struct FailingCheckpoint;
+
+        impl SubscriptionCheckpoint for FailingCheckpoint {
+            fn load(&self, _topic_id: TopicId) -> crate::Result<Option<TopicCheckpoint>> {
+                Ok(None)
+            }
+
+            fn save(&self, _topic_id: TopicId, _checkpoint: TopicCheckpoint) -> crate::Result<()> {
+                Err(crate::Error::subscription_checkpoint("disk full"))
+            }
+        }
+
+        let mut query = TopicMessageQuery::new();
+        query.topic_id(TopicId::new(0, 0, 1001)).checkpoint(FailingCheckpoint);
+
+        let mut stream = query.data.make_item_stream(futures_util::stream::iter([Ok(response(1))]));
+
+        assert_eq!(
+            stream.try_next().await.unwrap().unwrap().into_message().unwrap().sequence_number,
+            1
+        );
+        assert!(matches!(stream.try_next().await, Err(crate::Error::SubscriptionCheckpoint(_))));
+    }
+
+    #[tokio::test]
+    async fn broken_running_hash_ends_stream() {
+        let mut query = TopicMessageQuery::new();
+        query.topic_id(TopicId::new(0, 0, 1001)).verify_running_hash(true);
+
+        // the first message of a topic must follow from an all zero hash, which these don't.
+        let mut first = response(1);
+        first.running_hash = vec![1; 48];
+        first.chunk_info = Some(hedera_proto::services::ConsensusMessageChunkInfo {
+            initial_transaction_id: Some(crate::ToProtobuf::to_protobuf(
+                &crate::transaction::test_helpers::TEST_TX_ID,
+            )),
+            total: 1,
+            number: 1,
+        });
+
+        let items: Vec<_> = futures_util::StreamExt::collect(
+            query.data.make_item_stream(futures_util::stream::iter([Ok(first), Ok(response(2))])),
+        )
+        .await;
+
+        assert_eq!(items.len(), 1);
+        assert!(matches!(
+            items[0],
+            Err(crate::Error::TopicRunningHashMismatch { sequence_number: 1, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn incomplete_message_times_out() {
+        let mut policy = crate::ChunkReassemblyPolicy::new();
+        policy.timeout(std::time::Duration::from_millis(10));
+
+        let mut query = TopicMessageQuery::new();
+        query.topic_id(TopicId::new(0, 0, 1001)).chunk_reassembly(policy);
+
+        let mut chunk = response(1);
+        chunk.chunk_info = Some(hedera_proto::services::ConsensusMessageChunkInfo {
+            initial_transaction_id: Some(crate::ToProtobuf::to_protobuf(
+                &crate::transaction::test_helpers::TEST_TX_ID,
+            )),
+            total: 2,
+            number: 1,
+        });
+
+        // the rest of the message never arrives, but the stream stays open.
+        let stream = futures_util::StreamExt::chain(
+            futures_util::stream::iter([Ok(chunk), Ok(response(2))]),
+            futures_util::stream::pending(),
+        );
+
+        let mut items = query.data.make_item_stream(stream);
+
+        let message = items.try_next().await.unwrap().unwrap().into_message().unwrap();
+        assert_eq!(message.sequence_number, 2);
+
+        let item = tokio::time::timeout(std::time::Duration::from_secs(5), items.try_next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+
+        let crate::TopicMessageItem::Incomplete(incomplete) = item else {
+            panic!("expected an incomplete message, got {item:?}");
+        };
+
+        assert_eq!(incomplete.total, 2);
+        assert_eq!(incomplete.chunks.len(), 1);
+        assert_eq!(incomplete.reason, crate::IncompleteTopicMessageReason::TimedOut);
+    }
+
+    #[tokio::test]
+    async fn collect_skips_incomplete_messages() {
+        let mut policy = crate::ChunkReassemblyPolicy::new();
+        policy.max_pending_messages(1);
+
+        let mut query = TopicMessageQuery::new();
+        query.topic_id(TopicId::new(0, 0, 1001)).chunk_reassembly(policy);
+
+        // two chunked messages are pending at once, so the first one is given up on.
+        let chunks = [1, 2].map(|sequence_number| {
+            let mut chunk = response(sequence_number);
+            chunk.chunk_info = Some(hedera_proto::services::ConsensusMessageChunkInfo {
+                initial_transaction_id: Some(crate::ToProtobuf::to_protobuf(
+                    &crate::TransactionId {
+                        nonce: Some(sequence_number as i32),
+                        ..crate::transaction::test_helpers::TEST_TX_ID
+                    },
+                )),
+                total: 2,
+                number: 1,
+            });
+
+            Ok(chunk)
+        });
+
+        let stream = futures_util::stream::iter(chunks.into_iter().chain([Ok(response(3))]));
+
+        let messages = query.data.try_collect(stream).await.unwrap();
+
+        let sequence_numbers: Vec<_> = messages.iter().map(|it| it.sequence_number).collect();
+        assert_eq!(sequence_numbers, [3]);
+    }
+
+    #[tokio::test]
+    async fn checkpoint_stays_before_pending_chunks() {
+        #[derive(Debug, Default)]
+        struct RecordingCheckpoint(Mutex<Vec<TopicCheckpoint>>);
+
+        impl SubscriptionCheckpoint for RecordingCheckpoint {
+            fn load(&self, _topic_id: TopicId) -> crate::Result<Option<TopicCheckpoint>> {
+                Ok(self.0.lock().last().copied())
+            }
+
+            fn save(&self, _topic_id: TopicId, checkpoint: TopicCheckpoint) -> crate::Result<()> {
+                self.0.lock().push(checkpoint);
+                Ok(())
+            }
+        }
+
+        let checkpoint = Arc::new(RecordingCheckpoint::default());
+
+        let mut query = TopicMessageQuery::new();
+        query.topic_id(TopicId::new(0, 0, 1001)).checkpoint(checkpoint.clone());
+
+        // message A is split across sequence numbers 10 and 12, with message B (11) in between.
+        let [first, last] = [(10, 1), (12, 2)].map(|(sequence_number, number)| {
+            let mut chunk = response(sequence_number);
+            chunk.chunk_info = Some(hedera_proto::services::ConsensusMessageChunkInfo {
+                initial_transaction_id: Some(crate::ToProtobuf::to_protobuf(
+                    &crate::transaction::test_helpers::TEST_TX_ID,
+                )),
+                total: 2,
+                number,
+            });
+
+            Ok(chunk)
+        });
+
+        let messages: Vec<_> = query
+            .data
+            .make_item_stream(futures_util::stream::iter([first, Ok(response(11)), last]))
+            .try_collect()
+            .await
+            .unwrap();
+
+        let sequence_numbers: Vec<_> = messages
+            .into_iter()
+            .filter_map(|it| it.into_message())
+            .map(|it| it.sequence_number)
+            .collect();
+        assert_eq!(sequence_numbers, [11, 12]);
+
+        // B was delivered while A was pending, so a restart after B has to start with A's first chunk.
+        assert_eq!(
+            *checkpoint.0.lock(),
+            [
+                TopicCheckpoint {
+                    consensus_timestamp: OffsetDateTime::from_unix_timestamp(1_700_000_010)
+                        .unwrap(),
+                    sequence_number: 9,
+                },
+                TopicCheckpoint {
+                    consensus_timestamp: OffsetDateTime::from_unix_timestamp(1_700_000_012)
+                        .unwrap(),
+                    sequence_number: 12,
+                },
+            ]
+        );
+    }
 }
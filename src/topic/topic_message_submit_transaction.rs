@@ -43,6 +43,7 @@ use crate::transaction::{
 };
 use crate::{
     BoxGrpcFuture,
+    CustomFeeLimit,
     Error,
     TopicId,
     Transaction,
@@ -56,6 +57,9 @@ use crate::{
 ///
 /// The `submit_key` (if any) must sign this transaction.
 ///
+/// If the topic has custom fees, the payer is charged them unless it holds one of the topic's
+/// fee-exempt keys; use [`custom_fee_limits`](Self::custom_fee_limits) to cap what may be charged.
+///
 /// On success, the resulting `TransactionReceipt` contains the topic's updated `topic_sequence_number` and
 /// `topic_running_hash`.
 ///
@@ -92,6 +96,35 @@ impl TopicMessageSubmitTransaction {
         self.data_mut().chunk_data_mut().data = bytes.into();
         self
     }
+
+    /// Returns the maximum custom fees that the payer is willing to pay for this message.
+    #[must_use]
+    pub fn get_custom_fee_limits(&self) -> &[CustomFeeLimit] {
+        &self.body().custom_fee_limits
+    }
+
+    /// Sets the maximum custom fees that the payer is willing to pay for this message.
+    ///
+    /// If the topic's custom fees exceed these limits, the transaction fails.
+    pub fn custom_fee_limits(
+        &mut self,
+        limits: impl IntoIterator<Item = CustomFeeLimit>,
+    ) -> &mut Self {
+        self.body_mut().custom_fee_limits = limits.into_iter().collect();
+        self
+    }
+
+    /// Adds a maximum custom fee that the payer is willing to pay for this message.
+    pub fn add_custom_fee_limit(&mut self, limit: CustomFeeLimit) -> &mut Self {
+        self.body_mut().custom_fee_limits.push(limit);
+        self
+    }
+
+    /// Removes all custom fee limits from this transaction.
+    pub fn clear_custom_fee_limits(&mut self) -> &mut Self {
+        self.body_mut().custom_fee_limits.clear();
+        self
+    }
 }
 
 impl TransactionData for TopicMessageSubmitTransactionData {
@@ -223,12 +256,17 @@ impl FromProtobuf<Vec<services::ConsensusSubmitMessageTransactionBody>>
 mod tests {
     use expect_test::expect;
 
+    use crate::protobuf::ToProtobuf;
     use crate::transaction::test_helpers::{
         check_body,
         transaction_bodies,
     };
     use crate::{
+        AccountId,
         AnyTransaction,
+        CustomFeeLimit,
+        FixedFeeData,
+        Hbar,
         TopicId,
         TopicMessageSubmitTransaction,
     };
@@ -237,6 +275,13 @@ mod tests {
 
     const MESSAGE: &[u8] = br#"{"foo": 231}"#;
 
+    fn custom_fee_limit() -> CustomFeeLimit {
+        CustomFeeLimit::new(
+            Some(AccountId::new(0, 0, 5006)),
+            [FixedFeeData::from_hbar(Hbar::new(1))],
+        )
+    }
+
     fn make_transaction() -> TopicMessageSubmitTransaction {
         let mut tx = TopicMessageSubmitTransaction::new_for_tests();
         tx.topic_id(TOPIC_ID).message(MESSAGE).freeze().unwrap();
@@ -326,6 +371,24 @@ mod tests {
         assert_eq!(tx, tx2);
     }
 
+    #[test]
+    fn to_from_bytes_custom_fee_limits() {
+        let mut tx = TopicMessageSubmitTransaction::new_for_tests();
+        tx.topic_id(TOPIC_ID)
+            .message(MESSAGE)
+            .add_custom_fee_limit(custom_fee_limit())
+            .freeze()
+            .unwrap();
+
+        let tx2 = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        let tx = transaction_bodies(tx);
+        let tx2 = transaction_bodies(tx2);
+
+        assert_eq!(tx[0].max_custom_fees, vec![custom_fee_limit().to_protobuf()]);
+        assert_eq!(tx, tx2);
+    }
+
     #[test]
     fn get_set_topic_id() {
         let mut tx = TopicMessageSubmitTransaction::new();
@@ -355,4 +418,23 @@ mod tests {
         let mut tx = make_transaction();
         tx.message(MESSAGE);
     }
+
+    #[test]
+    fn get_set_custom_fee_limits() {
+        let mut tx = TopicMessageSubmitTransaction::new();
+        tx.custom_fee_limits([custom_fee_limit()]).add_custom_fee_limit(custom_fee_limit());
+
+        assert_eq!(tx.get_custom_fee_limits(), [custom_fee_limit(), custom_fee_limit()]);
+
+        tx.clear_custom_fee_limits();
+
+        assert_eq!(tx.get_custom_fee_limits(), []);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_custom_fee_limits_frozen_panics() {
+        let mut tx = make_transaction();
+        tx.add_custom_fee_limit(custom_fee_limit());
+    }
 }
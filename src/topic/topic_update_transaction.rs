@@ -43,6 +43,7 @@ use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
+    FixedFee,
     Key,
     TopicId,
     Transaction,
@@ -79,6 +80,15 @@ pub struct TopicUpdateTransactionData {
 
     /// Optional account to be used at the topic's expiration time to extend the life of the topic.
     auto_renew_account_id: Option<AccountId>,
+
+    /// Access control for updating the topic's custom fees.
+    fee_schedule_key: Option<Key>,
+
+    /// Keys whose signatures exempt a `TopicMessageSubmitTransaction` from the topic's custom fees.
+    fee_exempt_keys: Option<Vec<Key>>,
+
+    /// The fixed fees to assess when a message is submitted to the topic.
+    custom_fees: Option<Vec<FixedFee>>,
 }
 
 impl TopicUpdateTransaction {
@@ -192,6 +202,78 @@ impl TopicUpdateTransaction {
             checksum: None,
         })
     }
+
+    /// Returns the access control for updating the topic's custom fees.
+    #[must_use]
+    pub fn get_fee_schedule_key(&self) -> Option<&Key> {
+        self.data().fee_schedule_key.as_ref()
+    }
+
+    /// Sets the access control for updating the topic's custom fees.
+    pub fn fee_schedule_key(&mut self, key: impl Into<Key>) -> &mut Self {
+        self.data_mut().fee_schedule_key = Some(key.into());
+        self
+    }
+
+    /// Clears the access control for updating the topic's custom fees.
+    pub fn clear_fee_schedule_key(&mut self) -> &mut Self {
+        self.data_mut().fee_schedule_key = Some(Key::KeyList(crate::KeyList::new()));
+        self
+    }
+
+    /// Returns the new keys that are exempt from paying the topic's custom fees.
+    #[must_use]
+    pub fn get_fee_exempt_keys(&self) -> Option<&[Key]> {
+        self.data().fee_exempt_keys.as_deref()
+    }
+
+    /// Sets the keys that are exempt from paying the topic's custom fees.
+    ///
+    /// This replaces the topic's current list of fee-exempt keys.
+    pub fn fee_exempt_keys(&mut self, keys: impl IntoIterator<Item = Key>) -> &mut Self {
+        self.data_mut().fee_exempt_keys = Some(keys.into_iter().collect());
+        self
+    }
+
+    /// Adds a key to the keys that are exempt from paying the topic's custom fees.
+    pub fn add_fee_exempt_key(&mut self, key: impl Into<Key>) -> &mut Self {
+        self.data_mut().fee_exempt_keys.get_or_insert_with(Vec::new).push(key.into());
+        self
+    }
+
+    /// Removes all fee-exempt keys from the topic.
+    pub fn clear_fee_exempt_keys(&mut self) -> &mut Self {
+        self.data_mut().fee_exempt_keys = Some(Vec::new());
+        self
+    }
+
+    /// Returns the new fixed fees to assess when a message is submitted to the topic.
+    #[must_use]
+    pub fn get_custom_fees(&self) -> Option<&[FixedFee]> {
+        self.data().custom_fees.as_deref()
+    }
+
+    /// Sets the fixed fees to assess when a message is submitted to the topic.
+    ///
+    /// This replaces the topic's current custom fees.
+    ///
+    /// Topic fees have no exempt collectors, `all_collectors_are_exempt` isn't sent to the network.
+    pub fn custom_fees(&mut self, fees: impl IntoIterator<Item = FixedFee>) -> &mut Self {
+        self.data_mut().custom_fees = Some(fees.into_iter().collect());
+        self
+    }
+
+    /// Adds a fixed fee to assess when a message is submitted to the topic.
+    pub fn add_custom_fee(&mut self, fee: FixedFee) -> &mut Self {
+        self.data_mut().custom_fees.get_or_insert_with(Vec::new).push(fee);
+        self
+    }
+
+    /// Removes all custom fees from the topic.
+    pub fn clear_custom_fees(&mut self) -> &mut Self {
+        self.data_mut().custom_fees = Some(Vec::new());
+        self
+    }
 }
 
 impl TransactionData for TopicUpdateTransactionData {}
@@ -248,6 +330,12 @@ impl FromProtobuf<services::ConsensusUpdateTopicTransactionBody> for TopicUpdate
             submit_key: Option::from_protobuf(pb.submit_key)?,
             auto_renew_period: pb.auto_renew_period.map(Into::into),
             auto_renew_account_id: Option::from_protobuf(pb.auto_renew_account)?,
+            fee_schedule_key: Option::from_protobuf(pb.fee_schedule_key)?,
+            fee_exempt_keys: pb
+                .fee_exempt_key_list
+                .map(|it| Vec::from_protobuf(it.keys))
+                .transpose()?,
+            custom_fees: pb.custom_fees.map(|it| Vec::from_protobuf(it.fees)).transpose()?,
         })
     }
 }
@@ -262,6 +350,14 @@ impl ToProtobuf for TopicUpdateTransactionData {
         let submit_key = self.submit_key.to_protobuf();
         let auto_renew_period = self.auto_renew_period.map(Into::into);
         let auto_renew_account_id = self.auto_renew_account_id.to_protobuf();
+        let fee_exempt_key_list = self
+            .fee_exempt_keys
+            .as_ref()
+            .map(|keys| services::FeeExemptKeyList { keys: keys.to_protobuf() });
+        let custom_fees = self
+            .custom_fees
+            .as_ref()
+            .map(|fees| services::FixedCustomFeeList { fees: fees.to_protobuf() });
 
         services::ConsensusUpdateTopicTransactionBody {
             auto_renew_account: auto_renew_account_id,
@@ -271,6 +367,9 @@ impl ToProtobuf for TopicUpdateTransactionData {
             admin_key,
             submit_key,
             auto_renew_period,
+            fee_schedule_key: self.fee_schedule_key.to_protobuf(),
+            fee_exempt_key_list,
+            custom_fees,
         }
     }
 }
@@ -287,11 +386,24 @@ mod tests {
         VALID_START,
     };
     use crate::{
+        AccountId,
         AnyTransaction,
+        FixedFee,
+        FixedFeeData,
+        Hbar,
+        Key,
         TopicId,
         TopicUpdateTransaction,
     };
 
+    fn custom_fee() -> FixedFee {
+        FixedFee {
+            fee: FixedFeeData::from_hbar(Hbar::new(1)),
+            fee_collector_account_id: Some(AccountId::new(0, 0, 5008)),
+            all_collectors_are_exempt: false,
+        }
+    }
+
     fn make_transaction() -> TopicUpdateTransaction {
         let mut tx = TopicUpdateTransaction::new_for_tests();
 
@@ -299,6 +411,9 @@ mod tests {
             .clear_admin_key()
             .clear_auto_renew_account_id()
             .clear_submit_key()
+            .clear_fee_schedule_key()
+            .clear_fee_exempt_keys()
+            .clear_custom_fees()
             .topic_memo("")
             .freeze()
             .unwrap();
@@ -362,6 +477,27 @@ mod tests {
                             ),
                         },
                     ),
+                    fee_schedule_key: Some(
+                        Key {
+                            key: Some(
+                                KeyList(
+                                    KeyList {
+                                        keys: [],
+                                    },
+                                ),
+                            ),
+                        },
+                    ),
+                    fee_exempt_key_list: Some(
+                        FeeExemptKeyList {
+                            keys: [],
+                        },
+                    ),
+                    custom_fees: Some(
+                        FixedCustomFeeList {
+                            fees: [],
+                        },
+                    ),
                 },
             )
         "#]]
@@ -391,6 +527,9 @@ mod tests {
             .submit_key(unused_private_key().public_key())
             .topic_memo("Hello memo")
             .expiration_time(VALID_START)
+            .fee_schedule_key(unused_private_key().public_key())
+            .add_fee_exempt_key(unused_private_key().public_key())
+            .add_custom_fee(custom_fee())
             .freeze()
             .unwrap();
 
@@ -524,6 +663,119 @@ mod tests {
                             ),
                         },
                     ),
+                    fee_schedule_key: Some(
+                        Key {
+                            key: Some(
+                                Ed25519(
+                                    [
+                                        224,
+                                        200,
+                                        236,
+                                        39,
+                                        88,
+                                        165,
+                                        135,
+                                        159,
+                                        250,
+                                        194,
+                                        38,
+                                        161,
+                                        60,
+                                        12,
+                                        81,
+                                        107,
+                                        121,
+                                        158,
+                                        114,
+                                        227,
+                                        81,
+                                        65,
+                                        160,
+                                        221,
+                                        130,
+                                        143,
+                                        148,
+                                        211,
+                                        121,
+                                        136,
+                                        164,
+                                        183,
+                                    ],
+                                ),
+                            ),
+                        },
+                    ),
+                    fee_exempt_key_list: Some(
+                        FeeExemptKeyList {
+                            keys: [
+                                Key {
+                                    key: Some(
+                                        Ed25519(
+                                            [
+                                                224,
+                                                200,
+                                                236,
+                                                39,
+                                                88,
+                                                165,
+                                                135,
+                                                159,
+                                                250,
+                                                194,
+                                                38,
+                                                161,
+                                                60,
+                                                12,
+                                                81,
+                                                107,
+                                                121,
+                                                158,
+                                                114,
+                                                227,
+                                                81,
+                                                65,
+                                                160,
+                                                221,
+                                                130,
+                                                143,
+                                                148,
+                                                211,
+                                                121,
+                                                136,
+                                                164,
+                                                183,
+                                            ],
+                                        ),
+                                    ),
+                                },
+                            ],
+                        },
+                    ),
+                    custom_fees: Some(
+                        FixedCustomFeeList {
+                            fees: [
+                                FixedCustomFee {
+                                    fixed_fee: Some(
+                                        FixedFee {
+                                            amount: 100000000,
+                                            denominating_token_id: None,
+                                        },
+                                    ),
+                                    fee_collector_account_id: Some(
+                                        AccountId {
+                                            shard_num: 0,
+                                            realm_num: 0,
+                                            account: Some(
+                                                AccountNum(
+                                                    5008,
+                                                ),
+                                            ),
+                                        },
+                                    ),
+                                },
+                            ],
+                        },
+                    ),
                 },
             )
         "#]]
@@ -542,4 +794,36 @@ mod tests {
 
         assert_eq!(tx, tx2);
     }
+
+    #[test]
+    fn get_set_fee_exempt_keys() {
+        let key = Key::from(unused_private_key().public_key());
+
+        let mut tx = TopicUpdateTransaction::new();
+        assert_eq!(tx.get_fee_exempt_keys(), None);
+
+        tx.fee_exempt_keys([key.clone()]).add_fee_exempt_key(key.clone());
+        assert_eq!(tx.get_fee_exempt_keys(), Some([key.clone(), key].as_slice()));
+
+        tx.clear_fee_exempt_keys();
+        assert_eq!(tx.get_fee_exempt_keys(), Some([].as_slice()));
+    }
+
+    #[test]
+    fn get_set_custom_fees() {
+        let mut tx = TopicUpdateTransaction::new();
+        assert_eq!(tx.get_custom_fees(), None);
+
+        tx.custom_fees([custom_fee()]);
+        assert_eq!(tx.get_custom_fees(), Some([custom_fee()].as_slice()));
+
+        tx.clear_custom_fees();
+        assert_eq!(tx.get_custom_fees(), Some([].as_slice()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_set_custom_fees_frozen_panics() {
+        make_transaction().add_custom_fee(custom_fee());
+    }
 }
@@ -50,7 +50,10 @@ mod data {
         AccountCreateTransactionData as AccountCreate,
         AccountDeleteTransactionData as AccountDelete,
         AccountUpdateTransactionData as AccountUpdate,
+        LiveHashAddTransactionData as LiveHashAdd,
+        LiveHashDeleteTransactionData as LiveHashDelete,
     };
+    pub(super) use crate::batch_transaction::BatchTransactionData as Batch;
     pub(super) use crate::contract::{
         ContractCreateTransactionData as ContractCreate,
         ContractDeleteTransactionData as ContractDelete,
@@ -64,6 +67,11 @@ mod data {
         FileDeleteTransactionData as FileDelete,
         FileUpdateTransactionData as FileUpdate,
     };
+    pub(super) use crate::node::{
+        NodeCreateTransactionData as NodeCreate,
+        NodeDeleteTransactionData as NodeDelete,
+        NodeUpdateTransactionData as NodeUpdate,
+    };
     pub(super) use crate::prng_transaction::PrngTransactionData as Prng;
     pub(super) use crate::schedule::{
         ScheduleCreateTransactionData as ScheduleCreate,
@@ -76,8 +84,11 @@ mod data {
         SystemUndeleteTransactionData as SystemUndelete,
     };
     pub(super) use crate::token::{
+        TokenAirdropTransactionData as TokenAirdrop,
         TokenAssociateTransactionData as TokenAssociate,
         TokenBurnTransactionData as TokenBurn,
+        TokenCancelAirdropTransactionData as TokenCancelAirdrop,
+        TokenClaimAirdropTransactionData as TokenClaimAirdrop,
         TokenCreateTransactionData as TokenCreate,
         TokenDeleteTransactionData as TokenDelete,
         TokenDissociateTransactionData as TokenDissociate,
@@ -86,6 +97,7 @@ mod data {
         TokenGrantKycTransactionData as TokenGrantKyc,
         TokenMintTransactionData as TokenMint,
         TokenPauseTransactionData as TokenPause,
+        TokenRejectTransactionData as TokenReject,
         TokenRevokeKycTransactionData as TokenRevokeKyc,
         TokenUnfreezeTransactionData as TokenUnfreeze,
         TokenUnpauseTransactionData as TokenUnpause,
@@ -150,6 +162,16 @@ pub enum AnyTransactionData {
     Freeze(data::Freeze),
     Ethereum(data::Ethereum),
     TokenUpdateNfts(data::TokenUpdateNfts),
+    TokenAirdrop(data::TokenAirdrop),
+    TokenClaimAirdrop(data::TokenClaimAirdrop),
+    TokenCancelAirdrop(data::TokenCancelAirdrop),
+    TokenReject(data::TokenReject),
+    LiveHashAdd(data::LiveHashAdd),
+    LiveHashDelete(data::LiveHashDelete),
+    NodeCreate(data::NodeCreate),
+    NodeUpdate(data::NodeUpdate),
+    NodeDelete(data::NodeDelete),
+    Batch(data::Batch),
 }
 
 impl ToTransactionDataProtobuf for AnyTransactionData {
@@ -282,6 +304,22 @@ impl ToTransactionDataProtobuf for AnyTransactionData {
             Self::TokenUpdateNfts(transaction) => {
                 transaction.to_transaction_data_protobuf(chunk_info)
             }
+            Self::TokenAirdrop(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
+            Self::TokenClaimAirdrop(transaction) => {
+                transaction.to_transaction_data_protobuf(chunk_info)
+            }
+            Self::TokenCancelAirdrop(transaction) => {
+                transaction.to_transaction_data_protobuf(chunk_info)
+            }
+            Self::TokenReject(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
+            Self::LiveHashAdd(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
+            Self::LiveHashDelete(transaction) => {
+                transaction.to_transaction_data_protobuf(chunk_info)
+            }
+            Self::NodeCreate(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
+            Self::NodeUpdate(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
+            Self::NodeDelete(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
+            Self::Batch(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
         }
     }
 }
@@ -331,6 +369,16 @@ impl TransactionData for AnyTransactionData {
             Self::ScheduleDelete(transaction) => transaction.default_max_transaction_fee(),
             Self::Ethereum(transaction) => transaction.default_max_transaction_fee(),
             Self::TokenUpdateNfts(transaction) => transaction.default_max_transaction_fee(),
+            Self::TokenAirdrop(transaction) => transaction.default_max_transaction_fee(),
+            Self::TokenClaimAirdrop(transaction) => transaction.default_max_transaction_fee(),
+            Self::TokenCancelAirdrop(transaction) => transaction.default_max_transaction_fee(),
+            Self::TokenReject(transaction) => transaction.default_max_transaction_fee(),
+            Self::LiveHashAdd(transaction) => transaction.default_max_transaction_fee(),
+            Self::LiveHashDelete(transaction) => transaction.default_max_transaction_fee(),
+            Self::NodeCreate(transaction) => transaction.default_max_transaction_fee(),
+            Self::NodeUpdate(transaction) => transaction.default_max_transaction_fee(),
+            Self::NodeDelete(transaction) => transaction.default_max_transaction_fee(),
+            Self::Batch(transaction) => transaction.default_max_transaction_fee(),
         }
     }
 
@@ -378,6 +426,16 @@ impl TransactionData for AnyTransactionData {
             Self::ScheduleDelete(it) => it.maybe_chunk_data(),
             Self::Ethereum(it) => it.maybe_chunk_data(),
             Self::TokenUpdateNfts(it) => it.maybe_chunk_data(),
+            Self::TokenAirdrop(it) => it.maybe_chunk_data(),
+            Self::TokenClaimAirdrop(it) => it.maybe_chunk_data(),
+            Self::TokenCancelAirdrop(it) => it.maybe_chunk_data(),
+            Self::TokenReject(it) => it.maybe_chunk_data(),
+            Self::LiveHashAdd(it) => it.maybe_chunk_data(),
+            Self::LiveHashDelete(it) => it.maybe_chunk_data(),
+            Self::NodeCreate(it) => it.maybe_chunk_data(),
+            Self::NodeUpdate(it) => it.maybe_chunk_data(),
+            Self::NodeDelete(it) => it.maybe_chunk_data(),
+            Self::Batch(it) => it.maybe_chunk_data(),
         }
     }
 
@@ -425,6 +483,16 @@ impl TransactionData for AnyTransactionData {
             Self::ScheduleDelete(it) => it.wait_for_receipt(),
             Self::Ethereum(it) => it.wait_for_receipt(),
             Self::TokenUpdateNfts(it) => it.wait_for_receipt(),
+            Self::TokenAirdrop(it) => it.wait_for_receipt(),
+            Self::TokenClaimAirdrop(it) => it.wait_for_receipt(),
+            Self::TokenCancelAirdrop(it) => it.wait_for_receipt(),
+            Self::TokenReject(it) => it.wait_for_receipt(),
+            Self::LiveHashAdd(it) => it.wait_for_receipt(),
+            Self::LiveHashDelete(it) => it.wait_for_receipt(),
+            Self::NodeCreate(it) => it.wait_for_receipt(),
+            Self::NodeUpdate(it) => it.wait_for_receipt(),
+            Self::NodeDelete(it) => it.wait_for_receipt(),
+            Self::Batch(it) => it.wait_for_receipt(),
         }
     }
 }
@@ -478,6 +546,16 @@ impl TransactionExecute for AnyTransactionData {
             Self::ScheduleDelete(transaction) => transaction.execute(channel, request),
             Self::Ethereum(transaction) => transaction.execute(channel, request),
             Self::TokenUpdateNfts(transaction) => transaction.execute(channel, request),
+            Self::TokenAirdrop(transaction) => transaction.execute(channel, request),
+            Self::TokenClaimAirdrop(transaction) => transaction.execute(channel, request),
+            Self::TokenCancelAirdrop(transaction) => transaction.execute(channel, request),
+            Self::TokenReject(transaction) => transaction.execute(channel, request),
+            Self::LiveHashAdd(transaction) => transaction.execute(channel, request),
+            Self::LiveHashDelete(transaction) => transaction.execute(channel, request),
+            Self::NodeCreate(transaction) => transaction.execute(channel, request),
+            Self::NodeUpdate(transaction) => transaction.execute(channel, request),
+            Self::NodeDelete(transaction) => transaction.execute(channel, request),
+            Self::Batch(transaction) => transaction.execute(channel, request),
         }
     }
 }
@@ -529,6 +607,16 @@ impl ValidateChecksums for AnyTransactionData {
             Self::Freeze(transaction) => transaction.validate_checksums(ledger_id),
             Self::Ethereum(transaction) => transaction.validate_checksums(ledger_id),
             Self::TokenUpdateNfts(transaction) => transaction.validate_checksums(ledger_id),
+            Self::TokenAirdrop(transaction) => transaction.validate_checksums(ledger_id),
+            Self::TokenClaimAirdrop(transaction) => transaction.validate_checksums(ledger_id),
+            Self::TokenCancelAirdrop(transaction) => transaction.validate_checksums(ledger_id),
+            Self::TokenReject(transaction) => transaction.validate_checksums(ledger_id),
+            Self::LiveHashAdd(transaction) => transaction.validate_checksums(ledger_id),
+            Self::LiveHashDelete(transaction) => transaction.validate_checksums(ledger_id),
+            Self::NodeCreate(transaction) => transaction.validate_checksums(ledger_id),
+            Self::NodeUpdate(transaction) => transaction.validate_checksums(ledger_id),
+            Self::NodeDelete(transaction) => transaction.validate_checksums(ledger_id),
+            Self::Batch(transaction) => transaction.validate_checksums(ledger_id),
         }
     }
 }
@@ -588,16 +676,16 @@ impl FromProtobuf<services::transaction_body::Data> for AnyTransactionData {
             Data::ScheduleDelete(pb) => data::ScheduleDelete::from_protobuf(pb)?.into(),
             Data::ScheduleSign(pb) => data::ScheduleSign::from_protobuf(pb)?.into(),
             Data::TokenUpdateNfts(pb) => data::TokenUpdateNfts::from_protobuf(pb)?.into(),
-            Data::CryptoAddLiveHash(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `AddLiveHashTransaction`",
-                ))
-            }
-            Data::CryptoDeleteLiveHash(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `DeleteLiveHashTransaction`",
-                ))
-            }
+            Data::TokenAirdrop(pb) => data::TokenAirdrop::from_protobuf(pb)?.into(),
+            Data::TokenClaimAirdrop(pb) => data::TokenClaimAirdrop::from_protobuf(pb)?.into(),
+            Data::TokenCancelAirdrop(pb) => data::TokenCancelAirdrop::from_protobuf(pb)?.into(),
+            Data::TokenReject(pb) => data::TokenReject::from_protobuf(pb)?.into(),
+            Data::CryptoAddLiveHash(pb) => data::LiveHashAdd::from_protobuf(pb)?.into(),
+            Data::CryptoDeleteLiveHash(pb) => data::LiveHashDelete::from_protobuf(pb)?.into(),
+            Data::NodeCreate(pb) => data::NodeCreate::from_protobuf(pb)?.into(),
+            Data::NodeUpdate(pb) => data::NodeUpdate::from_protobuf(pb)?.into(),
+            Data::NodeDelete(pb) => data::NodeDelete::from_protobuf(pb)?.into(),
+            Data::AtomicBatch(pb) => data::Batch::from_protobuf(pb)?.into(),
             Data::UncheckedSubmit(_) => {
                 return Err(Error::from_protobuf(
                     "unsupported transaction `UncheckedSubmitTransaction`",
@@ -752,6 +840,36 @@ impl AnyTransactionData {
             ServicesTransactionDataList::TokenUpdateNfts(v) => {
                 data::TokenUpdateNfts::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            ServicesTransactionDataList::TokenAirdrop(v) => {
+                data::TokenAirdrop::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::TokenClaimAirdrop(v) => {
+                data::TokenClaimAirdrop::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::TokenCancelAirdrop(v) => {
+                data::TokenCancelAirdrop::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::TokenReject(v) => {
+                data::TokenReject::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::LiveHashAdd(v) => {
+                data::LiveHashAdd::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::LiveHashDelete(v) => {
+                data::LiveHashDelete::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::NodeCreate(v) => {
+                data::NodeCreate::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::NodeUpdate(v) => {
+                data::NodeUpdate::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::NodeDelete(v) => {
+                data::NodeDelete::from_protobuf(try_into_only_element(v)?)?.into()
+            }
+            ServicesTransactionDataList::Batch(v) => {
+                data::Batch::from_protobuf(try_into_only_element(v)?)?.into()
+            }
         };
 
         Ok(data)
@@ -776,9 +894,12 @@ impl AnyTransaction {
                     first_body,
                     transaction_id
                 )?)?),
+                batch_key: Option::from_protobuf(first_body.batch_key)?,
+                custom_fee_limits: Vec::from_protobuf(first_body.max_custom_fees)?,
                 operator: None,
                 is_frozen: true,
                 regenerate_transaction_id: Some(false),
+                idempotent_resubmission: false,
             },
             signers: Vec::new(),
             sources: None,
@@ -831,6 +952,16 @@ enum ServicesTransactionDataList {
     Ethereum(Vec<services::EthereumTransactionBody>),
     UtilPrng(Vec<services::UtilPrngTransactionBody>),
     TokenUpdateNfts(Vec<services::TokenUpdateNftsTransactionBody>),
+    TokenAirdrop(Vec<services::TokenAirdropTransactionBody>),
+    TokenClaimAirdrop(Vec<services::TokenClaimAirdropTransactionBody>),
+    TokenCancelAirdrop(Vec<services::TokenCancelAirdropTransactionBody>),
+    TokenReject(Vec<services::TokenRejectTransactionBody>),
+    LiveHashAdd(Vec<services::CryptoAddLiveHashTransactionBody>),
+    LiveHashDelete(Vec<services::CryptoDeleteLiveHashTransactionBody>),
+    NodeCreate(Vec<services::NodeCreateTransactionBody>),
+    NodeUpdate(Vec<services::NodeUpdateTransactionBody>),
+    NodeDelete(Vec<services::NodeDeleteTransactionBody>),
+    Batch(Vec<services::AtomicBatchTransactionBody>),
 }
 
 impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransactionDataList {
@@ -895,16 +1026,16 @@ impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransaction
             Data::ScheduleSign(it) => Self::ScheduleSign(make_vec(it, len)),
             Data::UtilPrng(it) => Self::UtilPrng(make_vec(it, len)),
             Data::TokenUpdateNfts(it) => Self::TokenUpdateNfts(make_vec(it, len)),
-            Data::CryptoAddLiveHash(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `AddLiveHashTransaction`",
-                ))
-            }
-            Data::CryptoDeleteLiveHash(_) => {
-                return Err(Error::from_protobuf(
-                    "unsupported transaction `DeleteLiveHashTransaction`",
-                ))
-            }
+            Data::TokenAirdrop(it) => Self::TokenAirdrop(make_vec(it, len)),
+            Data::TokenClaimAirdrop(it) => Self::TokenClaimAirdrop(make_vec(it, len)),
+            Data::TokenCancelAirdrop(it) => Self::TokenCancelAirdrop(make_vec(it, len)),
+            Data::TokenReject(it) => Self::TokenReject(make_vec(it, len)),
+            Data::CryptoAddLiveHash(it) => Self::LiveHashAdd(make_vec(it, len)),
+            Data::CryptoDeleteLiveHash(it) => Self::LiveHashDelete(make_vec(it, len)),
+            Data::NodeCreate(it) => Self::NodeCreate(make_vec(it, len)),
+            Data::NodeUpdate(it) => Self::NodeUpdate(make_vec(it, len)),
+            Data::NodeDelete(it) => Self::NodeDelete(make_vec(it, len)),
+            Data::AtomicBatch(it) => Self::Batch(make_vec(it, len)),
 
             Data::UncheckedSubmit(_) => {
                 return Err(Error::from_protobuf(
@@ -969,6 +1100,16 @@ impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransaction
                 (Self::ScheduleDelete(v), Data::ScheduleDelete(element)) => v.push(element),
                 (Self::Ethereum(v), Data::EthereumTransaction(element)) => v.push(element),
                 (Self::UtilPrng(v), Data::UtilPrng(element)) => v.push(element),
+                (Self::TokenAirdrop(v), Data::TokenAirdrop(element)) => v.push(element),
+                (Self::TokenClaimAirdrop(v), Data::TokenClaimAirdrop(element)) => v.push(element),
+                (Self::TokenCancelAirdrop(v), Data::TokenCancelAirdrop(element)) => v.push(element),
+                (Self::TokenReject(v), Data::TokenReject(element)) => v.push(element),
+                (Self::LiveHashAdd(v), Data::CryptoAddLiveHash(element)) => v.push(element),
+                (Self::LiveHashDelete(v), Data::CryptoDeleteLiveHash(element)) => v.push(element),
+                (Self::NodeCreate(v), Data::NodeCreate(element)) => v.push(element),
+                (Self::NodeUpdate(v), Data::NodeUpdate(element)) => v.push(element),
+                (Self::NodeDelete(v), Data::NodeDelete(element)) => v.push(element),
+                (Self::Batch(v), Data::AtomicBatch(element)) => v.push(element),
                 _ => return Err(Error::from_protobuf("mismatched transaction types")),
             }
         }
@@ -1065,4 +1206,14 @@ impl_downcast_any! {
     Freeze,
     Ethereum,
     TokenUpdateNfts,
+    TokenAirdrop,
+    TokenClaimAirdrop,
+    TokenCancelAirdrop,
+    TokenReject,
+    LiveHashAdd,
+    LiveHashDelete,
+    NodeCreate,
+    NodeUpdate,
+    NodeDelete,
+    Batch,
 }
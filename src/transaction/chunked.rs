@@ -154,6 +154,10 @@ where
         self.transaction.regenerate_transaction_id()
     }
 
+    fn idempotent_resubmission(&self) -> bool {
+        self.transaction.body.idempotent_resubmission
+    }
+
     fn operator_account_id(&self) -> Option<&AccountId> {
         self.transaction.operator_account_id()
     }
@@ -258,6 +262,10 @@ where
         self.transaction.regenerate_transaction_id()
     }
 
+    fn idempotent_resubmission(&self) -> bool {
+        self.transaction.body.idempotent_resubmission
+    }
+
     fn make_request(
         &self,
         transaction_id: Option<&TransactionId>,
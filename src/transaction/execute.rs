@@ -191,6 +191,10 @@ where
         self.body.regenerate_transaction_id
     }
 
+    fn idempotent_resubmission(&self) -> bool {
+        self.body.idempotent_resubmission
+    }
+
     fn make_request(
         &self,
         transaction_id: Option<&TransactionId>,
@@ -287,6 +291,8 @@ where
             node_account_id: Some(chunk_info.node_account_id.to_protobuf()),
             generate_record: false,
             transaction_fee: max_transaction_fee.to_tinybars() as u64,
+            batch_key: self.body.batch_key.to_protobuf(),
+            max_custom_fees: self.body.custom_fee_limits.to_protobuf(),
         }
     }
 }
@@ -394,6 +400,10 @@ impl<'a, D: TransactionExecute> Execute for SourceTransactionExecuteView<'a, D>
         Some(false)
     }
 
+    fn idempotent_resubmission(&self) -> bool {
+        self.transaction.body.idempotent_resubmission
+    }
+
     fn make_request(
         &self,
         transaction_id: Option<&TransactionId>,
@@ -0,0 +1,541 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+use std::time::Duration;
+
+use backoff::ExponentialBackoffBuilder;
+use tokio::time::Instant;
+
+use super::{
+    Transaction,
+    TransactionExecute,
+};
+use crate::execute::execute_with_backoff;
+use crate::{
+    Client,
+    Error,
+    TransactionId,
+    TransactionReceipt,
+    TransactionRecord,
+    TransactionResponse,
+};
+
+/// How [`Transaction::execute_and_wait`] finds out that a transaction reached consensus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum FinalityStrategy {
+    /// Poll the consensus node with [`TransactionReceiptQuery`](crate::TransactionReceiptQuery)s.
+    #[default]
+    Receipt,
+
+    /// Poll for the receipt, then fetch the [`TransactionRecord`] as well.
+    ///
+    /// Unlike receipts, records are *not* free.
+    Record,
+
+    /// Poll the mirror node REST API until it has the transaction, then fetch the receipt.
+    ///
+    /// This keeps the polling load off of the consensus nodes,
+    /// at the cost of waiting for the mirror node to import the transaction.
+    #[cfg(feature = "mirror-rest")]
+    MirrorNode,
+}
+
+/// The step of [`Transaction::execute_and_wait`] that was in progress.
+///
+/// Used by [`Error::FinalityTimedOut`] to report which phase failed to finish before the deadline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FinalityPhase {
+    /// Submitting the transaction to a node.
+    Submit,
+
+    /// Waiting for the transaction's receipt (IE, waiting for consensus).
+    Consensus,
+
+    /// Waiting for the mirror node to have the transaction.
+    MirrorNode,
+
+    /// Fetching the transaction's record.
+    Record,
+}
+
+impl fmt::Display for FinalityPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Submit => "submit",
+            Self::Consensus => "consensus",
+            Self::MirrorNode => "mirror node",
+            Self::Record => "record",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// Options for [`Transaction::execute_and_wait`].
+#[derive(Debug, Clone)]
+pub struct FinalityPolling {
+    strategy: FinalityStrategy,
+    interval: Duration,
+    deadline: Duration,
+    include_children: bool,
+    include_duplicates: bool,
+}
+
+impl Default for FinalityPolling {
+    fn default() -> Self {
+        Self {
+            strategy: FinalityStrategy::default(),
+            interval: Duration::from_millis(500),
+            deadline: Duration::from_secs(120),
+            include_children: false,
+            include_duplicates: false,
+        }
+    }
+}
+
+impl FinalityPolling {
+    /// Create new finality polling options with the default values.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns how the transaction's finality will be checked.
+    #[must_use]
+    pub fn get_strategy(&self) -> FinalityStrategy {
+        self.strategy
+    }
+
+    /// Sets how the transaction's finality will be checked.
+    ///
+    /// Defaults to [`FinalityStrategy::Receipt`].
+    pub fn strategy(&mut self, strategy: FinalityStrategy) -> &mut Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Returns the time between polls.
+    #[must_use]
+    pub fn get_interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// Sets the time between polls.
+    ///
+    /// Defaults to 500 milliseconds.
+    pub fn interval(&mut self, interval: Duration) -> &mut Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Returns the total time allowed for submitting the transaction and waiting for finality.
+    #[must_use]
+    pub fn get_deadline(&self) -> Duration {
+        self.deadline
+    }
+
+    /// Sets the total time allowed for submitting the transaction and waiting for finality.
+    ///
+    /// Defaults to 2 minutes.
+    pub fn deadline(&mut self, deadline: Duration) -> &mut Self {
+        self.deadline = deadline;
+        self
+    }
+
+    /// Returns whether the receipts (and records) of child transactions should be included.
+    #[must_use]
+    pub fn get_include_children(&self) -> bool {
+        self.include_children
+    }
+
+    /// Whether the receipts (and records) of child transactions should be included.
+    pub fn include_children(&mut self, include: bool) -> &mut Self {
+        self.include_children = include;
+        self
+    }
+
+    /// Returns whether the receipts (and records) of duplicate transactions should be included.
+    #[must_use]
+    pub fn get_include_duplicates(&self) -> bool {
+        self.include_duplicates
+    }
+
+    /// Whether the receipts (and records) of duplicate transactions should be included.
+    pub fn include_duplicates(&mut self, include: bool) -> &mut Self {
+        self.include_duplicates = include;
+        self
+    }
+}
+
+/// The result of [`Transaction::execute_and_wait`].
+#[derive(Debug)]
+pub struct TransactionOutcome {
+    /// The response from submitting the transaction.
+    pub response: TransactionResponse,
+
+    /// The transaction's receipt.
+    ///
+    /// Includes child and duplicate receipts if they were requested.
+    pub receipt: TransactionReceipt,
+
+    /// The transaction's record, present when using [`FinalityStrategy::Record`].
+    pub record: Option<TransactionRecord>,
+}
+
+impl<D> Transaction<D>
+where
+    D: TransactionExecute,
+{
+    /// Execute this transaction and wait for it to reach consensus.
+    ///
+    /// This is a combination of [`execute`](Self::execute) and [`get_receipt`](TransactionResponse::get_receipt)
+    /// (and optionally [`get_record`](TransactionResponse::get_record)),
+    /// with the whole thing bounded by the `polling` deadline.
+    ///
+    /// # Errors
+    /// - [`Error::FinalityTimedOut`] if the deadline passes before finality is reached.
+    /// - if the response's `validate_status` is `true`:
+    ///   [`Error::ReceiptStatus`] for a failing receipt.
+    /// - Any error that [`execute`](Self::execute) can return.
+    pub async fn execute_and_wait(
+        &mut self,
+        client: &Client,
+        polling: &FinalityPolling,
+    ) -> crate::Result<TransactionOutcome> {
+        // resolve the mirror node up front, so that a missing url fails before anything is submitted.
+        #[cfg(feature = "mirror-rest")]
+        let mirror = match polling.strategy {
+            FinalityStrategy::MirrorNode => Some(crate::MirrorRestClient::for_client(client)?),
+            _ => None,
+        };
+
+        let deadline = Instant::now() + polling.deadline;
+
+        let response = self
+            .execute_with_optional_timeout(client, Some(polling.deadline))
+            .await
+            .map_err(|e| timed_out(e, FinalityPhase::Submit, self.get_transaction_id()))?;
+
+        let transaction_id = Some(response.transaction_id);
+
+        #[cfg(feature = "mirror-rest")]
+        if let Some(mirror) = &mirror {
+            wait_for_mirror_node(mirror, &response, polling, deadline).await?;
+        }
+
+        let receipt = {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+
+            let backoff = ExponentialBackoffBuilder::new()
+                .with_initial_interval(polling.interval)
+                .with_max_interval(polling.interval)
+                .with_multiplier(1.0)
+                .with_randomization_factor(0.0)
+                .with_max_elapsed_time(Some(remaining))
+                .build();
+
+            let mut query = response.get_receipt_query();
+            query
+                .include_children(polling.include_children)
+                .include_duplicates(polling.include_duplicates);
+
+            execute_with_backoff(client, &query, backoff, usize::MAX)
+                .await
+                .map_err(|e| timed_out(e, FinalityPhase::Consensus, transaction_id))?
+        };
+
+        let record = match polling.strategy {
+            FinalityStrategy::Record => {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+
+                let record = response
+                    .get_record_query()
+                    .include_children(polling.include_children)
+                    .include_duplicates(polling.include_duplicates)
+                    .execute_with_optional_timeout(client, Some(remaining))
+                    .await
+                    .map_err(|e| timed_out(e, FinalityPhase::Record, transaction_id))?;
+
+                Some(record)
+            }
+
+            _ => None,
+        };
+
+        Ok(TransactionOutcome { response, receipt, record })
+    }
+}
+
+/// Turns a generic timeout into a [`Error::FinalityTimedOut`] for `phase`, other errors are passed through.
+fn timed_out(error: Error, phase: FinalityPhase, transaction_id: Option<TransactionId>) -> Error {
+    match error {
+        Error::TimedOut(last_error) => Error::FinalityTimedOut {
+            phase,
+            transaction_id: transaction_id.map(Box::new),
+            last_error: Some(last_error),
+        },
+        error => error,
+    }
+}
+
+#[cfg(feature = "mirror-rest")]
+async fn wait_for_mirror_node(
+    mirror: &crate::MirrorRestClient,
+    response: &TransactionResponse,
+    polling: &FinalityPolling,
+    deadline: Instant,
+) -> crate::Result<()> {
+    let mut last_error = None;
+
+    loop {
+        match mirror.transaction_exists(&response.transaction_id).await {
+            Ok(true) => return Ok(()),
+            Ok(false) => {}
+            Err(e) => last_error = Some(Box::new(e)),
+        }
+
+        if Instant::now() + polling.interval >= deadline {
+            break;
+        }
+
+        tokio::time::sleep(polling.interval).await;
+    }
+
+    Err(Error::FinalityTimedOut {
+        phase: FinalityPhase::MirrorNode,
+        transaction_id: Some(Box::new(response.transaction_id)),
+        last_error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+    use std::time::Duration;
+
+    use expect_test::expect;
+    use hedera_proto::services::ResponseCodeEnum;
+    use triomphe::Arc;
+
+    use super::{
+        FinalityPhase,
+        FinalityPolling,
+        FinalityStrategy,
+        TransactionOutcome,
+    };
+    use crate::client::mock_node;
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::{
+        AccountId,
+        Client,
+        Error,
+        Hbar,
+        Status,
+        TransferTransaction,
+    };
+
+    async fn execute_and_wait(
+        client: &Client,
+        polling: &FinalityPolling,
+    ) -> crate::Result<TransactionOutcome> {
+        TransferTransaction::new()
+            .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(1))
+            .execute_and_wait(client, polling)
+            .await
+    }
+
+    /// A node that accepts every transaction and reports consensus after `unknown` receipt polls.
+    ///
+    /// Returns the number of submissions and receipt polls the node has seen.
+    async fn node(unknown: usize) -> (Client, Arc<AtomicUsize>, Arc<AtomicUsize>) {
+        let submissions = Arc::new(AtomicUsize::new(0));
+        let polls = Arc::new(AtomicUsize::new(0));
+
+        let address = mock_node::spawn({
+            let submissions = submissions.clone();
+            let polls = polls.clone();
+            move |method, _| match method {
+                "getTransactionReceipts" => {
+                    let status = match polls.fetch_add(1, Ordering::Relaxed) < unknown {
+                        true => ResponseCodeEnum::Unknown,
+                        false => ResponseCodeEnum::Success,
+                    };
+
+                    Ok(mock_node::receipt_response(status))
+                }
+                _ => {
+                    submissions.fetch_add(1, Ordering::Relaxed);
+                    Ok(mock_node::transaction_response(ResponseCodeEnum::Ok))
+                }
+            }
+        })
+        .await;
+
+        (mock_node::client(&[address]), submissions, polls)
+    }
+
+    fn polling(deadline: Duration) -> FinalityPolling {
+        let mut polling = FinalityPolling::new();
+        polling.interval(Duration::from_millis(10)).deadline(deadline);
+        polling
+    }
+
+    #[test]
+    fn get_set() {
+        let mut polling = FinalityPolling::new();
+        polling
+            .strategy(FinalityStrategy::Record)
+            .interval(Duration::from_millis(250))
+            .deadline(Duration::from_secs(30))
+            .include_children(true)
+            .include_duplicates(true);
+
+        assert_eq!(polling.get_strategy(), FinalityStrategy::Record);
+        assert_eq!(polling.get_interval(), Duration::from_millis(250));
+        assert_eq!(polling.get_deadline(), Duration::from_secs(30));
+        assert!(polling.get_include_children());
+        assert!(polling.get_include_duplicates());
+    }
+
+    #[test]
+    fn defaults() {
+        expect![[r#"
+            FinalityPolling {
+                strategy: Receipt,
+                interval: 500ms,
+                deadline: 120s,
+                include_children: false,
+                include_duplicates: false,
+            }
+        "#]]
+        .assert_debug_eq(&FinalityPolling::new());
+    }
+
+    #[test]
+    fn timed_out_reports_phase() {
+        let error = super::timed_out(
+            Error::TimedOut(Box::new(Error::FreezeUnsetNodeAccountIds)),
+            FinalityPhase::Consensus,
+            None,
+        );
+
+        expect!["transaction did not reach finality before the deadline (phase: consensus)"]
+            .assert_eq(&error.to_string());
+
+        let error = super::timed_out(
+            Error::TimedOut(Box::new(Error::FreezeUnsetNodeAccountIds)),
+            FinalityPhase::Record,
+            Some(TEST_TX_ID),
+        );
+
+        expect!["transaction `0.0.5006@1554158542.0` did not reach finality before the deadline (phase: record)"].assert_eq(&error.to_string());
+    }
+
+    #[tokio::test]
+    async fn receipt_strategy_polls_until_consensus() {
+        let (client, _, polls) = node(2).await;
+
+        let outcome = execute_and_wait(&client, &polling(Duration::from_secs(10))).await.unwrap();
+
+        assert_eq!(outcome.receipt.status, Status::Success);
+        assert_eq!(outcome.receipt.transaction_id, Some(outcome.response.transaction_id));
+        assert!(outcome.record.is_none());
+        assert_eq!(polls.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn receipt_strategy_times_out_in_consensus_phase() {
+        let (client, ..) = node(usize::MAX).await;
+
+        let error = execute_and_wait(&client, &polling(Duration::from_millis(200))).await;
+
+        assert!(
+            matches!(
+                error,
+                Err(Error::FinalityTimedOut {
+                    phase: FinalityPhase::Consensus,
+                    transaction_id: Some(_),
+                    last_error: Some(_),
+                })
+            ),
+            "{error:?}"
+        );
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    #[tokio::test]
+    async fn mirror_node_strategy_requires_rest_url() {
+        let (client, submissions, polls) = node(0).await;
+
+        let mut polling = polling(Duration::from_secs(10));
+        polling.strategy(FinalityStrategy::MirrorNode);
+
+        let error = execute_and_wait(&client, &polling).await;
+
+        assert!(matches!(error, Err(Error::MirrorRestUrlUnset)), "{error:?}");
+        assert_eq!(submissions.load(Ordering::Relaxed), 0);
+        assert_eq!(polls.load(Ordering::Relaxed), 0);
+    }
+
+    #[cfg(feature = "mirror-rest")]
+    #[tokio::test]
+    async fn mirror_node_strategy_waits_for_mirror_node() {
+        let (client, _, polls) = node(0).await;
+
+        let mut transaction = TransferTransaction::new();
+        transaction
+            .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(1))
+            .transaction_id(TEST_TX_ID);
+
+        let path = format!(
+            "/api/v1/transactions/{}",
+            crate::mirror_rest::transaction_id_path(&TEST_TX_ID)
+        );
+
+        // the transaction isn't known to the mirror node until the second request.
+        let mirror_polls = Arc::new(AtomicUsize::new(0));
+
+        client.set_mirror_rest_url(Some(crate::mirror_rest::tests::serve_fn({
+            let mirror_polls = mirror_polls.clone();
+            move |request| match request == path {
+                true if mirror_polls.fetch_add(1, Ordering::Relaxed) == 0 => (404, String::new()),
+                true => (200, r#"{"transactions": []}"#.to_owned()),
+                false => (404, String::new()),
+            }
+        })));
+
+        let mut polling = polling(Duration::from_secs(10));
+        polling.strategy(FinalityStrategy::MirrorNode);
+
+        let outcome = transaction.execute_and_wait(&client, &polling).await.unwrap();
+
+        assert_eq!(outcome.receipt.status, Status::Success);
+        assert_eq!(mirror_polls.load(Ordering::Relaxed), 2);
+        assert_eq!(polls.load(Ordering::Relaxed), 1);
+    }
+}
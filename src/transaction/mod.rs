@@ -38,8 +38,10 @@ use crate::signer::AnySigner;
 use crate::{
     AccountId,
     Client,
+    CustomFeeLimit,
     Error,
     Hbar,
+    Key,
     Operator,
     PrivateKey,
     PublicKey,
@@ -53,6 +55,7 @@ use crate::{
 mod any;
 mod chunked;
 mod execute;
+mod finality;
 mod protobuf;
 mod source;
 #[cfg(test)]
@@ -70,6 +73,12 @@ pub(crate) use execute::{
     TransactionExecute,
     TransactionExecuteChunked,
 };
+pub use finality::{
+    FinalityPhase,
+    FinalityPolling,
+    FinalityStrategy,
+    TransactionOutcome,
+};
 pub(crate) use protobuf::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
@@ -102,11 +111,17 @@ pub(crate) struct TransactionBody<D> {
 
     pub(crate) transaction_id: Option<TransactionId>,
 
+    pub(crate) batch_key: Option<Key>,
+
+    pub(crate) custom_fee_limits: Vec<CustomFeeLimit>,
+
     pub(crate) operator: Option<Arc<Operator>>,
 
     pub(crate) is_frozen: bool,
 
     pub(crate) regenerate_transaction_id: Option<bool>,
+
+    pub(crate) idempotent_resubmission: bool,
 }
 
 impl<D> Default for Transaction<D>
@@ -122,9 +137,12 @@ where
                 max_transaction_fee: None,
                 transaction_memo: String::new(),
                 transaction_id: None,
+                batch_key: None,
+                custom_fee_limits: Vec::new(),
                 operator: None,
                 is_frozen: false,
                 regenerate_transaction_id: None,
+                idempotent_resubmission: false,
             },
             signers: Vec::new(),
             sources: None,
@@ -186,9 +204,14 @@ impl<D> Transaction<D> {
         );
     }
 
+    #[inline(always)]
+    pub(crate) fn body(&self) -> &TransactionBody<D> {
+        &self.body
+    }
+
     /// # Panics
     /// If `self.is_frozen()`.
-    fn body_mut(&mut self) -> &mut TransactionBody<D> {
+    pub(crate) fn body_mut(&mut self) -> &mut TransactionBody<D> {
         self.require_not_frozen();
         &mut self.body
     }
@@ -291,6 +314,43 @@ impl<D> Transaction<D> {
         self
     }
 
+    /// Returns the key of the account that is trusted to submit this transaction as part of a
+    /// [`BatchTransaction`](crate::BatchTransaction).
+    #[must_use]
+    pub fn get_batch_key(&self) -> Option<&Key> {
+        self.body.batch_key.as_ref()
+    }
+
+    /// Sets the key of the account that is trusted to submit this transaction as part of a
+    /// [`BatchTransaction`](crate::BatchTransaction).
+    pub fn batch_key(&mut self, key: impl Into<Key>) -> &mut Self {
+        self.body_mut().batch_key = Some(key.into());
+        self
+    }
+
+    /// Returns whether a [`Status::DuplicateTransaction`](crate::Status::DuplicateTransaction) pre-check is treated as a success.
+    #[must_use]
+    pub fn get_idempotent_resubmission(&self) -> bool {
+        self.body.idempotent_resubmission
+    }
+
+    /// Sets whether a [`Status::DuplicateTransaction`](crate::Status::DuplicateTransaction) pre-check is treated as a success.
+    ///
+    /// A request can reach consensus even though the SDK never saw the node's response (ex. a client-side timeout),
+    /// in which case resubmitting it with the same transaction ID fails pre-check with `DuplicateTransaction`.
+    /// When enabled, that pre-check resolves to a [`TransactionResponse`] for the original submission instead,
+    /// so receipts and records can be fetched as usual.
+    ///
+    /// Only enable this if the transaction ID is never reused for a *different* transaction,
+    /// otherwise the duplicate will be mistaken for this transaction.
+    ///
+    /// Defaults to `false`.
+    pub fn idempotent_resubmission(&mut self, idempotent_resubmission: bool) -> &mut Self {
+        self.body_mut().idempotent_resubmission = idempotent_resubmission;
+
+        self
+    }
+
     /// Sign the transaction.
     pub fn sign(&mut self, private_key: PrivateKey) -> &mut Self {
         self.sign_signer(AnySigner::PrivateKey(private_key))
@@ -425,7 +485,7 @@ impl<D: ValidateChecksums> Transaction<D> {
             client.and_then(Client::default_max_transaction_fee)
         });
 
-        let operator = client.and_then(Client::full_load_operator);
+        let operator = client.and_then(Client::full_load_operator_with_generator);
 
         // note: yes, there's an `Some(opt.unwrap())`, this is INTENTIONAL.
         self.body.node_account_ids = Some(node_account_ids);
@@ -455,7 +515,9 @@ impl<D: ValidateChecksums> Transaction<D> {
     /// # Panics
     /// If `client` has no operator.
     pub fn sign_with_operator(&mut self, client: &Client) -> crate::Result<&mut Self> {
-        let Some(op) = client.full_load_operator() else { panic!("Client had no operator") };
+        let Some(op) = client.full_load_operator_with_generator() else {
+            panic!("Client had no operator")
+        };
 
         self.freeze_with(client)?;
 
@@ -465,6 +527,30 @@ impl<D: ValidateChecksums> Transaction<D> {
 
         Ok(self)
     }
+
+    /// Prepare the transaction to be an inner transaction of a [`BatchTransaction`](crate::BatchTransaction).
+    ///
+    /// This sets the `batch_key`, the node account ID `0.0.0` and a transaction ID (if one isn't already set),
+    /// then freezes the transaction and signs it with the `client`'s operator.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`] if `client` has no operator.
+    /// - If [`freeze_with`](Self::freeze_with) would error for this transaction.
+    pub fn batchify(
+        &mut self,
+        client: &Client,
+        batch_key: impl Into<Key>,
+    ) -> crate::Result<&mut Self> {
+        let op = client.full_load_operator().ok_or(Error::NoPayerAccountOrTransactionId)?;
+
+        self.batch_key(batch_key).node_account_ids([AccountId::new(0, 0, 0)]);
+
+        if self.body.transaction_id.is_none() {
+            self.transaction_id(client.generate_transaction_id(op.account_id));
+        }
+
+        self.sign_with_operator(client)
+    }
 }
 
 impl<D: TransactionExecute> Transaction<D> {
@@ -591,6 +677,50 @@ impl<D: TransactionExecute> Transaction<D> {
         self
     }
 
+    pub(crate) fn into_any(self) -> AnyTransaction {
+        let Self { body, signers, sources } = self;
+
+        Transaction {
+            body: TransactionBody {
+                data: body.data.into(),
+                node_account_ids: body.node_account_ids,
+                transaction_valid_duration: body.transaction_valid_duration,
+                max_transaction_fee: body.max_transaction_fee,
+                transaction_memo: body.transaction_memo,
+                transaction_id: body.transaction_id,
+                batch_key: body.batch_key,
+                custom_fee_limits: body.custom_fee_limits,
+                operator: body.operator,
+                is_frozen: body.is_frozen,
+                regenerate_transaction_id: body.regenerate_transaction_id,
+                idempotent_resubmission: body.idempotent_resubmission,
+            },
+            signers,
+            sources,
+        }
+    }
+
+    /// Returns the signed transaction bytes of this transaction, fixing its sources in place.
+    ///
+    /// Note: Calling this function _disables_ transaction ID regeneration.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`]
+    ///     if `freeze_with` wasn't called with an operator and no transaction ID was set.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub(crate) fn signed_transaction_bytes(&mut self) -> crate::Result<Vec<Vec<u8>>> {
+        let sources = self.make_sources()?;
+
+        let sources = match sources {
+            Cow::Borrowed(it) => it,
+            Cow::Owned(it) => &*self.sources.insert(it),
+        };
+
+        Ok(sources.transactions().iter().map(|it| it.signed_transaction_bytes.clone()).collect())
+    }
+
     /// # Panics
     /// panics if the transaction is not schedulable, a transaction can be non-schedulable due to:
     /// - if `self.is_frozen`
@@ -875,6 +1005,10 @@ impl AnyTransaction {
             list.transaction_list
         };
 
+        Self::from_transaction_list(list)
+    }
+
+    pub(crate) fn from_transaction_list(list: Vec<services::Transaction>) -> crate::Result<Self> {
         let sources = TransactionSources::new(list)?;
 
         let transaction_bodies: Result<Vec<_>, _> = sources
@@ -938,7 +1072,9 @@ fn pb_transaction_body_eq(
         transaction_valid_duration,
         generate_record,
         memo,
+        batch_key,
         data,
+        max_custom_fees,
     } = rhs;
 
     if &lhs.transaction_fee != transaction_fee {
@@ -957,6 +1093,14 @@ fn pb_transaction_body_eq(
         return false;
     }
 
+    if &lhs.batch_key != batch_key {
+        return false;
+    }
+
+    if &lhs.max_custom_fees != max_custom_fees {
+        return false;
+    }
+
     match (&lhs.data, data) {
         (None, None) => {}
         (Some(lhs), Some(rhs)) => match (lhs, rhs) {
@@ -1027,9 +1171,12 @@ where
             max_transaction_fee,
             transaction_memo,
             transaction_id,
+            batch_key,
+            custom_fee_limits,
             operator,
             is_frozen,
             regenerate_transaction_id,
+            idempotent_resubmission,
         } = body;
 
         // not a `map().map_err()` because ownership.
@@ -1042,9 +1189,12 @@ where
                     max_transaction_fee,
                     transaction_memo,
                     transaction_id,
+                    batch_key,
+                    custom_fee_limits,
                     operator,
                     is_frozen,
                     regenerate_transaction_id,
+                    idempotent_resubmission,
                 },
                 signers,
                 sources,
@@ -1058,9 +1208,12 @@ where
                     max_transaction_fee,
                     transaction_memo,
                     transaction_id,
+                    batch_key,
+                    custom_fee_limits,
                     operator,
                     is_frozen,
                     regenerate_transaction_id,
+                    idempotent_resubmission,
                 },
                 signers,
                 sources,
@@ -1143,7 +1296,9 @@ pub(crate) mod test_helpers {
             transaction_valid_duration,
             generate_record,
             memo,
+            batch_key,
             data,
+            max_custom_fees,
         } = body;
 
         let node_account_id = node_account_id.unwrap();
@@ -1156,6 +1311,8 @@ pub(crate) mod test_helpers {
         assert_eq!(transaction_valid_duration, Some(services::Duration { seconds: 120 }));
         assert_eq!(generate_record, false);
         assert_eq!(memo, "");
+        assert_eq!(batch_key, None);
+        assert_eq!(max_custom_fees, Vec::new());
 
         data.unwrap()
     }
@@ -1,15 +1,20 @@
 use std::collections::HashSet;
 
 use assert_matches::assert_matches;
+use hedera_proto::services::ResponseCodeEnum;
 use hex_literal::hex;
 use time::OffsetDateTime;
 
+use crate::client::mock_node;
 use crate::transaction::AnyTransactionData;
 use crate::{
+    AccountId,
     AnyTransaction,
     Client,
+    Error,
     Hbar,
     PrivateKey,
+    Status,
     TopicMessageSubmitTransaction,
     TransactionId,
     TransferTransaction,
@@ -119,3 +124,85 @@ async fn chunked_to_from_bytes() -> crate::Result<()> {
 
     Ok(())
 }
+
+/// Executes a transfer against a node that fails every submission with `DUPLICATE_TRANSACTION`.
+async fn execute_duplicate(
+    idempotent_resubmission: bool,
+) -> crate::Result<crate::TransactionResponse> {
+    let address = mock_node::spawn(|_, _| {
+        Ok(mock_node::transaction_response(ResponseCodeEnum::DuplicateTransaction))
+    })
+    .await;
+
+    let client = mock_node::client(&[address]);
+
+    TransferTransaction::new()
+        .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+        .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(1))
+        .idempotent_resubmission(idempotent_resubmission)
+        .execute(&client)
+        .await
+}
+
+#[tokio::test]
+async fn duplicate_transaction_is_success_with_idempotent_resubmission() -> crate::Result<()> {
+    let response = execute_duplicate(true).await?;
+
+    assert_eq!(response.node_account_id, AccountId::new(0, 0, 3));
+    assert_eq!(response.transaction_id.account_id, AccountId::new(0, 0, 2));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn duplicate_transaction_fails_pre_check_by_default() {
+    let error = execute_duplicate(false).await;
+
+    assert_matches!(
+        error,
+        Err(Error::TransactionPreCheckStatus { status: Status::DuplicateTransaction, .. })
+    );
+}
+
+#[tokio::test]
+async fn to_bytes_uses_client_transaction_id_generator() -> crate::Result<()> {
+    struct Fixed;
+
+    impl crate::TransactionIdGenerator for Fixed {
+        fn generate(&self, account_id: AccountId) -> TransactionId {
+            TransactionId {
+                account_id,
+                valid_start: OffsetDateTime::from_unix_timestamp(1554158542).unwrap(),
+                nonce: None,
+                scheduled: false,
+            }
+        }
+    }
+
+    let client = Client::for_network(std::collections::HashMap::new())?;
+    client.set_operator(AccountId::new(0, 0, 2), PrivateKey::generate_ed25519());
+    client.set_transaction_id_generator(Some(Fixed));
+
+    let bytes = TransferTransaction::new()
+        .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+        .hbar_transfer(AccountId::new(0, 0, 1001), Hbar::new(1))
+        .node_account_ids([AccountId::new(0, 0, 3)])
+        .freeze_with(&client)?
+        .to_bytes()?;
+
+    let transaction = AnyTransaction::from_bytes(&bytes)?;
+
+    assert_eq!(
+        transaction.get_transaction_id().map(|it| it.to_string()).as_deref(),
+        Some("0.0.2@1554158542.0")
+    );
+
+    client.set_transaction_id_generator(None::<Fixed>);
+
+    assert_ne!(
+        client.generate_transaction_id(AccountId::new(0, 0, 2)).to_string(),
+        "0.0.2@1554158542.0"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,299 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+use crate::{
+    AccountId,
+    TransactionId,
+};
+
+/// Generates [`TransactionId`]s for transactions that don't have an explicit one.
+///
+/// Set one on a [`Client`](crate::Client) with
+/// [`set_transaction_id_generator`](crate::Client::set_transaction_id_generator).
+pub trait TransactionIdGenerator: Send + Sync {
+    /// Generate a new transaction ID paid for by `account_id`.
+    fn generate(&self, account_id: AccountId) -> TransactionId;
+}
+
+/// The last `valid_start` (in nanoseconds since the unix epoch) handed out, per payer.
+type LastValidStarts = Mutex<HashMap<AccountId, i128>>;
+
+static PROCESS_WIDE_VALID_STARTS: Lazy<LastValidStarts> = Lazy::new(LastValidStarts::default);
+
+/// A [`TransactionIdGenerator`] that never hands out the same `valid_start` twice for a payer.
+///
+/// Every generated ID for a given payer has a `valid_start` strictly greater than the previous one,
+/// even when many IDs are generated within the same nanosecond or from multiple threads.
+///
+/// By default the guarantee only covers IDs from this generator,
+/// use [`process_wide`](Self::process_wide) to share it with every other process-wide generator
+/// (ex. when using several [`Client`](crate::Client)s with the same payer).
+///
+/// Generators never coordinate across processes, two processes paying with the same account can still
+/// generate the same ID. Give each process its own [`partition`](Self::partition) to prevent that.
+#[derive(Debug)]
+pub struct MonotonicTransactionIdGenerator {
+    backdate: Duration,
+    nonce: Option<i32>,
+    partition: (u32, u32),
+    local: Option<LastValidStarts>,
+}
+
+impl Default for MonotonicTransactionIdGenerator {
+    fn default() -> Self {
+        Self {
+            backdate: Duration::seconds(5),
+            nonce: None,
+            partition: (0, 1),
+            local: Some(LastValidStarts::default()),
+        }
+    }
+}
+
+impl MonotonicTransactionIdGenerator {
+    /// Create a new generator that only coordinates with itself.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new generator that coordinates with every other process-wide generator in this process.
+    ///
+    /// Other processes are not coordinated with, see [`partition`](Self::partition).
+    #[must_use]
+    pub fn process_wide() -> Self {
+        Self { local: None, ..Self::default() }
+    }
+
+    /// Returns true if this generator coordinates with every other process-wide generator in this process.
+    #[must_use]
+    pub fn is_process_wide(&self) -> bool {
+        self.local.is_none()
+    }
+
+    /// Returns how far in the past generated `valid_start`s are placed.
+    #[must_use]
+    pub fn get_backdate(&self) -> Duration {
+        self.backdate
+    }
+
+    /// Sets how far in the past generated `valid_start`s are placed.
+    ///
+    /// This gives some leeway for the local clock being ahead of the network's.
+    ///
+    /// Defaults to 5 seconds.
+    pub fn backdate(&mut self, backdate: Duration) -> &mut Self {
+        self.backdate = backdate;
+        self
+    }
+
+    /// Returns the nonce that is set on generated transaction IDs.
+    #[must_use]
+    pub fn get_nonce(&self) -> Option<i32> {
+        self.nonce
+    }
+
+    /// Sets the nonce that is set on generated transaction IDs.
+    pub fn nonce(&mut self, nonce: Option<i32>) -> &mut Self {
+        self.nonce = nonce;
+        self
+    }
+
+    /// Returns the partition of `valid_start`s this generator uses, as `(index, count)`.
+    #[must_use]
+    pub fn get_partition(&self) -> (u32, u32) {
+        self.partition
+    }
+
+    /// Restricts generated `valid_start`s to partition `index` out of `count`.
+    ///
+    /// Every generated `valid_start` is `index` modulo `count` nanoseconds,
+    /// so generators with different indices (and the same `count`) never generate the same ID,
+    /// even from different processes or machines.
+    /// Give each process paying with the same account a distinct `index`.
+    ///
+    /// Defaults to `(0, 1)` (IE, not partitioned).
+    ///
+    /// # Panics
+    /// - If `count` is zero, or `index` is not less than `count`.
+    pub fn partition(&mut self, index: u32, count: u32) -> &mut Self {
+        assert!(index < count, "partition index must be less than the partition count");
+
+        self.partition = (index, count);
+        self
+    }
+
+    fn generate_at(&self, account_id: AccountId, now: OffsetDateTime) -> TransactionId {
+        let (index, count) = (i128::from(self.partition.0), i128::from(self.partition.1));
+
+        let mut last_valid_starts =
+            self.local.as_ref().unwrap_or(&PROCESS_WIDE_VALID_STARTS).lock();
+
+        let earliest = match last_valid_starts.get(&account_id) {
+            Some(&last) => ((now - self.backdate).unix_timestamp_nanos()).max(last + 1),
+            None => (now - self.backdate).unix_timestamp_nanos(),
+        };
+
+        // round up to the next `valid_start` in our partition.
+        let valid_start = earliest + (index - earliest).rem_euclid(count);
+
+        last_valid_starts.insert(account_id, valid_start);
+
+        drop(last_valid_starts);
+
+        TransactionId {
+            account_id,
+            // the generated nanos come from a valid `OffsetDateTime`,
+            // and a handful of extra nanoseconds won't push it out of range.
+            valid_start: OffsetDateTime::from_unix_timestamp_nanos(valid_start).unwrap(),
+            nonce: self.nonce,
+            scheduled: false,
+        }
+    }
+}
+
+impl TransactionIdGenerator for MonotonicTransactionIdGenerator {
+    fn generate(&self, account_id: AccountId) -> TransactionId {
+        self.generate_at(account_id, OffsetDateTime::now_utc())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use expect_test::expect;
+    use time::OffsetDateTime;
+    use triomphe::Arc;
+
+    use super::{
+        MonotonicTransactionIdGenerator,
+        TransactionIdGenerator,
+    };
+    use crate::AccountId;
+
+    const PAYER: AccountId = AccountId::new(0, 0, 5006);
+
+    #[test]
+    fn strictly_increasing_within_same_instant() {
+        let generator = MonotonicTransactionIdGenerator::new();
+        let now = OffsetDateTime::from_unix_timestamp(1554158542).unwrap();
+
+        let ids: Vec<_> = (0..3).map(|_| generator.generate_at(PAYER, now)).collect();
+
+        expect![[r#"
+            [
+                "0.0.5006@1554158537.0",
+                "0.0.5006@1554158537.1",
+                "0.0.5006@1554158537.2",
+            ]
+        "#]]
+        .assert_debug_eq(&ids);
+    }
+
+    #[test]
+    fn payers_are_independent() {
+        let generator = MonotonicTransactionIdGenerator::new();
+        let now = OffsetDateTime::from_unix_timestamp(1554158542).unwrap();
+
+        let a = generator.generate_at(PAYER, now);
+        let b = generator.generate_at(AccountId::new(0, 0, 5007), now);
+
+        assert_eq!(a.valid_start, b.valid_start);
+    }
+
+    #[test]
+    fn nonce() {
+        let mut generator = MonotonicTransactionIdGenerator::new();
+        generator.nonce(Some(4));
+
+        assert_eq!(generator.generate(PAYER).nonce, Some(4));
+    }
+
+    #[test]
+    fn partitions_never_overlap() {
+        let now = OffsetDateTime::from_unix_timestamp(1554158542).unwrap();
+
+        // stand-ins for 3 processes that don't know about each other.
+        let ids: Vec<HashSet<_>> = (0..3)
+            .map(|index| {
+                let mut generator = MonotonicTransactionIdGenerator::new();
+                generator.partition(index, 3);
+
+                (0..100).map(|_| generator.generate_at(PAYER, now)).collect()
+            })
+            .collect();
+
+        for (index, ids) in ids.iter().enumerate() {
+            assert_eq!(ids.len(), 100);
+            assert!(ids
+                .iter()
+                .all(|it| it.valid_start.unix_timestamp_nanos() % 3 == index as i128));
+        }
+
+        assert!(ids[0].is_disjoint(&ids[1]) && ids[1].is_disjoint(&ids[2]));
+
+        let mut generator = MonotonicTransactionIdGenerator::new();
+        generator.partition(1, 3);
+
+        let ids: Vec<_> = (0..2).map(|_| generator.generate_at(PAYER, now)).collect();
+
+        expect![[r#"
+            [
+                "0.0.5006@1554158537.2",
+                "0.0.5006@1554158537.5",
+            ]
+        "#]]
+        .assert_debug_eq(&ids);
+    }
+
+    #[test]
+    #[should_panic = "partition index must be less than the partition count"]
+    fn partition_index_out_of_range() {
+        MonotonicTransactionIdGenerator::new().partition(3, 3);
+    }
+
+    #[test]
+    fn unique_across_threads() {
+        let generator = Arc::new(MonotonicTransactionIdGenerator::process_wide());
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let generator = Arc::clone(&generator);
+                std::thread::spawn(move || {
+                    (0..1000).map(|_| generator.generate(PAYER)).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let ids: HashSet<_> = handles.into_iter().flat_map(|it| it.join().unwrap()).collect();
+
+        assert_eq!(ids.len(), 4000);
+    }
+}
@@ -41,7 +41,7 @@ use crate::{
 /// The summary of a transaction's result so far, if the transaction has reached consensus.
 /// Response from [`TransactionReceiptQuery`][crate::TransactionReceiptQuery].
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TransactionReceipt {
     // fixme(sr): better doc comment.
     /// The ID of the transaction that this is a receipt for.
@@ -99,6 +99,9 @@ pub struct TransactionReceipt {
     /// the serial numbers of the newly created NFTs.
     pub serials: Vec<i64>,
 
+    /// In the receipt of a `NodeCreateTransaction`, the id of the newly created node.
+    pub node_id: u64,
+
     /// The receipts of processing all transactions with the given id, in consensus time order.
     pub duplicates: Vec<TransactionReceipt>,
 
@@ -159,6 +162,7 @@ impl TransactionReceipt {
             status,
             total_supply: receipt.new_total_supply,
             serials: receipt.serial_numbers,
+            node_id: receipt.node_id,
             topic_running_hash_version: receipt.topic_running_hash_version,
             topic_sequence_number: receipt.topic_sequence_number,
             topic_running_hash: receipt
@@ -232,6 +236,7 @@ impl ToProtobuf for TransactionReceipt {
             schedule_id: self.schedule_id.to_protobuf(),
             scheduled_transaction_id: self.scheduled_transaction_id.to_protobuf(),
             serial_numbers: self.serials.clone(),
+            node_id: self.node_id,
         }
     }
 }
@@ -270,6 +275,7 @@ mod tests {
             schedule_id: Some(ScheduleId::new(1, 1, 1)),
             scheduled_transaction_id: Some(TEST_TX_ID),
             serials: Vec::from([1, 2, 3]),
+            node_id: 1,
             duplicates: Vec::new(),
             children: Vec::new(),
         }
@@ -381,6 +387,7 @@ mod tests {
                     2,
                     3,
                 ],
+                node_id: 1,
             }
         "#]]
         .assert_debug_eq(&make_receipt().to_protobuf())
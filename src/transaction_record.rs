@@ -31,6 +31,7 @@ use crate::{
     EvmAddress,
     FromProtobuf,
     Hbar,
+    PendingAirdropRecord,
     PublicKey,
     ScheduleId,
     Tinybar,
@@ -44,7 +45,7 @@ use crate::{
 
 /// The complete record for a transaction on Hedera that has reached consensus.
 /// Response from [`TransactionRecordQuery`][crate::TransactionRecordQuery].
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct TransactionRecord {
     /// The status (reach consensus, or failed, or is unknown) and the ID of
     /// any new account/file/instance created.
@@ -124,6 +125,12 @@ pub struct TransactionRecord {
 
     /// The last 20 bytes of the keccak-256 hash of a ECDSA_SECP256K1 primitive key.
     pub evm_address: Option<EvmAddress>,
+
+    /// The pending airdrops created by this transaction.
+    ///
+    /// Populated by a [`TokenAirdropTransaction`](crate::TokenAirdropTransaction) for every transfer
+    /// that couldn't be completed immediately.
+    pub new_pending_airdrops: Vec<PendingAirdropRecord>,
 }
 // TODO: paid_staking_rewards
 
@@ -237,6 +244,7 @@ impl TransactionRecord {
             evm_address,
             prng_bytes,
             prng_number,
+            new_pending_airdrops: Vec::from_protobuf(record.new_pending_airdrops)?,
         })
     }
 }
@@ -325,6 +333,7 @@ impl ToProtobuf for TransactionRecord {
                 .as_ref()
                 .map(|it| services::transaction_record::Body::ContractCallResult(it.to_protobuf())),
             entropy,
+            new_pending_airdrops: self.new_pending_airdrops.to_protobuf(),
         }
     }
 }
@@ -346,6 +355,8 @@ mod tests {
         ContractFunctionResult,
         ContractId,
         Hbar,
+        PendingAirdropId,
+        PendingAirdropRecord,
         PrivateKey,
         ScheduleId,
         TokenAssociation,
@@ -422,6 +433,14 @@ mod tests {
             prng_bytes,
             prng_number,
             evm_address: Some(crate::EvmAddress([0; 20])),
+            new_pending_airdrops: Vec::from([PendingAirdropRecord {
+                pending_airdrop_id: PendingAirdropId::new_token_id(
+                    AccountId::new(1, 2, 3),
+                    AccountId::new(3, 2, 1),
+                    TokenId::new(6, 6, 6),
+                ),
+                amount: 4,
+            }]),
         }
     }
 
@@ -82,7 +82,7 @@ struct Transfer {
 
 #[derive(Debug, Clone)]
 #[cfg_attr(test, derive(Eq, PartialEq))]
-struct TokenTransfer {
+pub(crate) struct TokenTransfer {
     token_id: TokenId,
 
     transfers: Vec<Transfer>,
@@ -92,6 +92,98 @@ struct TokenTransfer {
     expected_decimals: Option<u32>,
 }
 
+// shared by every transaction with a list of token transfers (ex. `TokenAirdropTransaction`).
+impl TokenTransfer {
+    /// Adds a fungible transfer to `token_transfers`, grouped with any other transfers of `token_id`.
+    pub(crate) fn push_transfer(
+        token_transfers: &mut Vec<Self>,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: i64,
+        approved: bool,
+        expected_decimals: Option<u32>,
+    ) {
+        let transfer = Transfer { account_id, amount, is_approval: approved };
+
+        if let Some(tt) = token_transfers.iter_mut().find(|tt| tt.token_id == token_id) {
+            tt.expected_decimals = expected_decimals;
+            tt.transfers.push(transfer);
+        } else {
+            token_transfers.push(Self {
+                token_id,
+                expected_decimals,
+                nft_transfers: Vec::new(),
+                transfers: vec![transfer],
+            });
+        }
+    }
+
+    /// Adds an NFT transfer to `token_transfers`, grouped with any other transfers of the NFT's token.
+    pub(crate) fn push_nft_transfer(
+        token_transfers: &mut Vec<Self>,
+        nft_id: NftId,
+        sender_account_id: AccountId,
+        receiver_account_id: AccountId,
+        approved: bool,
+    ) {
+        let NftId { token_id, serial } = nft_id;
+        let transfer = TokenNftTransfer {
+            token_id,
+            serial,
+            sender: sender_account_id,
+            receiver: receiver_account_id,
+            is_approved: approved,
+        };
+
+        if let Some(tt) = token_transfers.iter_mut().find(|tt| tt.token_id == token_id) {
+            tt.nft_transfers.push(transfer);
+        } else {
+            token_transfers.push(Self {
+                token_id,
+                expected_decimals: None,
+                transfers: Vec::new(),
+                nft_transfers: vec![transfer],
+            });
+        }
+    }
+
+    /// Returns the fungible amounts in `token_transfers`, by token and then by account.
+    pub(crate) fn amounts(token_transfers: &[Self]) -> HashMap<TokenId, HashMap<AccountId, i64>> {
+        use std::collections::hash_map::Entry;
+
+        // note: using fold instead of nested collects on the off chance a token is in here twice.
+        token_transfers.iter().fold(
+            HashMap::with_capacity(token_transfers.len()),
+            |mut map, transfer| {
+                let iter = transfer.transfers.iter().map(|it| (it.account_id, it.amount));
+                match map.entry(transfer.token_id) {
+                    Entry::Occupied(mut it) => it.get_mut().extend(iter),
+                    Entry::Vacant(it) => {
+                        it.insert(iter.collect());
+                    }
+                }
+
+                map
+            },
+        )
+    }
+
+    /// Returns the expected decimals of each token in `token_transfers` that has them.
+    pub(crate) fn decimals(token_transfers: &[Self]) -> HashMap<TokenId, u32> {
+        token_transfers
+            .iter()
+            .filter_map(|it| it.expected_decimals.map(|decimals| (it.token_id, decimals)))
+            .collect()
+    }
+
+    /// Returns the NFT transfers in `token_transfers`, by token.
+    pub(crate) fn nft_transfers(
+        token_transfers: &[Self],
+    ) -> HashMap<TokenId, Vec<TokenNftTransfer>> {
+        token_transfers.iter().map(|it| (it.token_id, it.nft_transfers.clone())).collect()
+    }
+}
+
 impl TransferTransaction {
     fn _hbar_transfer(&mut self, account_id: AccountId, amount: Hbar, approved: bool) -> &mut Self {
         self.data_mut().transfers.push(Transfer {
@@ -130,20 +222,14 @@ impl TransferTransaction {
         approved: bool,
         expected_decimals: Option<u32>,
     ) -> &mut Self {
-        let transfer = Transfer { account_id, amount, is_approval: approved };
-        let data = self.data_mut();
-
-        if let Some(tt) = data.token_transfers.iter_mut().find(|tt| tt.token_id == token_id) {
-            tt.expected_decimals = expected_decimals;
-            tt.transfers.push(transfer);
-        } else {
-            data.token_transfers.push(TokenTransfer {
-                token_id,
-                expected_decimals,
-                nft_transfers: Vec::new(),
-                transfers: vec![transfer],
-            });
-        }
+        TokenTransfer::push_transfer(
+            &mut self.data_mut().token_transfers,
+            token_id,
+            account_id,
+            amount,
+            approved,
+            expected_decimals,
+        );
 
         self
     }
@@ -203,32 +289,12 @@ impl TransferTransaction {
 
     /// Returns all the token transfers associated associated with this transaction.
     pub fn get_token_transfers(&self) -> HashMap<TokenId, HashMap<AccountId, i64>> {
-        use std::collections::hash_map::Entry;
-
-        // note: using fold instead of nested collects on the off chance a token is in here twice.
-        self.data().token_transfers.iter().fold(
-            HashMap::with_capacity(self.data().token_transfers.len()),
-            |mut map, transfer| {
-                let iter = transfer.transfers.iter().map(|it| (it.account_id, it.amount));
-                match map.entry(transfer.token_id) {
-                    Entry::Occupied(mut it) => it.get_mut().extend(iter),
-                    Entry::Vacant(it) => {
-                        it.insert(iter.collect());
-                    }
-                }
-
-                map
-            },
-        )
+        TokenTransfer::amounts(&self.data().token_transfers)
     }
 
     /// Returns the decimals associated with each token.
     pub fn get_token_decimals(&self) -> HashMap<TokenId, u32> {
-        self.data()
-            .token_transfers
-            .iter()
-            .filter_map(|it| it.expected_decimals.map(|decimals| (it.token_id, decimals)))
-            .collect()
+        TokenTransfer::decimals(&self.data().token_transfers)
     }
 
     fn _nft_transfer(
@@ -238,27 +304,13 @@ impl TransferTransaction {
         receiver_account_id: AccountId,
         approved: bool,
     ) -> &mut Self {
-        let NftId { token_id, serial } = nft_id;
-        let transfer = TokenNftTransfer {
-            token_id,
-            serial,
-            sender: sender_account_id,
-            receiver: receiver_account_id,
-            is_approved: approved,
-        };
-
-        let data = self.data_mut();
-
-        if let Some(tt) = data.token_transfers.iter_mut().find(|tt| tt.token_id == token_id) {
-            tt.nft_transfers.push(transfer);
-        } else {
-            data.token_transfers.push(TokenTransfer {
-                token_id,
-                expected_decimals: None,
-                transfers: Vec::new(),
-                nft_transfers: vec![transfer],
-            });
-        }
+        TokenTransfer::push_nft_transfer(
+            &mut self.data_mut().token_transfers,
+            nft_id,
+            sender_account_id,
+            receiver_account_id,
+            approved,
+        );
 
         self
     }
@@ -285,11 +337,7 @@ impl TransferTransaction {
 
     /// Returns all the NFT transfers associated with this transaction.
     pub fn get_nft_transfers(&self) -> HashMap<TokenId, Vec<TokenNftTransfer>> {
-        self.data()
-            .token_transfers
-            .iter()
-            .map(|it| (it.token_id, it.nft_transfers.clone()))
-            .collect()
+        TokenTransfer::nft_transfers(&self.data().token_transfers)
     }
 }
 
@@ -312,14 +360,21 @@ impl ValidateChecksums for TransferTransactionData {
             transfer.account_id.validate_checksums(ledger_id)?;
         }
         for token_transfer in &self.token_transfers {
-            token_transfer.token_id.validate_checksums(ledger_id)?;
-            for transfer in &token_transfer.transfers {
-                transfer.account_id.validate_checksums(ledger_id)?;
-            }
-            for nft_transfer in &token_transfer.nft_transfers {
-                nft_transfer.sender.validate_checksums(ledger_id)?;
-                nft_transfer.receiver.validate_checksums(ledger_id)?;
-            }
+            token_transfer.validate_checksums(ledger_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl ValidateChecksums for TokenTransfer {
+    fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
+        self.token_id.validate_checksums(ledger_id)?;
+        for transfer in &self.transfers {
+            transfer.account_id.validate_checksums(ledger_id)?;
+        }
+        for nft_transfer in &self.nft_transfers {
+            nft_transfer.sender.validate_checksums(ledger_id)?;
+            nft_transfer.receiver.validate_checksums(ledger_id)?;
         }
         Ok(())
     }